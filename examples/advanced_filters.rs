@@ -4,13 +4,10 @@
 //!
 //! Run with: cargo run --example advanced_filters
 
-use jobsuche::{Angebotsart, Arbeitszeit, Befristung, Credentials, Jobsuche, SearchOptions};
+use jobsuche::{Angebotsart, Arbeitszeit, Befristung, Jobsuche, SearchOptions};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = Jobsuche::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )?;
+    let client = Jobsuche::default_client()?;
 
     println!("🎯 Searching with advanced filters...\n");
 
@@ -45,9 +42,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             job.arbeitgeber.as_deref().unwrap_or("Unknown")
         );
         println!(
-            "  📍 {}, {} ({}km away)",
-            job.arbeitsort.ort.as_deref().unwrap_or("unknown"),
-            job.arbeitsort.region.as_deref().unwrap_or("unknown"),
+            "  📍 {} ({}km away)",
+            job.arbeitsort.short(),
             job.arbeitsort.entfernung.as_deref().unwrap_or("?")
         );
 
@@ -4,7 +4,7 @@
 //!
 //! Run with: cargo run --example async_search --features async
 
-use jobsuche::{Arbeitszeit, Credentials, JobsucheAsync, SearchOptions};
+use jobsuche::{Arbeitszeit, JobsucheAsync, SearchOptions, StatusCode};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -12,11 +12,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
 
     // Create an async client with the default API key
-    let client = JobsucheAsync::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )
-    .await?;
+    let client = JobsucheAsync::default_client()?;
 
     println!("🔍 Searching for Rust Developer jobs in Germany (async)...\n");
 
@@ -41,11 +37,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "   Company: {}",
             job.arbeitgeber.as_deref().unwrap_or("Unknown")
         );
-        println!(
-            "   Location: {}, {}",
-            job.arbeitsort.ort.as_deref().unwrap_or("unknown"),
-            job.arbeitsort.region.as_deref().unwrap_or("unknown")
-        );
+        println!("   Location: {}", job.arbeitsort);
         if let Some(date) = &job.aktuelle_veroeffentlichungsdatum {
             println!("   Published: {}", date);
         }
@@ -82,12 +74,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("\nWork locations:");
                 for location in &details.arbeitsorte {
                     if let Some(adresse) = &location.adresse {
-                        println!(
-                            "  - {}, {} {}",
-                            adresse.ort.as_deref().unwrap_or("unknown"),
-                            adresse.plz.as_deref().unwrap_or(""),
-                            adresse.region.as_deref().unwrap_or("unknown")
-                        );
+                        println!("  - {}", adresse);
                     }
                 }
 
@@ -95,9 +82,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("\nAllianzpartner: {}", partner);
                 }
             }
+            Err(e) if e.status_code() == Some(StatusCode::NOT_FOUND) => {
+                println!("⚠️  Job details not found: {}", e);
+                println!("(This is common - jobs expire quickly)");
+            }
             Err(e) => {
                 println!("⚠️  Could not get job details: {}", e);
-                println!("(This is common - jobs expire quickly)");
             }
         }
     }
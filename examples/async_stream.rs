@@ -6,18 +6,14 @@
 //! Run with: cargo run --example async_stream --features async
 
 use futures::StreamExt;
-use jobsuche::{Credentials, JobsucheAsync, SearchOptions};
+use jobsuche::{JobsucheAsync, SearchOptions};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging
     tracing_subscriber::fmt::init();
 
-    let client = JobsucheAsync::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )
-    .await?;
+    let client = JobsucheAsync::default_client()?;
 
     println!("🌊 Stream-based job search - constant memory usage!\n");
 
@@ -43,7 +39,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     count,
                     job.beruf.as_deref().unwrap_or("Unknown"),
                     job.arbeitgeber.as_deref().unwrap_or("Unknown"),
-                    job.arbeitsort.ort.as_deref().unwrap_or("unknown")
+                    job.arbeitsort.short()
                 );
             }
             Err(e) => {
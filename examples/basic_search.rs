@@ -4,17 +4,14 @@
 //!
 //! Run with: cargo run --example basic_search
 
-use jobsuche::{Arbeitszeit, Credentials, Jobsuche, SearchOptions};
+use jobsuche::{Arbeitszeit, Jobsuche, SearchOptions, StatusCode};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging (optional - uncomment if you want debug output)
     // tracing_subscriber::fmt::init();
 
     // Create a client with the default API key
-    let client = Jobsuche::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )?;
+    let client = Jobsuche::default_client()?;
 
     println!("🔍 Searching for Rust Developer jobs in Germany...\n");
 
@@ -39,11 +36,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             "   Company: {}",
             job.arbeitgeber.as_deref().unwrap_or("Unknown")
         );
-        println!(
-            "   Location: {}, {}",
-            job.arbeitsort.ort.as_deref().unwrap_or("unknown"),
-            job.arbeitsort.region.as_deref().unwrap_or("unknown")
-        );
+        println!("   Location: {}", job.arbeitsort);
         if let Some(date) = &job.aktuelle_veroeffentlichungsdatum {
             println!("   Published: {}", date);
         }
@@ -80,12 +73,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("\nWork locations:");
                 for location in &details.arbeitsorte {
                     if let Some(adresse) = &location.adresse {
-                        println!(
-                            "  - {}, {} {}",
-                            adresse.ort.as_deref().unwrap_or("unknown"),
-                            adresse.plz.as_deref().unwrap_or(""),
-                            adresse.region.as_deref().unwrap_or("unknown")
-                        );
+                        println!("  - {}", adresse);
                     }
                 }
 
@@ -93,9 +81,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("\nAllianzpartner: {}", partner);
                 }
             }
+            Err(e) if e.status_code() == Some(StatusCode::NOT_FOUND) => {
+                println!("⚠️  Job details not found: {}", e);
+                println!("(This is common - jobs expire quickly)");
+            }
             Err(e) => {
                 println!("⚠️  Could not get job details: {}", e);
-                println!("(This is common - jobs expire quickly)");
             }
         }
     }
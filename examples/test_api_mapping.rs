@@ -2,13 +2,10 @@
 //!
 //! Run with: cargo run --example test_api_mapping
 
-use jobsuche::{Credentials, Jobsuche};
+use jobsuche::Jobsuche;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = Jobsuche::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )?;
+    let client = Jobsuche::default_client()?;
 
     let refnr = "12265-443677_JB4946295-S";
     println!("Testing job_details for: {}", refnr);
@@ -30,9 +27,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("  arbeitsorte: {} locations", details.arbeitsorte.len());
             for (i, loc) in details.arbeitsorte.iter().enumerate() {
-                if let Some(addr) = &loc.adresse {
-                    println!("    Location {}: {:?}, {:?}", i + 1, addr.ort, addr.plz);
-                }
+                println!(
+                    "    Location {}: {:?}, {:?}, {:?}",
+                    i + 1,
+                    loc.ort(),
+                    loc.plz(),
+                    loc.coordinates()
+                );
             }
 
             println!("  verguetung: {:?}", details.verguetung);
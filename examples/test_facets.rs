@@ -2,13 +2,10 @@
 //!
 //! Run with: cargo run --example test_facets
 
-use jobsuche::{Credentials, Jobsuche, SearchOptions};
+use jobsuche::{Arbeitszeit, Jobsuche, SearchOptions};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = Jobsuche::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )?;
+    let client = Jobsuche::default_client()?;
 
     let options = SearchOptions::builder()
         .was("Softwareentwickler")
@@ -31,6 +28,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     if let Some(facetten) = &results.facetten {
+        println!(
+            "\nFull-time: {}, part-time: {}",
+            facetten.arbeitszeit_count(Arbeitszeit::Vollzeit),
+            facetten.arbeitszeit_count(Arbeitszeit::Teilzeit)
+        );
+        if !facetten.other.is_empty() {
+            let unknown: Vec<_> = facetten.other.keys().collect();
+            println!("Unmodelled facet groups: {:?}", unknown);
+        }
+
         println!("\nFacets data:");
         println!("{}", serde_json::to_string_pretty(facetten)?);
     }
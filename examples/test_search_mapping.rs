@@ -2,13 +2,10 @@
 //!
 //! Run with: cargo run --example test_search_mapping
 
-use jobsuche::{Credentials, Jobsuche, SearchOptions};
+use jobsuche::{Jobsuche, SearchOptions};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = Jobsuche::new(
-        "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-        Credentials::default(),
-    )?;
+    let client = Jobsuche::default_client()?;
 
     println!("Testing Job Search API mapping...\n");
 
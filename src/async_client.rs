@@ -2,36 +2,61 @@
 //!
 //! This module provides an async/await interface for non-blocking API calls.
 
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
-use tracing::{debug, warn};
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use tokio::io::AsyncWriteExt;
+
+use tracing::{debug, warn, Instrument, Span};
 
 use backon::{BackoffBuilder, ExponentialBuilder};
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+};
 use reqwest::{Client, Method, StatusCode};
 use serde::de::DeserializeOwned;
 
-use crate::core::{encode_refnr, ClientCore};
+use crate::auth::{self, TokenManager};
+#[cfg(feature = "cache")]
+use crate::cache::{
+    CacheHandle, DetailsLookup, InFlight, ResponseCache, StaleDetails, WarmupReport,
+};
+use crate::core::{
+    encode_refnr, env_var, image_extension, insert_request_id, parse_retry_after, validate_refnr,
+    ClientCore, DEFAULT_HOST, HOST_ENV,
+};
+use crate::errors::{decode_json, fault_body};
+use crate::hooks::{self, Endpoint};
+#[cfg(feature = "metrics")]
+use crate::metrics::{ClientMetrics, MetricsSnapshot};
+use crate::rate_limit::RateLimiter;
+use crate::request_options::RequestOptions;
 use crate::search::SearchAsync;
 use crate::sync::ClientConfig;
-use crate::{ApiErrors, Credentials, Error, JobDetails, Result};
+use crate::telemetry::{self, RetryWaits, WaitReason};
+use crate::{
+    ApiErrors, Conditional, Credentials, Error, JobDetails, JobSearchResponse, RequestContext,
+    Result, SearchOptions,
+};
 
 /// Asynchronous Jobsuche API client
 ///
 /// This is the async counterpart to the synchronous [`Jobsuche`](crate::Jobsuche) client.
 /// It uses async/await for non-blocking I/O operations.
 ///
+/// Cloning is cheap: all clones share the same connection pool and configuration.
+///
 /// # Example
 ///
 /// ```no_run
-/// use jobsuche::{JobsucheAsync, Credentials, SearchOptions};
+/// use jobsuche::{JobsucheAsync, SearchOptions};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-///     let client = JobsucheAsync::new(
-///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-///         Credentials::default()
-///     ).await?;
+///     let client = JobsucheAsync::default_client()?;
 ///
 ///     // Search for jobs asynchronously
 ///     let results = client.search()
@@ -48,9 +73,24 @@ use crate::{ApiErrors, Credentials, Error, JobDetails, Result};
 /// ```
 #[derive(Clone, Debug)]
 pub struct JobsucheAsync {
-    pub(crate) core: ClientCore,
+    inner: Arc<ClientInner>,
+}
+
+/// State shared between clones of a [`JobsucheAsync`] client
+#[derive(Debug)]
+struct ClientInner {
+    core: ClientCore,
     client: Client,
     config: ClientConfig,
+    rate_limiter: Option<RateLimiter>,
+    token_manager: Option<TokenManager>,
+    #[cfg(feature = "cache")]
+    cache: Option<Arc<ResponseCache>>,
+    /// Details lookups waiting for the cache, see [`InFlight`]
+    #[cfg(feature = "cache")]
+    in_flight: InFlight,
+    #[cfg(feature = "metrics")]
+    metrics: ClientMetrics,
 }
 
 impl JobsucheAsync {
@@ -58,28 +98,50 @@ impl JobsucheAsync {
     ///
     /// # Arguments
     ///
-    /// * `host` - Base URL of the API
+    /// * `host` - Base URL of the API (typically [`crate::DEFAULT_HOST`])
     /// * `credentials` - Authentication credentials
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials};
+    /// use jobsuche::{JobsucheAsync, Credentials, DEFAULT_HOST};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::new(DEFAULT_HOST, Credentials::default())?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn new<H>(host: H, credentials: Credentials) -> Result<JobsucheAsync>
+    pub fn new<H>(host: H, credentials: Credentials) -> Result<JobsucheAsync>
     where
         H: Into<String>,
     {
-        Self::with_config(host, credentials, ClientConfig::default()).await
+        Self::with_config(host, credentials, ClientConfig::default())
+    }
+
+    /// Creates an async client for the public API ([`DEFAULT_HOST`]) using the default API key
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::JobsucheAsync;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn default_client() -> Result<JobsucheAsync> {
+        Self::new(DEFAULT_HOST, Credentials::default())
+    }
+
+    /// Creates an async client configured from the environment
+    ///
+    /// See [`Jobsuche::from_env`](crate::Jobsuche::from_env) for the variables read.
+    pub fn from_env() -> Result<JobsucheAsync> {
+        let host = env_var(HOST_ENV).unwrap_or_else(|| DEFAULT_HOST.to_string());
+        Self::new(host, Credentials::from_env())
     }
 
     /// Creates a new async instance with custom configuration
@@ -87,7 +149,7 @@ impl JobsucheAsync {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials, ClientConfig};
+    /// use jobsuche::{JobsucheAsync, Credentials, ClientConfig, DEFAULT_HOST};
     /// use std::time::Duration;
     ///
     /// #[tokio::main]
@@ -99,14 +161,14 @@ impl JobsucheAsync {
     ///     };
     ///
     ///     let client = JobsucheAsync::with_config(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
+    ///         DEFAULT_HOST,
     ///         Credentials::default(),
     ///         config
-    ///     ).await?;
+    ///     )?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn with_config<H>(
+    pub fn with_config<H>(
         host: H,
         credentials: Credentials,
         config: ClientConfig,
@@ -115,59 +177,223 @@ impl JobsucheAsync {
         H: Into<String>,
     {
         let core = ClientCore::new(host, credentials)?;
+        Self::with_config_and_core(core, config)
+    }
 
-        let client = Client::builder()
-            .timeout(config.timeout)
-            .connect_timeout(config.connect_timeout)
-            .build()?;
-
-        Ok(JobsucheAsync {
-            core,
-            client,
-            config,
-        })
+    /// Creates a new async instance using a custom reqwest client
+    ///
+    /// This is the extension point for HTTP-level customization such as proxies, default
+    /// headers (e.g. tracing propagation) or custom connectors. Retries (per
+    /// [`ClientConfig`]) and the request/response hooks run around every call made through
+    /// this client, so anything configured on the reqwest client applies to each individual
+    /// attempt. Timeout and TLS settings from `config` are ignored; configure them on the
+    /// client instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{ClientConfig, Credentials, JobsucheAsync, DEFAULT_HOST};
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("traceparent", HeaderValue::from_static("00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01"));
+    ///
+    /// let client = reqwest::Client::builder()
+    ///     .default_headers(headers)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let jobsuche = JobsucheAsync::from_client(
+    ///     DEFAULT_HOST,
+    ///     Credentials::default(),
+    ///     client,
+    ///     ClientConfig::default(),
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_client<H>(
+        host: H,
+        credentials: Credentials,
+        client: Client,
+        config: ClientConfig,
+    ) -> Result<JobsucheAsync>
+    where
+        H: Into<String>,
+    {
+        let core = ClientCore::new(host, credentials)?;
+        Self::from_parts(core, client, config)
     }
 
     /// Creates an async client from an existing ClientCore
-    pub async fn with_core(core: ClientCore) -> Result<JobsucheAsync> {
-        Self::with_config_and_core(core, ClientConfig::default()).await
+    pub fn with_core(core: ClientCore) -> Result<JobsucheAsync> {
+        Self::with_config_and_core(core, ClientConfig::default())
     }
 
     /// Creates an async client from ClientCore with custom config
-    pub async fn with_config_and_core(
-        core: ClientCore,
-        config: ClientConfig,
-    ) -> Result<JobsucheAsync> {
-        let client = Client::builder()
+    pub fn with_config_and_core(core: ClientCore, config: ClientConfig) -> Result<JobsucheAsync> {
+        let mut builder = Client::builder()
             .timeout(config.timeout)
             .connect_timeout(config.connect_timeout)
-            .build()?;
+            .danger_accept_invalid_certs(config.danger_accept_invalid_certs);
+        for certificate in config.root_certificates()? {
+            builder = builder.add_root_certificate(certificate);
+        }
+        if let Some(version) = config.min_tls_version {
+            builder = builder.min_tls_version(version);
+        }
+        let client = builder.build()?;
+
+        Self::from_parts(core, client, config)
+    }
 
+    /// Assemble a client; shared by the public constructors
+    fn from_parts(core: ClientCore, client: Client, config: ClientConfig) -> Result<JobsucheAsync> {
+        let rate_limiter = config.rate_limit.map(RateLimiter::new).transpose()?;
+        let token_manager = TokenManager::from_credentials(&core.credentials);
+        #[cfg(feature = "cache")]
+        let cache = ResponseCache::from_config(&config, core.host.as_str())?.map(Arc::new);
         Ok(JobsucheAsync {
-            core,
-            client,
-            config,
+            inner: Arc::new(ClientInner {
+                core,
+                client,
+                rate_limiter,
+                token_manager,
+                #[cfg(feature = "cache")]
+                cache,
+                #[cfg(feature = "cache")]
+                in_flight: InFlight::default(),
+                #[cfg(feature = "metrics")]
+                metrics: ClientMetrics::default(),
+                config,
+            }),
         })
     }
 
+    /// Return a client for a different base URL
+    ///
+    /// The new client shares this client's HTTP connection pool and configuration and keeps
+    /// its credentials; see [`ClientCore::rebase`]. Client-side rate limiting, cached OAuth
+    /// tokens and the in-memory response cache start fresh for the new host; a shared
+    /// [`cache_backend`](crate::ClientConfig::cache_backend) keeps entries per host. This
+    /// client is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::JobsucheAsync;
+    ///
+    /// let production = JobsucheAsync::default_client().unwrap();
+    /// let staging = production.with_host("https://staging.example.com/jobsuche-service").unwrap();
+    /// ```
+    pub fn with_host<H>(&self, host: H) -> Result<JobsucheAsync>
+    where
+        H: Into<String>,
+    {
+        let core = self.inner.core.rebase(host)?;
+        Self::from_parts(core, self.inner.client.clone(), self.inner.config.clone())
+    }
+
+    /// Get the core (host and credentials) used by this client
+    pub fn core(&self) -> &ClientCore {
+        &self.inner.core
+    }
+
+    /// Get the configuration used by this client
+    pub fn config(&self) -> &ClientConfig {
+        &self.inner.config
+    }
+
+    /// Counters and latency statistics of the requests sent by this client and its clones
+    /// (requires the `metrics` feature)
+    ///
+    /// Independent of the recorder installed for the [`metrics`](crate::metrics) facade.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.inner.metrics.snapshot()
+    }
+
     /// Return async search interface
+    ///
+    /// This only clones an `Arc`, so calling it per incoming request is fine.
     pub fn search(&self) -> SearchAsync {
         SearchAsync::new(self)
     }
 
+    /// Run several searches with bounded concurrency (async)
+    ///
+    /// Results are returned in the same order as `queries`, each paired with its options.
+    /// A failing query (e.g. a 500 after retries) is reported in its own slot and does not
+    /// abort the batch. Every request goes through the client's retry configuration and, if
+    /// [`ClientConfig::rate_limit`] is set, the shared rate limiter, so the whole batch stays
+    /// within the configured rate. A `concurrency` of 0 is treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let titles = ["Rust", "Go", "Python"];
+    ///     let queries = titles
+    ///         .iter()
+    ///         .map(|was| SearchOptions::builder().was(was).wo("Berlin").build())
+    ///         .collect();
+    ///
+    ///     let results = client.search_many(queries, 3).await;
+    ///     for (title, (_, result)) in titles.iter().zip(results) {
+    ///         match result {
+    ///             Ok(results) => println!("{}: {:?} jobs", title, results.max_ergebnisse),
+    ///             Err(e) => println!("{}: {}", title, e),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn search_many(
+        &self,
+        queries: Vec<SearchOptions>,
+        concurrency: usize,
+    ) -> Vec<(SearchOptions, Result<JobSearchResponse>)> {
+        let search = self.search();
+        let mut results: Vec<_> = futures::stream::iter(queries.into_iter().enumerate())
+            .map(|(index, options)| {
+                let search = &search;
+                async move {
+                    let result = search.list(options.clone()).await;
+                    (index, options, result)
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+        results
+            .into_iter()
+            .map(|(_, options, result)| (options, result))
+            .collect()
+    }
+
     /// Get detailed information about a specific job (async)
     ///
+    /// # Errors
+    ///
+    /// `refnr` must be the decoded reference number. An already base64-encoded value (as
+    /// found in arbeitsagentur.de URLs) is rejected with [`Error::InvalidRefnr`] naming the
+    /// decoded form, rather than being used silently; see
+    /// [`ClientConfig::validate_refnr`](crate::ClientConfig::validate_refnr).
+    ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials};
+    /// use jobsuche::JobsucheAsync;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::default_client()?;
     ///
     ///     let job = client.job_details("10001-1001601666-S").await?;
     ///     if let Some(title) = &job.titel {
@@ -176,10 +402,335 @@ impl JobsucheAsync {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn job_details(&self, refnr: &str) -> Result<JobDetails> {
+    pub async fn job_details(&self, refnr: impl AsRef<str>) -> Result<JobDetails> {
+        self.job_details_with(refnr, &RequestOptions::default())
+            .await
+    }
+
+    /// Get job details with per-request overrides (async)
+    ///
+    /// Like [`job_details`](Self::job_details), but applies `options` to this call only.
+    /// With [`ClientConfig::cache`](crate::ClientConfig::cache) set, details fetched within
+    /// the TTL are returned from the cache unless `options.cache_bypass` is set. Expired
+    /// details with an `ETag` are revalidated with `If-None-Match`; see
+    /// [`CacheConfig::revalidate_for`](crate::CacheConfig::revalidate_for).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{JobsucheAsync, RequestOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let options = RequestOptions {
+    ///         skip_retry: true,
+    ///         ..Default::default()
+    ///     };
+    ///     let job = client.job_details_with("10001-1001601666-S", &options).await?;
+    ///     println!("{:?}", job.titel);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn job_details_with(
+        &self,
+        refnr: impl AsRef<str>,
+        options: &RequestOptions,
+    ) -> Result<JobDetails> {
+        let refnr = refnr.as_ref();
+        let path = self.job_details_path(refnr)?;
+        let span = telemetry::details_span(refnr);
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.inner.cache {
+            return self
+                .cached_job_details(cache, refnr, &path, options)
+                .instrument(span)
+                .await;
+        }
+        self.get(Endpoint::JobDetails, &path, options)
+            .instrument(span)
+            .await
+            .map_err(|e| e.with_id(refnr))
+    }
+
+    /// Job details via the cache
+    ///
+    /// Concurrent lookups of the same refnr wait for the first one, so only it sends a
+    /// request unless the response is not cached.
+    #[cfg(feature = "cache")]
+    async fn cached_job_details(
+        &self,
+        cache: &Arc<ResponseCache>,
+        refnr: &str,
+        path: &str,
+        options: &RequestOptions,
+    ) -> Result<JobDetails> {
+        let _in_flight = self.inner.in_flight.lock(refnr).await;
+        let key = refnr.to_string();
+        let fresh = options.cache_bypass;
+        let stale = match cache
+            .run(move |cache| cache.lookup_details(&key, fresh))
+            .await
+        {
+            DetailsLookup::Hit(details) => return Ok(details),
+            DetailsLookup::NotFound => return Err(DetailsLookup::not_found(refnr, path)),
+            DetailsLookup::Revalidate(stale) => Some(stale),
+            DetailsLookup::Miss => None,
+        };
+        self.fetch_details(cache, refnr, path, stale, options).await
+    }
+
+    /// Request the details of `refnr`, revalidating `stale`, and update the cache
+    #[cfg(feature = "cache")]
+    async fn fetch_details(
+        &self,
+        cache: &Arc<ResponseCache>,
+        refnr: &str,
+        path: &str,
+        stale: Option<StaleDetails>,
+        options: &RequestOptions,
+    ) -> Result<JobDetails> {
+        let etag = stale.as_ref().and_then(|stale| stale.etag());
+        let response = self
+            .get_conditional(Endpoint::JobDetails, path, etag, options)
+            .await;
+        let key = refnr.to_string();
+        match response {
+            Ok(response) => Ok(cache
+                .run(move |cache| cache.complete_details(&key, stale, response))
+                .await),
+            Err(e) => {
+                if e.is_not_found() {
+                    cache.run(move |cache| cache.store_not_found(&key)).await;
+                }
+                Err(e.with_id(refnr))
+            }
+        }
+    }
+
+    /// Get job details from the API even if they are cached (async)
+    ///
+    /// Same as [`job_details_with`](Self::job_details_with) with
+    /// [`RequestOptions::cache_bypass`]: the response replaces the cached entry.
+    ///
+    /// If the cached entry has an `ETag`, the request sends `If-None-Match`, and a
+    /// 304 Not Modified returns the cached details without downloading them again.
+    #[cfg(feature = "cache")]
+    pub async fn job_details_fresh(&self, refnr: impl AsRef<str>) -> Result<JobDetails> {
+        let options = RequestOptions {
+            cache_bypass: true,
+            ..Default::default()
+        };
+        self.job_details_with(refnr, &options).await
+    }
+
+    /// Invalidate or clear cached responses
+    ///
+    /// See [`CacheHandle`]; its methods do nothing if no cache is configured.
+    #[cfg(feature = "cache")]
+    pub fn cache(&self) -> CacheHandle<'_> {
+        CacheHandle::new(self.inner.cache.as_deref())
+    }
+
+    #[cfg(feature = "cache")]
+    pub(crate) fn response_cache(&self) -> Option<&Arc<ResponseCache>> {
+        self.inner.cache.as_ref()
+    }
+
+    /// Fetch and cache the details of `refnrs` ahead of time, with at most `concurrency`
+    /// requests in flight (async)
+    ///
+    /// Like [`Jobsuche::warm_details_cache`](crate::Jobsuche::warm_details_cache): refnrs
+    /// with fresh cached details or a remembered 404 are skipped, and failures are counted
+    /// in the report. All requests share the client's rate limiter. A `concurrency` of 0 is
+    /// treated as 1.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ConfigError`] if the client has no cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{
+    ///     CacheConfig, ClientConfig, Credentials, JobsucheAsync, SearchOptions, DEFAULT_HOST,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = ClientConfig {
+    ///         cache: Some(CacheConfig::default()),
+    ///         ..Default::default()
+    ///     };
+    ///     let client = JobsucheAsync::with_config(DEFAULT_HOST, Credentials::default(), config)?;
+    ///
+    ///     let results = client
+    ///         .search()
+    ///         .list(SearchOptions::builder().was("Rust").size(50).build())
+    ///         .await?;
+    ///     let refnrs: Vec<_> = results.stellenangebote.into_iter().map(|j| j.refnr).collect();
+    ///
+    ///     let report = client.warm_details_cache(&refnrs, 5).await?;
+    ///     println!("{:?}", report);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "cache")]
+    pub async fn warm_details_cache<R: AsRef<str>>(
+        &self,
+        refnrs: &[R],
+        concurrency: usize,
+    ) -> Result<WarmupReport> {
+        let cache = self
+            .inner
+            .cache
+            .as_ref()
+            .ok_or_else(WarmupReport::no_cache)?;
+        let outcomes: Vec<_> = futures::stream::iter(refnrs)
+            .map(|refnr| async move {
+                let refnr = refnr.as_ref();
+                (refnr, self.warm_details(cache, refnr).await)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut report = WarmupReport::default();
+        for (refnr, outcome) in outcomes {
+            report.record(refnr, outcome);
+        }
+        Ok(report)
+    }
+
+    /// Fetch the details of `refnr` unless cached; true if a request was sent
+    #[cfg(feature = "cache")]
+    async fn warm_details(&self, cache: &Arc<ResponseCache>, refnr: &str) -> Result<bool> {
+        let path = self.job_details_path(refnr)?;
+        let _in_flight = self.inner.in_flight.lock(refnr).await;
+        let key = refnr.to_string();
+        let stale = match cache
+            .run(move |cache| cache.lookup_details(&key, false))
+            .await
+        {
+            DetailsLookup::Hit(_) | DetailsLookup::NotFound => return Ok(false),
+            DetailsLookup::Revalidate(stale) => Some(stale),
+            DetailsLookup::Miss => None,
+        };
+        self.fetch_details(cache, refnr, &path, stale, &RequestOptions::default())
+            .instrument(telemetry::details_span(refnr))
+            .await?;
+        Ok(true)
+    }
+
+    /// The validated path of the details of `refnr`
+    fn job_details_path(&self, refnr: &str) -> Result<String> {
+        if self.inner.config.validate_refnr {
+            validate_refnr(refnr)?;
+        }
+        let encoded = encode_refnr(refnr);
+        Ok(self.inner.core.path(&["pc", "v4", "jobdetails", &encoded]))
+    }
+
+    /// Get job details only if they changed since `etag` was issued (async)
+    ///
+    /// Sends `If-None-Match` when `etag` is given. A 304 response yields
+    /// [`Conditional::NotModified`] without attempting to deserialize a body; a 200 response
+    /// yields the details together with the new `ETag` (if the server sent one). Retries
+    /// follow the client configuration, so a transient 503 followed by a 304 still succeeds.
+    /// The response cache is neither read nor written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{Conditional, JobsucheAsync};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let first = client.job_details_conditional("10001-1001601666-S", None).await?;
+    ///     let etag = first.etag().map(String::from);
+    ///
+    ///     match client.job_details_conditional("10001-1001601666-S", etag.as_deref()).await? {
+    ///         Conditional::NotModified => println!("Unchanged"),
+    ///         Conditional::Modified { value, .. } => println!("Updated: {:?}", value.titel),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn job_details_conditional(
+        &self,
+        refnr: impl AsRef<str>,
+        etag: Option<&str>,
+    ) -> Result<Conditional<JobDetails>> {
+        let refnr = refnr.as_ref();
+        if self.inner.config.validate_refnr {
+            validate_refnr(refnr)?;
+        }
         let encoded = encode_refnr(refnr);
-        let path = self.core.path(&["pc", "v4", "jobdetails", &encoded]);
-        self.get(&path).await
+        let path = self.inner.core.path(&["pc", "v4", "jobdetails", &encoded]);
+        self.get_conditional(
+            Endpoint::JobDetails,
+            &path,
+            etag,
+            &RequestOptions::default(),
+        )
+        .instrument(telemetry::details_span(refnr))
+        .await
+        .map_err(|e| e.with_id(refnr))
+    }
+
+    /// Fetch details for many jobs with at most `concurrency` requests in flight
+    ///
+    /// Results are returned in the same order as `refnrs`, paired with their reference number.
+    /// Failures (e.g. [`Error::NotFound`] for expired jobs) are reported per item and do not
+    /// abort the remaining requests. Each request uses the client's retry configuration.
+    /// A `concurrency` of 0 is treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{JobsucheAsync, Refnr, SearchOptions};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let results = client
+    ///         .search()
+    ///         .list(SearchOptions::builder().was("Rust").size(50).build())
+    ///         .await?;
+    ///     let refnrs: Vec<Refnr> = results.stellenangebote.into_iter().map(|j| j.refnr).collect();
+    ///
+    ///     for (refnr, details) in client.job_details_concurrent(&refnrs, 5).await {
+    ///         match details {
+    ///             Ok(job) => println!("{}: {:?}", refnr, job.titel),
+    ///             Err(e) => println!("{}: {}", refnr, e),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn job_details_concurrent<R: AsRef<str>>(
+        &self,
+        refnrs: &[R],
+        concurrency: usize,
+    ) -> Vec<(String, Result<JobDetails>)> {
+        let mut results: Vec<_> = futures::stream::iter(refnrs.iter().enumerate())
+            .map(|(index, refnr)| async move {
+                let refnr = refnr.as_ref();
+                (index, refnr.to_string(), self.job_details(refnr).await)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+        results
+            .into_iter()
+            .map(|(_, refnr, result)| (refnr, result))
+            .collect()
     }
 
     /// Get the logo of an employer (async)
@@ -189,14 +740,11 @@ impl JobsucheAsync {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials};
+    /// use jobsuche::JobsucheAsync;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::default_client()?;
     ///
     ///     match client.employer_logo("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=").await {
     ///         Ok(logo_bytes) => println!("Got logo: {} bytes", logo_bytes.len()),
@@ -206,29 +754,204 @@ impl JobsucheAsync {
     /// }
     /// ```
     pub async fn employer_logo(&self, hash_id: &str) -> Result<Vec<u8>> {
-        let path = self.core.path(&["ed", "v1", "arbeitgeberlogo", hash_id]);
+        self.employer_logo_with(hash_id, &RequestOptions::default())
+            .await
+    }
+
+    /// Get the logo of an employer with per-request overrides (async)
+    ///
+    /// Like [`employer_logo`](Self::employer_logo), but applies `options` to this call only.
+    /// Logo requests are never retried, so [`RequestOptions::skip_retry`] has no effect here.
+    pub async fn employer_logo_with(
+        &self,
+        hash_id: &str,
+        options: &RequestOptions,
+    ) -> Result<Vec<u8>> {
+        let response = self.employer_logo_response(hash_id, options).await?;
+        let bytes = response.bytes().await?.to_vec();
+        Ok(bytes)
+    }
+
+    /// Stream the logo of an employer without buffering it in memory (async)
+    ///
+    /// Status codes are mapped to errors before the stream is returned (e.g. 404 becomes
+    /// [`Error::NotFound`]); errors while reading the body are yielded by the stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use jobsuche::JobsucheAsync;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let mut chunks = client
+    ///         .employer_logo_stream("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=")
+    ///         .await?;
+    ///     while let Some(chunk) = chunks.next().await {
+    ///         println!("Received {} bytes", chunk?.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn employer_logo_stream(
+        &self,
+        hash_id: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin + 'static> {
+        let response = self
+            .employer_logo_response(hash_id, &RequestOptions::default())
+            .await?;
+        Ok(response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(Error::from)))
+    }
+
+    /// Download the logo of an employer directly to a file (async)
+    ///
+    /// The response body is streamed to disk chunk by chunk instead of being buffered in
+    /// memory. The image format is detected from the downloaded bytes and the extension of
+    /// `path` is replaced accordingly (e.g. `logo` becomes `logo.png`); unknown formats keep
+    /// `path` unchanged. The file is written to a temporary sibling first and renamed into
+    /// place once complete, so readers never observe a partial logo.
+    ///
+    /// Returns the path of the written file.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::JobsucheAsync;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let written = client
+    ///         .employer_logo_to_file("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=", "logo")
+    ///         .await?;
+    ///     println!("Saved logo to {}", written.display());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn employer_logo_to_file(
+        &self,
+        hash_id: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<PathBuf> {
+        let path = path.as_ref();
+        let response = self
+            .employer_logo_response(hash_id, &RequestOptions::default())
+            .await?;
+
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".part");
+        let partial = PathBuf::from(partial);
+
+        match write_stream_to_file(response, &partial).await {
+            Ok(extension) => {
+                let target = match extension {
+                    Some(extension) => path.with_extension(extension),
+                    None => path.to_path_buf(),
+                };
+                tokio::fs::rename(&partial, &target).await?;
+                Ok(target)
+            }
+            Err(e) => {
+                // Best effort cleanup; the original error is more useful than a cleanup failure
+                let _ = tokio::fs::remove_file(&partial).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// Send the logo request and map error statuses
+    async fn employer_logo_response(
+        &self,
+        hash_id: &str,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response> {
+        // Logos are not retried, so there is exactly one attempt
+        let span = telemetry::request_span(&Method::GET, Endpoint::EmployerLogo);
+        span.record("attempts", 1);
+        let attempt = span.in_scope(|| telemetry::attempt_span(1));
+        let started = Instant::now();
+        let result = self
+            .employer_logo_attempt(hash_id, options)
+            .instrument(attempt.clone())
+            .await;
+        telemetry::record_result(&attempt, &result, started.elapsed());
+        telemetry::record_result(&span, &result, started.elapsed());
+        result
+    }
+
+    async fn employer_logo_attempt(
+        &self,
+        hash_id: &str,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response> {
+        let path = self
+            .inner
+            .core
+            .path(&["ed", "v1", "arbeitgeberlogo", hash_id]);
 
         let mut headers = HeaderMap::new();
-        headers.insert(
-            "X-API-Key",
-            HeaderValue::from_str(self.core.api_key()).unwrap(),
-        );
+        self.insert_auth_header(&mut headers).await?;
         headers.insert(ACCEPT, HeaderValue::from_static("image/png"));
+        options.apply_headers(&mut headers)?;
+        let request_id =
+            insert_request_id(&mut headers, self.inner.config.request_id_header.as_deref())?;
 
-        let response = self
+        self.wait_for_rate_limit().await;
+        let hook_attempt = hooks::Attempt {
+            method: &Method::GET,
+            endpoint: Endpoint::EmployerLogo,
+            url: &path,
+            number: 1,
+        };
+        hooks::notify_request(&self.inner.config, &hook_attempt, &headers);
+        let start = Instant::now();
+        let mut request = self
+            .inner
             .client
             .request(Method::GET, &path)
-            .headers(headers)
-            .send()
-            .await?;
+            .headers(headers);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                self.notify_metric(&Method::GET, Endpoint::EmployerLogo, None, start.elapsed());
+                return Err(e.into());
+            }
+        };
 
         let status = response.status();
+        let elapsed = start.elapsed();
+        self.notify_metric(&Method::GET, Endpoint::EmployerLogo, Some(status), elapsed);
+        telemetry::record_status(status);
+        hooks::notify_response(
+            &self.inner.config,
+            &hook_attempt,
+            status,
+            elapsed,
+            None,
+            response.content_length(),
+        );
         if !status.is_success() {
-            return Err(self.error_from_status(status, response).await);
+            return Err(self
+                .error_from_status(
+                    status,
+                    response,
+                    request_id,
+                    RequestContext::new(Endpoint::EmployerLogo, &path),
+                )
+                .await
+                .with_id(hash_id));
         }
 
-        let bytes = response.bytes().await?.to_vec();
-        Ok(bytes)
+        Ok(response)
     }
 
     /// Internal method to perform async GET requests with retry logic
@@ -237,161 +960,508 @@ impl JobsucheAsync {
     /// a `Retry-After` header, the client sleeps for the specified duration before
     /// retrying. For other transient errors (5xx, timeouts), exponential backoff is
     /// used instead.
-    pub(crate) async fn get<T>(&self, path: &str) -> Result<T>
+    pub(crate) async fn get<T>(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        match self.get_conditional(endpoint, path, None, options).await? {
+            Conditional::Modified { value, .. } => Ok(value),
+            // get_once only reports NotModified when an ETag was sent
+            Conditional::NotModified => unreachable!("304 without If-None-Match"),
+        }
+    }
+
+    /// Retrying GET that sends `If-None-Match` when `etag` is given
+    async fn get_conditional<T>(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<Conditional<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let span = telemetry::request_span(&Method::GET, endpoint);
+        let started = Instant::now();
+        let mut waits = RetryWaits::default();
+        let mut attempts = 0;
+        let result = self
+            .get_retrying(endpoint, path, etag, options, &mut attempts, &mut waits)
+            .instrument(span.clone())
+            .await;
+        span.record("attempts", attempts);
+        telemetry::record_result(&span, &result, started.elapsed());
+        waits.warn_if_over(self.inner.config.retry_wait_warning, endpoint);
+        result
+    }
+
+    /// Retry loop of [`get_conditional`](Self::get_conditional), counting the attempts in
+    /// `attempts` and the sleeps in `waits`
+    async fn get_retrying<T>(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        options: &RequestOptions,
+        attempts: &mut u32,
+        waits: &mut RetryWaits,
+    ) -> Result<Conditional<T>>
     where
         T: DeserializeOwned,
     {
-        if !self.config.retry_enabled {
-            return self.get_once(path).await;
+        if !self.inner.config.retry_enabled || options.skip_retry {
+            *attempts = 1;
+            return self.get_attempt(1, endpoint, path, etag, options).await.0;
         }
 
         // Build exponential backoff strategy
         let backoff = ExponentialBuilder::default()
-            .with_max_times(self.config.max_retries as usize)
+            .with_max_times(self.inner.config.max_retries as usize)
             .with_max_delay(Duration::from_secs(60));
 
         let mut attempt = 0;
         let mut backoff_iter = backoff.build();
+        let started = Instant::now();
+        let mut last_error: Option<Error> = None;
 
         loop {
             attempt += 1;
+            *attempts = attempt;
             debug!(
                 "GET {} (async, attempt {}/{})",
                 path,
                 attempt,
-                self.config.max_retries + 1
+                self.inner.config.max_retries + 1
             );
 
-            match self.get_once(path).await {
+            // Sleeps are checked against the deadline below; attempts (including hedged
+            // requests) are cut off when it runs out
+            let attempt_future = self.get_attempt(attempt, endpoint, path, etag, options);
+            let (result, attempt_span) = match self.inner.config.retry_deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_sub(started.elapsed());
+                    match tokio::time::timeout(remaining, attempt_future).await {
+                        Ok(outcome) => outcome,
+                        Err(_) => {
+                            warn!(
+                                "Retry deadline of {:?} exceeded during attempt {}",
+                                deadline, attempt
+                            );
+                            return Err(match last_error {
+                                Some(e) => e.retries_exhausted(attempt, started.elapsed()),
+                                None => Error::DeadlineExceeded { deadline },
+                            });
+                        }
+                    }
+                }
+                None => attempt_future.await,
+            };
+            match result {
                 Ok(result) => return Ok(result),
                 Err(e) => {
-                    // Check if error is retryable
-                    let should_retry = matches!(
-                        e,
-                        Error::Http(_)
-                            | Error::RateLimited { .. }
-                            | Error::Fault {
-                                code: StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT,
-                                ..
-                            }
-                    );
-
-                    if !should_retry || attempt > self.config.max_retries {
+                    if !e.is_retryable() {
                         return Err(e);
                     }
+                    if attempt > self.inner.config.max_retries {
+                        return Err(e.retries_exhausted(attempt, started.elapsed()));
+                    }
 
                     // Handle rate limiting with Retry-After
-                    if let Error::RateLimited {
-                        retry_after: Some(seconds),
+                    let (delay, reason) = if let Error::RateLimited {
+                        retry_after: Some(wait),
+                        ..
                     } = e
                     {
-                        let duration = Duration::from_secs(seconds);
                         warn!(
-                            "Rate limited, waiting {} seconds as requested by server (attempt {}/{})",
-                            seconds, attempt, self.config.max_retries
+                            "Rate limited, waiting {:?} as requested by server (attempt {}/{})",
+                            wait,
+                            attempt,
+                            self.inner.config.max_retries + 1
                         );
-                        tokio::time::sleep(duration).await;
+                        (wait, WaitReason::RetryAfter)
                     } else if let Some(duration) = backoff_iter.next() {
                         warn!(
                             "Request failed ({}), retrying in {:?}... (attempt {}/{})",
-                            e, duration, attempt, self.config.max_retries
+                            e,
+                            duration,
+                            attempt,
+                            self.inner.config.max_retries + 1
                         );
-                        tokio::time::sleep(duration).await;
+                        (duration, WaitReason::Backoff)
                     } else {
-                        return Err(e);
+                        return Err(e.retries_exhausted(attempt, started.elapsed()));
+                    };
+
+                    if let Some(deadline) = self.inner.config.retry_deadline {
+                        if started.elapsed() + delay > deadline {
+                            warn!(
+                                "Retry deadline of {:?} would be exceeded, giving up after attempt {}",
+                                deadline, attempt
+                            );
+                            return Err(e.retries_exhausted(attempt, started.elapsed()));
+                        }
                     }
+
+                    telemetry::record_retry_after(&attempt_span, delay);
+                    waits.record(reason, delay);
+                    #[cfg(feature = "metrics")]
+                    self.inner.metrics.record_retry(endpoint, reason, delay);
+                    last_error = Some(e);
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
     }
 
+    /// One attempt in its own `jobsuche.attempt` span, returned for recording the retry delay
+    async fn get_attempt<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        options: &RequestOptions,
+    ) -> (Result<Conditional<T>>, Span)
+    where
+        T: DeserializeOwned,
+    {
+        let span = telemetry::attempt_span(attempt);
+        let started = Instant::now();
+        let result = self
+            .get_hedged(attempt, endpoint, path, etag, options)
+            .instrument(span.clone())
+            .await;
+        telemetry::record_result(&span, &result, started.elapsed());
+        (result, span)
+    }
+
+    /// Perform a single attempt, hedged with a duplicate request if `hedge_after` elapses
+    async fn get_hedged<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<Conditional<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let Some(delay) = self.inner.config.hedge_after else {
+            return self.get_once(attempt, endpoint, path, etag, options).await;
+        };
+
+        let first = self.get_once(attempt, endpoint, path, etag, options);
+        tokio::pin!(first);
+        tokio::select! {
+            result = &mut first => return result,
+            _ = tokio::time::sleep(delay) => {}
+        }
+
+        debug!(
+            "No response for {} after {:?}, sending hedged request",
+            path, delay
+        );
+        let second = self.get_once(attempt, endpoint, path, etag, options);
+        tokio::pin!(second);
+
+        // Whichever succeeds first wins; the other future is dropped, cancelling its request
+        tokio::select! {
+            result = &mut first => match result {
+                Ok(value) => Ok(value),
+                Err(_) => second.await,
+            },
+            result = &mut second => match result {
+                Ok(value) => Ok(value),
+                Err(_) => first.await,
+            },
+        }
+    }
+
+    /// Record a request attempt with the client's metrics and the metrics hook, and warn if
+    /// it was slow
+    fn notify_metric(
+        &self,
+        method: &Method,
+        endpoint: Endpoint,
+        status: Option<StatusCode>,
+        duration: Duration,
+    ) {
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record(endpoint, status, duration);
+        let threshold = self.inner.config.slow_request_threshold;
+        if telemetry::warn_if_slow(threshold, endpoint, status, duration) {
+            #[cfg(feature = "metrics")]
+            self.inner.metrics.record_slow(endpoint);
+        }
+        hooks::notify_metric(&self.inner.config, method, endpoint, status, duration);
+    }
+
     /// Perform a single async GET request without retry
-    async fn get_once<T>(&self, path: &str) -> Result<T>
+    async fn get_once<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        options: &RequestOptions,
+    ) -> Result<Conditional<T>>
     where
         T: DeserializeOwned,
     {
         let mut headers = HeaderMap::new();
-        headers.insert(
-            "X-API-Key",
-            HeaderValue::from_str(self.core.api_key()).unwrap(),
-        );
+        self.insert_auth_header(&mut headers).await?;
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        if let Some(etag) = etag {
+            let value = HeaderValue::from_str(etag).map_err(|e| Error::ConfigError {
+                message: format!("invalid ETag {:?}: {}", etag, e),
+            })?;
+            headers.insert(IF_NONE_MATCH, value);
+        }
+        options.apply_headers(&mut headers)?;
+        let request_id =
+            insert_request_id(&mut headers, self.inner.config.request_id_header.as_deref())?;
 
-        let response = self
+        self.wait_for_rate_limit().await;
+        let hook_attempt = hooks::Attempt {
+            method: &Method::GET,
+            endpoint,
+            url: path,
+            number: attempt,
+        };
+        hooks::notify_request(&self.inner.config, &hook_attempt, &headers);
+        let start = Instant::now();
+
+        let mut request = self
+            .inner
             .client
             .request(Method::GET, path)
-            .headers(headers)
-            .send()
-            .await?;
+            .headers(headers);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                self.notify_metric(&Method::GET, endpoint, None, start.elapsed());
+                return Err(e.into());
+            }
+        };
 
         let status = response.status();
-        debug!("Response status: {}", status);
+        let elapsed = start.elapsed();
+        self.notify_metric(&Method::GET, endpoint, Some(status), elapsed);
+        telemetry::record_status(status);
+        debug!(
+            "Response status: {} (request id: {})",
+            status,
+            request_id.as_deref().unwrap_or("none")
+        );
+
+        if status == StatusCode::NOT_MODIFIED && etag.is_some() {
+            hooks::notify_response(
+                &self.inner.config,
+                &hook_attempt,
+                status,
+                elapsed,
+                None,
+                response.content_length(),
+            );
+            return Ok(Conditional::NotModified);
+        }
 
         if !status.is_success() {
-            return Err(self.error_from_status(status, response).await);
+            hooks::notify_response(
+                &self.inner.config,
+                &hook_attempt,
+                status,
+                elapsed,
+                None,
+                response.content_length(),
+            );
+            return Err(self
+                .error_from_status(
+                    status,
+                    response,
+                    request_id,
+                    RequestContext::new(endpoint, path),
+                )
+                .await);
         }
 
-        let result = response.json::<T>().await?;
-        Ok(result)
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        // Buffer the body ourselves (as `Response::json` would) so decode errors can show it
+        let bytes = response.bytes().await?;
+        hooks::notify_response(
+            &self.inner.config,
+            &hook_attempt,
+            status,
+            elapsed,
+            Some(&bytes[..]),
+            None,
+        );
+        let value = decode_json(
+            &bytes,
+            self.inner.config.error_body_limit,
+            self.inner.config.unknown_fields,
+            self.inner.core.api_key(),
+        )?;
+        Ok(Conditional::Modified { value, etag })
     }
 
-    /// Convert HTTP status and response into an appropriate Error (async)
-    async fn error_from_status(&self, status: StatusCode, response: reqwest::Response) -> Error {
-        match status {
-            StatusCode::UNAUTHORIZED => Error::Unauthorized,
-            StatusCode::FORBIDDEN => Error::Forbidden,
-            StatusCode::NOT_FOUND => Error::NotFound,
-            StatusCode::METHOD_NOT_ALLOWED => Error::MethodNotAllowed,
-            StatusCode::TOO_MANY_REQUESTS => {
-                // Parse Retry-After header if present
-                let retry_after = response
-                    .headers()
-                    .get("Retry-After")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|s| {
-                        // Try parsing as delay-seconds (numeric)
-                        if let Ok(seconds) = s.parse::<u64>() {
-                            return Some(seconds);
-                        }
+    /// Add the API key or, for OAuth credentials, a bearer token to `headers`
+    async fn insert_auth_header(&self, headers: &mut HeaderMap) -> Result<()> {
+        let Some(tokens) = &self.inner.token_manager else {
+            headers.insert(
+                hooks::API_KEY_HEADER,
+                HeaderValue::from_str(self.inner.core.api_key()).unwrap(),
+            );
+            return Ok(());
+        };
 
-                        // Try parsing as HTTP-date
-                        if let Ok(date) = httpdate::parse_http_date(s) {
-                            if let Ok(duration) = date.duration_since(std::time::SystemTime::now())
-                            {
-                                return Some(duration.as_secs());
-                            }
-                        }
+        // Concurrent requests may each fetch a token when the cache is empty; the last one
+        // stored wins, and every fetched token is valid.
+        let token = match tokens.cached_token() {
+            Some(token) => token,
+            None => self.fetch_token(tokens).await?,
+        };
+        headers.insert(AUTHORIZATION, auth::bearer_header(&token)?);
+        Ok(())
+    }
+
+    /// Request a new OAuth token from the token endpoint and cache it
+    async fn fetch_token(&self, tokens: &TokenManager) -> Result<String> {
+        debug!("Fetching OAuth token from {}", tokens.token_url());
+        let response = self
+            .inner
+            .client
+            .post(tokens.token_url())
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(ACCEPT, "application/json")
+            .body(tokens.request_body())
+            .send()
+            .await
+            .map_err(|e| tokens.error(e))?;
 
-                        warn!("Retry-After header present but unparseable: {:?}", s);
-                        None
-                    });
+        let status = response.status();
+        if !status.is_success() {
+            return Err(tokens.error(format!("status {}", status)));
+        }
+        let body = response.bytes().await.map_err(|e| tokens.error(e))?;
+        tokens.store(&body)
+    }
 
-                Error::RateLimited { retry_after }
+    /// Wait for a free slot if a client-side rate limit is configured
+    async fn wait_for_rate_limit(&self) {
+        if let Some(limiter) = &self.inner.rate_limiter {
+            let wait = limiter.reserve();
+            if !wait.is_zero() {
+                debug!("Client-side rate limit reached, waiting {:?}", wait);
+                tokio::time::sleep(wait).await;
             }
-            _ => {
-                // Try to parse error response
-                if let Ok(body) = response.text().await {
-                    if let Ok(api_errors) = serde_json::from_str::<ApiErrors>(&body) {
-                        return Error::Fault {
-                            code: status,
-                            errors: api_errors,
-                        };
-                    }
+        }
+    }
+
+    /// Convert HTTP status and response into an appropriate Error (async)
+    async fn error_from_status(
+        &self,
+        status: StatusCode,
+        response: reqwest::Response,
+        request_id: Option<String>,
+        request: RequestContext,
+    ) -> Error {
+        let resource = request.endpoint.into();
+        let request = Some(Box::new(request));
+        match status {
+            StatusCode::BAD_REQUEST => {
+                let body = response.bytes().await.unwrap_or_default();
+                let errors = serde_json::from_slice::<ApiErrors>(&body).unwrap_or_default();
+                Error::InvalidRequest {
+                    messages: errors.messages(),
+                    request,
+                    body: fault_body(
+                        &body,
+                        self.inner.config.error_body_limit,
+                        self.inner.core.api_key(),
+                    ),
                 }
-                // Fallback: create a Fault error with empty errors
+            }
+            StatusCode::UNAUTHORIZED => Error::Unauthorized {
+                context: None,
+                request,
+            },
+            StatusCode::FORBIDDEN => Error::Forbidden { request },
+            StatusCode::NOT_FOUND => Error::NotFound {
+                resource,
+                id: None,
+                request,
+            },
+            StatusCode::METHOD_NOT_ALLOWED => Error::MethodNotAllowed { request },
+            StatusCode::TOO_MANY_REQUESTS => {
+                let (retry_after, retry_at) =
+                    parse_retry_after(response.headers(), SystemTime::now());
+                Error::RateLimited {
+                    retry_after,
+                    retry_at,
+                    request,
+                }
+            }
+            _ => {
+                // Keep the raw body even if it is not an API error response (e.g. HTML pages)
+                let body = response.bytes().await.unwrap_or_default();
+                let errors = serde_json::from_slice::<ApiErrors>(&body).unwrap_or_default();
                 Error::Fault {
                     code: status,
-                    errors: ApiErrors {
-                        errors: vec![],
-                        error_messages: vec![],
-                    },
+                    errors,
+                    request_id,
+                    request,
+                    body: fault_body(
+                        &body,
+                        self.inner.config.error_body_limit,
+                        self.inner.core.api_key(),
+                    ),
                 }
             }
         }
     }
 }
 
+/// Stream a response body into a new file, returning the detected image extension
+async fn write_stream_to_file(
+    response: reqwest::Response,
+    path: &Path,
+) -> Result<Option<&'static str>> {
+    let mut file = tokio::fs::File::create(path).await?;
+    let mut stream = response.bytes_stream();
+    let mut extension = None;
+    let mut first_chunk = true;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        if first_chunk && !chunk.is_empty() {
+            extension = image_extension(&chunk);
+            first_chunk = false;
+        }
+        file.write_all(&chunk).await?;
+    }
+
+    file.flush().await?;
+    file.sync_all().await?;
+    Ok(extension)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,11 +1472,42 @@ mod tests {
         let client = JobsucheAsync::new(
             "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
             Credentials::default(),
-        )
-        .await;
+        );
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_async_default_client() {
+        let client = JobsucheAsync::default_client().unwrap();
+        assert_eq!(client.core().host.as_str(), DEFAULT_HOST);
+    }
+
+    #[tokio::test]
+    async fn test_async_clone_shares_inner_state() {
+        let config = ClientConfig {
+            max_retries: 7,
+            ..Default::default()
+        };
+        let client =
+            JobsucheAsync::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+        let cloned = client.clone();
+
+        assert!(Arc::ptr_eq(&client.inner, &cloned.inner));
+        assert_eq!(cloned.config().max_retries, 7);
+    }
+
+    #[test]
+    fn test_async_search_shares_inner_state() {
+        let client = JobsucheAsync::default_client().unwrap();
+
+        let searches: Vec<SearchAsync> = (0..100).map(|_| client.search()).collect();
+        for search in searches.iter().chain(std::iter::once(&searches[0].clone())) {
+            assert!(Arc::ptr_eq(&client.inner, &search.client().inner));
+        }
+        // One handle held by `client`, one per search interface
+        assert_eq!(Arc::strong_count(&client.inner), 1 + searches.len());
+    }
+
     #[tokio::test]
     async fn test_async_client_with_config() {
         let config = ClientConfig {
@@ -420,8 +1521,7 @@ mod tests {
             "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
             Credentials::default(),
             config,
-        )
-        .await;
+        );
         assert!(client.is_ok());
     }
 }
@@ -0,0 +1,201 @@
+//! OAuth2 client-credentials token handling
+//!
+//! Used when a client is created with [`Credentials::OAuth`]. The token manager only caches
+//! tokens and prepares token requests; the sync and async clients send those requests with
+//! their own HTTP client.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::header::HeaderValue;
+use serde::Deserialize;
+
+use crate::{Credentials, Error, Result};
+
+/// Tokens are refreshed this long before they expire
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed when the token endpoint does not send `expires_in`
+const DEFAULT_LIFETIME: Duration = Duration::from_secs(300);
+
+/// Token endpoint response (RFC 6749, section 5.1)
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+struct CachedToken {
+    access_token: String,
+    refresh_at: Instant,
+}
+
+/// Token cache of a client using [`Credentials::OAuth`]
+pub(crate) struct TokenManager {
+    client_id: String,
+    client_secret: String,
+    token_url: String,
+    token: Mutex<Option<CachedToken>>,
+}
+
+impl fmt::Debug for TokenManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenManager")
+            .field("client_id", &self.client_id)
+            .field("token_url", &self.token_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenManager {
+    /// Create a token manager for OAuth credentials, or `None` for API key credentials
+    pub(crate) fn from_credentials(credentials: &Credentials) -> Option<Self> {
+        match credentials {
+            Credentials::ApiKey(_) => None,
+            Credentials::OAuth {
+                client_id,
+                client_secret,
+                token_url,
+            } => Some(Self {
+                client_id: client_id.clone(),
+                client_secret: client_secret.clone(),
+                token_url: token_url.clone(),
+                token: Mutex::new(None),
+            }),
+        }
+    }
+
+    pub(crate) fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    /// Form-encoded body of a client-credentials token request
+    pub(crate) fn request_body(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "client_credentials")
+            .append_pair("client_id", &self.client_id)
+            .append_pair("client_secret", &self.client_secret)
+            .finish()
+    }
+
+    /// The cached token, unless it is missing or about to expire
+    pub(crate) fn cached_token(&self) -> Option<String> {
+        self.cached_token_at(Instant::now())
+    }
+
+    fn cached_token_at(&self, now: Instant) -> Option<String> {
+        let token = self.token.lock().unwrap_or_else(|e| e.into_inner());
+        token
+            .as_ref()
+            .filter(|token| now < token.refresh_at)
+            .map(|token| token.access_token.clone())
+    }
+
+    /// Parse a token response body, cache the token and return it
+    pub(crate) fn store(&self, body: &[u8]) -> Result<String> {
+        self.store_at(body, Instant::now())
+    }
+
+    fn store_at(&self, body: &[u8], now: Instant) -> Result<String> {
+        let response: TokenResponse = serde_json::from_slice(body)
+            .map_err(|e| self.error(format!("invalid response: {}", e)))?;
+        let lifetime = response
+            .expires_in
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_LIFETIME);
+
+        *self.token.lock().unwrap_or_else(|e| e.into_inner()) = Some(CachedToken {
+            access_token: response.access_token.clone(),
+            refresh_at: now + lifetime.saturating_sub(REFRESH_MARGIN),
+        });
+        Ok(response.access_token)
+    }
+
+    /// Map a failed token request to [`Error::Unauthorized`]
+    pub(crate) fn error(&self, reason: impl fmt::Display) -> Error {
+        Error::Unauthorized {
+            context: Some(format!(
+                "OAuth token request to {} failed: {}",
+                self.token_url, reason
+            )),
+            request: None,
+        }
+    }
+}
+
+/// `Authorization` header value for a bearer token
+pub(crate) fn bearer_header(token: &str) -> Result<HeaderValue> {
+    let mut value =
+        HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|e| Error::Unauthorized {
+            context: Some(format!("OAuth token is not a valid header value: {}", e)),
+            request: None,
+        })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> TokenManager {
+        TokenManager::from_credentials(&Credentials::OAuth {
+            client_id: "my client".to_string(),
+            client_secret: "s3cr&t".to_string(),
+            token_url: "https://auth.example.com/token".to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_api_key_has_no_token_manager() {
+        assert!(TokenManager::from_credentials(&Credentials::default()).is_none());
+    }
+
+    #[test]
+    fn test_request_body_is_form_encoded() {
+        assert_eq!(
+            manager().request_body(),
+            "grant_type=client_credentials&client_id=my+client&client_secret=s3cr%26t"
+        );
+    }
+
+    #[test]
+    fn test_token_cached_until_refresh_margin() {
+        let tokens = manager();
+        let now = Instant::now();
+        assert_eq!(tokens.cached_token_at(now), None);
+
+        let token = tokens
+            .store_at(br#"{"access_token": "abc", "expires_in": 60}"#, now)
+            .unwrap();
+        assert_eq!(token, "abc");
+        assert_eq!(tokens.cached_token_at(now).as_deref(), Some("abc"));
+        assert_eq!(
+            tokens
+                .cached_token_at(now + Duration::from_secs(29))
+                .as_deref(),
+            Some("abc")
+        );
+        assert_eq!(tokens.cached_token_at(now + Duration::from_secs(30)), None);
+    }
+
+    #[test]
+    fn test_invalid_token_response() {
+        let result = manager().store(b"<html>");
+        assert!(matches!(
+            result,
+            Err(Error::Unauthorized {
+                context: Some(_),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        assert!(!format!("{:?}", manager()).contains("s3cr&t"));
+    }
+}
@@ -212,6 +212,9 @@ impl SearchOptionsBuilder {
 
     /// Filter by contract type (can specify multiple, semicolon-separated)
     ///
+    /// Codes are sent in a fixed order without duplicates, so the same set of types
+    /// always serializes the same way.
+    ///
     /// # Example
     /// ```
     /// use jobsuche::{SearchOptions, Befristung};
@@ -220,7 +223,9 @@ impl SearchOptionsBuilder {
     ///     .befristung(vec![Befristung::Unbefristet])
     ///     .build();
     /// ```
-    pub fn befristung(&mut self, types: Vec<Befristung>) -> &mut SearchOptionsBuilder {
+    pub fn befristung(&mut self, mut types: Vec<Befristung>) -> &mut SearchOptionsBuilder {
+        types.sort();
+        types.dedup();
         let value = types
             .iter()
             .map(|t| t.as_str())
@@ -232,6 +237,9 @@ impl SearchOptionsBuilder {
 
     /// Filter by working time model (can specify multiple, semicolon-separated)
     ///
+    /// Codes are sent in a fixed order without duplicates, see
+    /// [`befristung`](Self::befristung).
+    ///
     /// # Example
     /// ```
     /// use jobsuche::{SearchOptions, Arbeitszeit};
@@ -240,7 +248,9 @@ impl SearchOptionsBuilder {
     ///     .arbeitszeit(vec![Arbeitszeit::Vollzeit, Arbeitszeit::Teilzeit])
     ///     .build();
     /// ```
-    pub fn arbeitszeit(&mut self, times: Vec<Arbeitszeit>) -> &mut SearchOptionsBuilder {
+    pub fn arbeitszeit(&mut self, mut times: Vec<Arbeitszeit>) -> &mut SearchOptionsBuilder {
+        times.sort();
+        times.dedup();
         let value = times
             .iter()
             .map(|t| t.as_str())
@@ -334,6 +344,28 @@ mod tests {
         assert!(query.contains("arbeitszeit=vz%3Btz"));
     }
 
+    #[test]
+    fn test_multi_values_canonical() {
+        let a = SearchOptions::builder()
+            .arbeitszeit(vec![Arbeitszeit::HeimTelearbeit, Arbeitszeit::Vollzeit])
+            .befristung(vec![Befristung::Unbefristet, Befristung::Befristet])
+            .build();
+        let b = SearchOptions::builder()
+            .befristung(vec![
+                Befristung::Befristet,
+                Befristung::Unbefristet,
+                Befristung::Befristet,
+            ])
+            .arbeitszeit(vec![Arbeitszeit::Vollzeit, Arbeitszeit::HeimTelearbeit])
+            .build();
+
+        assert_eq!(a.serialize(), b.serialize());
+        assert_eq!(
+            a.serialize().as_deref(),
+            Some("arbeitszeit=vz%3Bho&befristung=1%3B2")
+        );
+    }
+
     #[test]
     fn test_size_capping() {
         let options = SearchOptions::builder()
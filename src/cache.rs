@@ -0,0 +1,1622 @@
+//! Caching of job details and searches (requires the `cache` feature)
+//!
+//! Configured via [`ClientConfig::cache`](crate::ClientConfig::cache). The cache is shared
+//! by all clones of a client. Only successful responses are stored, so errors are fetched
+//! again; the exception is a 404 for job details with
+//! [`CacheConfig::cache_not_found`] set.
+//!
+//! Searches are keyed by the serialized [`SearchOptions`]. Parameters
+//! and multi-value filters are serialized in a fixed order, so options built in a different
+//! call order share an entry.
+//!
+//! Entries live in memory by default ([`MemoryCache`]). Set
+//! [`ClientConfig::cache_backend`](crate::ClientConfig::cache_backend) to a [`FileCache`] to
+//! keep them between runs, or to your own [`Cache`] implementation.
+//!
+//! Entries are stored as JSON and deserialized on every read. Keys carry the
+//! [`CACHE_SCHEMA_VERSION`], so entries from releases with another layout are misses; so are
+//! entries that fail to deserialize, which are dropped and fetched again.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::hooks::Endpoint;
+use crate::{
+    ClientConfig, Conditional, Error, JobDetails, JobSearchResponse, RequestContext, ResourceKind,
+    Result, SearchOptions,
+};
+
+/// Settings of the response cache
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{CacheConfig, ClientConfig, Credentials, Jobsuche, DEFAULT_HOST};
+/// use std::time::Duration;
+///
+/// let config = ClientConfig {
+///     cache: Some(CacheConfig {
+///         details_ttl: Duration::from_secs(600),
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+/// let client = Jobsuche::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+///
+/// // The second call is answered from the cache
+/// let first = client.job_details("10001-1001601666-S").unwrap();
+/// let second = client.job_details("10001-1001601666-S").unwrap();
+/// assert_eq!(first, second);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// How long job details are served from the cache (default: 5 minutes)
+    pub details_ttl: Duration,
+    /// How long search results are served from the cache (default: 1 minute)
+    ///
+    /// Searches go stale faster than details as jobs are published and withdrawn.
+    /// `Duration::ZERO` disables caching of searches.
+    pub search_ttl: Duration,
+    /// Maximum number of entries of the in-memory cache (default: 1000)
+    ///
+    /// When the cache is full, expired entries are dropped first, then the least recently
+    /// used entry. Not used with a [`cache_backend`](crate::ClientConfig::cache_backend).
+    pub max_entries: usize,
+    /// How long job details with an `ETag` are kept after `details_ttl` to be revalidated
+    /// (default: 1 day)
+    ///
+    /// Looking up such an entry sends `If-None-Match` instead of fetching the details again.
+    /// If the API answers 304 Not Modified, the cached details are returned and their TTL
+    /// starts over. `Duration::ZERO` drops entries when `details_ttl` runs out.
+    pub revalidate_for: Duration,
+    /// Also cache the pages fetched by pagination iterators and streams (default: true)
+    ///
+    /// Disable to keep long paginated searches from filling the cache with pages that
+    /// are rarely requested again. Single pages from `search().list()` are cached either way.
+    pub cache_pages: bool,
+    /// Remember job details that returned 404 Not Found (default: false)
+    ///
+    /// Expired jobs stay gone, so repeated lookups of their refnrs are answered with
+    /// [`Error::NotFound`] from the cache for [`negative_ttl`](Self::negative_ttl).
+    /// `job_details_fresh` and `RequestOptions::cache_bypass` ask the API anyway.
+    pub cache_not_found: bool,
+    /// How long a 404 for job details is remembered with `cache_not_found` (default: 3 hours)
+    pub negative_ttl: Duration,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            details_ttl: Duration::from_secs(5 * 60),
+            search_ttl: Duration::from_secs(60),
+            revalidate_for: Duration::from_secs(24 * 60 * 60),
+            max_entries: 1000,
+            cache_pages: true,
+            cache_not_found: false,
+            negative_ttl: Duration::from_secs(3 * 60 * 60),
+        }
+    }
+}
+
+/// A cached API response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CachedEntry {
+    /// The response as JSON
+    pub value: serde_json::Value,
+    /// When the response was stored, or last confirmed unchanged by the API
+    pub stored_at: SystemTime,
+    /// `ETag` header of the response, to revalidate the entry with `If-None-Match`
+    #[serde(default)]
+    pub etag: Option<String>,
+}
+
+impl CachedEntry {
+    /// Wrap a response stored now
+    pub fn new(value: serde_json::Value) -> Self {
+        Self {
+            value,
+            stored_at: SystemTime::now(),
+            etag: None,
+        }
+    }
+
+    /// Set the `ETag` of the response
+    pub fn with_etag(mut self, etag: Option<String>) -> Self {
+        self.etag = etag;
+        self
+    }
+}
+
+/// Version of the cached data layout, the first part of every cache key
+///
+/// Bumped when [`JobDetails`], [`JobSearchResponse`] or the key format change shape, so
+/// entries written by an older release of this crate are never read into the new types.
+/// They stay stored until they expire or [`Cache::purge_old_versions`] removes them.
+pub const CACHE_SCHEMA_VERSION: u32 = 1;
+
+/// Whether `key` belongs to the current [`CACHE_SCHEMA_VERSION`]
+///
+/// For implementations of [`Cache::purge_old_versions`].
+pub fn is_current_key(key: &str) -> bool {
+    key.strip_prefix('v')
+        .and_then(|rest| rest.split_once(' '))
+        .is_some_and(|(version, _)| version == CACHE_SCHEMA_VERSION.to_string())
+}
+
+/// Storage for cached responses
+///
+/// Keys are opaque strings chosen by the client. They start with `v` and the
+/// [`CACHE_SCHEMA_VERSION`] and include the API host, so one store can be shared by clients
+/// for different hosts and by different releases of this crate. Implementations decide how to store
+/// entries but must not return an entry after its TTL has run out. Failures should be
+/// logged and treated as misses: a broken cache must never fail a request.
+///
+/// Backends that block on disk or network I/O should return true from
+/// [`is_blocking`](Cache::is_blocking), so [`JobsucheAsync`](crate::JobsucheAsync) calls
+/// them on tokio's blocking thread pool instead of stalling the executor.
+///
+/// # Example
+///
+/// ```
+/// use jobsuche::{Cache, CachedEntry};
+/// use std::time::Duration;
+///
+/// /// A cache that stores nothing
+/// #[derive(Debug)]
+/// struct NoCache;
+///
+/// impl Cache for NoCache {
+///     fn get(&self, _key: &str) -> Option<CachedEntry> {
+///         None
+///     }
+///     fn put(&self, _key: &str, _entry: CachedEntry, _ttl: Duration) {}
+///     fn invalidate(&self, _key: &str) {}
+///     fn clear(&self) {}
+/// }
+/// ```
+pub trait Cache: fmt::Debug + Send + Sync {
+    /// The entry stored for `key`, unless missing or expired
+    fn get(&self, key: &str) -> Option<CachedEntry>;
+
+    /// Store `entry` for `key` for `ttl`, replacing any previous entry
+    fn put(&self, key: &str, entry: CachedEntry, ttl: Duration);
+
+    /// Remove the entry stored for `key`
+    fn invalidate(&self, key: &str);
+
+    /// Remove all entries
+    fn clear(&self);
+
+    /// Remove entries of older [`CACHE_SCHEMA_VERSION`]s and return how many were removed
+    ///
+    /// Such entries are never read, but they take up space until they expire. Call this now
+    /// and then, e.g. at startup, via [`CacheHandle::purge_old_versions`]. The default does
+    /// nothing; see [`is_current_key`].
+    fn purge_old_versions(&self) -> usize {
+        0
+    }
+
+    /// Whether the methods block on I/O (default: false)
+    ///
+    /// The async client calls blocking backends via `tokio::task::spawn_blocking`.
+    /// Backends that only lock memory, like [`MemoryCache`], are called directly.
+    fn is_blocking(&self) -> bool {
+        false
+    }
+
+    /// Usage of the backend: `evictions`, `entries` and `approximate_bytes`
+    ///
+    /// Hits, misses and insertions are counted by the client and ignored here. The default
+    /// reports zeros.
+    fn stats(&self) -> CacheStats {
+        CacheStats::default()
+    }
+}
+
+/// Counters of a cache, see [`CacheHandle::stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that went to the API, including entries that could not be read
+    pub misses: u64,
+    /// Responses stored
+    pub insertions: u64,
+    /// Expired entries confirmed unchanged by the API (HTTP 304), see
+    /// [`CacheConfig::revalidate_for`]
+    pub revalidations: u64,
+    /// Lookups answered with a cached 404, see [`CacheConfig::cache_not_found`]
+    ///
+    /// Not included in `hits` or [`hit_rate`](Self::hit_rate).
+    pub negative_hits: u64,
+    /// Entries dropped because they expired or to make room
+    pub evictions: u64,
+    /// Entries currently stored, including expired ones not yet dropped
+    pub entries: usize,
+    /// Approximate size of the stored responses as JSON
+    pub approximate_bytes: usize,
+}
+
+impl CacheStats {
+    /// Share of lookups answered from the cache, or `None` before the first lookup
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits + self.misses;
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    entry: CachedEntry,
+    expires_at: Instant,
+    /// Value of [`MemoryState::tick`] at the last access
+    last_used: u64,
+    /// Approximate size in bytes
+    size: usize,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    entries: HashMap<String, MemoryEntry>,
+    /// Incremented on every access, to order entries by recency
+    tick: u64,
+}
+
+impl MemoryState {
+    fn touch(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// In-memory [`Cache`] with LRU eviction, the default backend
+#[derive(Debug)]
+pub struct MemoryCache {
+    max_entries: usize,
+    state: Mutex<MemoryState>,
+    evictions: AtomicU64,
+    entries: AtomicUsize,
+    bytes: AtomicUsize,
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `max_entries` entries
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ConfigError`] if `max_entries` is 0.
+    pub fn new(max_entries: usize) -> Result<Self> {
+        if max_entries == 0 {
+            return Err(Error::ConfigError {
+                message: "cache.max_entries must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            max_entries,
+            state: Mutex::new(MemoryState::default()),
+            evictions: AtomicU64::new(0),
+            entries: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
+        })
+    }
+
+    fn get_at(&self, key: &str, now: Instant) -> Option<CachedEntry> {
+        let mut state = self.state.lock();
+        let tick = state.touch();
+        let expired = match state.entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_used = tick;
+                return Some(entry.entry.clone());
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if expired {
+            self.remove(&mut state, key, true);
+        }
+        None
+    }
+
+    fn put_at(&self, key: &str, entry: CachedEntry, ttl: Duration, now: Instant) {
+        let mut state = self.state.lock();
+        self.remove(&mut state, key, false);
+        if state.entries.len() >= self.max_entries {
+            let expired: Vec<String> = state
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.expires_at <= now)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in expired {
+                self.remove(&mut state, &key, true);
+            }
+        }
+        if state.entries.len() >= self.max_entries {
+            let least_recent = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recent) = least_recent {
+                debug!("Evicting least recently used cache entry {}", least_recent);
+                self.remove(&mut state, &least_recent, true);
+            }
+        }
+        let size = key.len() + json_size(&entry.value);
+        let last_used = state.touch();
+        state.entries.insert(
+            key.to_string(),
+            MemoryEntry {
+                entry,
+                expires_at: now + ttl,
+                last_used,
+                size,
+            },
+        );
+        self.entries.store(state.entries.len(), Ordering::Relaxed);
+        self.bytes.fetch_add(size, Ordering::Relaxed);
+    }
+
+    /// Remove `key`, counting an eviction if `evicted`
+    fn remove(&self, state: &mut MemoryState, key: &str, evicted: bool) {
+        if let Some(entry) = state.entries.remove(key) {
+            self.entries.store(state.entries.len(), Ordering::Relaxed);
+            self.bytes.fetch_sub(entry.size, Ordering::Relaxed);
+            if evicted {
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedEntry> {
+        self.get_at(key, Instant::now())
+    }
+
+    fn put(&self, key: &str, entry: CachedEntry, ttl: Duration) {
+        self.put_at(key, entry, ttl, Instant::now());
+    }
+
+    fn invalidate(&self, key: &str) {
+        let mut state = self.state.lock();
+        self.remove(&mut state, key, false);
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock();
+        state.entries.clear();
+        self.entries.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
+    }
+
+    fn purge_old_versions(&self) -> usize {
+        let mut state = self.state.lock();
+        let old: Vec<String> = state
+            .entries
+            .keys()
+            .filter(|key| !is_current_key(key))
+            .cloned()
+            .collect();
+        for key in &old {
+            self.remove(&mut state, key, false);
+        }
+        old.len()
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            evictions: self.evictions.load(Ordering::Relaxed),
+            entries: self.entries.load(Ordering::Relaxed),
+            approximate_bytes: self.bytes.load(Ordering::Relaxed),
+            ..Default::default()
+        }
+    }
+}
+
+/// Length of `value` serialized as JSON, without allocating it
+fn json_size(value: &serde_json::Value) -> usize {
+    struct Counter(usize);
+
+    impl io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    // Writing to the counter cannot fail
+    let _ = serde_json::to_writer(&mut counter, value);
+    counter.0
+}
+
+/// Contents of a [`FileCache`] file
+#[derive(Serialize, Deserialize)]
+struct FileEntry {
+    /// The full key, to detect hash collisions
+    key: String,
+    expires_at: SystemTime,
+    entry: CachedEntry,
+}
+
+/// [`Cache`] keeping one JSON file per entry in a directory, to reuse responses between runs
+///
+/// File names are a hash of the key. Each file also holds the key and the expiry time.
+/// Files that cannot be read or parsed are treated as misses and removed. The directory
+/// is created on the first write. Expired files are removed when read or on
+/// [`clear`](Cache::clear); there is no size limit. [`stats`](Cache::stats) scans the
+/// directory to count entries and bytes.
+///
+/// Reads and writes use blocking file I/O. [`JobsucheAsync`](crate::JobsucheAsync) runs
+/// them on tokio's blocking thread pool.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{CacheConfig, ClientConfig, Credentials, FileCache, Jobsuche, DEFAULT_HOST};
+/// use std::sync::Arc;
+///
+/// let config = ClientConfig {
+///     cache: Some(CacheConfig::default()),
+///     cache_backend: Some(Arc::new(FileCache::new("/tmp/jobsuche-cache"))),
+///     ..Default::default()
+/// };
+/// let client = Jobsuche::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileCache {
+    dir: PathBuf,
+    /// Shared by clones
+    evictions: Arc<AtomicU64>,
+}
+
+impl FileCache {
+    /// Create a cache storing its files in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            evictions: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// The directory holding the cache files
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", fnv1a(key)))
+    }
+
+    fn read(&self, path: &Path) -> Option<FileEntry> {
+        let contents = match fs::read(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return None,
+            Err(e) => {
+                warn!("Failed to read cache file {}: {}", path.display(), e);
+                return None;
+            }
+        };
+        match serde_json::from_slice(&contents) {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                debug!("Removing corrupted cache file {}: {}", path.display(), e);
+                self.evict(path);
+                None
+            }
+        }
+    }
+
+    fn write(&self, path: &Path, entry: &FileEntry) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let contents = serde_json::to_vec(entry)?;
+        // Write to a temporary file first, so readers never see a partial entry. The name is
+        // unique per write, as other threads or processes may write the same key at once.
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+        let write = WRITES.fetch_add(1, Ordering::Relaxed);
+        let tmp = path.with_extension(format!("{}-{}.tmp", std::process::id(), write));
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, path).inspect_err(|_| remove_file(&tmp))
+    }
+
+    fn evict(&self, path: &Path) {
+        remove_file(path);
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The cache files, not including temporary ones
+    fn files(&self) -> impl Iterator<Item = fs::DirEntry> {
+        fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|file| {
+                file.path()
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+            })
+    }
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> Option<CachedEntry> {
+        let path = self.path(key);
+        let entry = self.read(&path)?;
+        if entry.key != key {
+            return None;
+        }
+        if entry.expires_at <= SystemTime::now() {
+            self.evict(&path);
+            return None;
+        }
+        Some(entry.entry)
+    }
+
+    fn put(&self, key: &str, entry: CachedEntry, ttl: Duration) {
+        let path = self.path(key);
+        let entry = FileEntry {
+            key: key.to_string(),
+            expires_at: SystemTime::now() + ttl,
+            entry,
+        };
+        if let Err(e) = self.write(&path, &entry) {
+            warn!("Failed to write cache file {}: {}", path.display(), e);
+        }
+    }
+
+    fn invalidate(&self, key: &str) {
+        remove_file(&self.path(key));
+    }
+
+    fn clear(&self) {
+        for file in self.files() {
+            remove_file(&file.path());
+        }
+    }
+
+    /// Reads every file to check its key; corrupted files are removed as well
+    fn purge_old_versions(&self) -> usize {
+        let mut purged = 0;
+        for file in self.files() {
+            let path = file.path();
+            if self
+                .read(&path)
+                .is_some_and(|entry| !is_current_key(&entry.key))
+            {
+                remove_file(&path);
+                purged += 1;
+            }
+        }
+        purged
+    }
+
+    fn is_blocking(&self) -> bool {
+        true
+    }
+
+    fn stats(&self) -> CacheStats {
+        let (entries, approximate_bytes) = self.files().fold((0, 0), |(count, bytes), file| {
+            let size = file
+                .metadata()
+                .map_or(0, |metadata| metadata.len() as usize);
+            (count + 1, bytes + size)
+        });
+        CacheStats {
+            evictions: self.evictions.load(Ordering::Relaxed),
+            entries,
+            approximate_bytes,
+            ..Default::default()
+        }
+    }
+}
+
+/// Remove a file, logging failures other than a missing file
+fn remove_file(path: &Path) {
+    if let Err(e) = fs::remove_file(path) {
+        if e.kind() != io::ErrorKind::NotFound {
+            warn!("Failed to remove cache file {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across Rust versions unlike `DefaultHasher`
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Control over the cache of a client, returned by
+/// [`Jobsuche::cache`](crate::Jobsuche::cache) and `JobsucheAsync::cache`
+///
+/// All methods do nothing if the client has no cache.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{CacheConfig, ClientConfig, Credentials, Jobsuche, SearchOptions, DEFAULT_HOST};
+///
+/// let config = ClientConfig {
+///     cache: Some(CacheConfig::default()),
+///     ..Default::default()
+/// };
+/// let client = Jobsuche::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+///
+/// client.cache().invalidate_details("10001-1001601666-S");
+/// client.cache().invalidate_search(&SearchOptions::builder().was("Rust").build());
+/// client.cache().clear();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CacheHandle<'a> {
+    cache: Option<&'a ResponseCache>,
+}
+
+impl<'a> CacheHandle<'a> {
+    pub(crate) fn new(cache: Option<&'a ResponseCache>) -> Self {
+        Self { cache }
+    }
+
+    /// Whether the client caches responses
+    pub fn is_enabled(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Drop the cached details or 404 for `refnr`, so the next lookup hits the API
+    pub fn invalidate_details(&self, refnr: impl AsRef<str>) {
+        if let Some(cache) = self.cache {
+            cache.invalidate(&cache.key("jobdetails", refnr.as_ref()));
+            cache.invalidate(&cache.key("notfound", refnr.as_ref()));
+        }
+    }
+
+    /// Drop the cached response of a search, so the next search with equal options hits the
+    /// API
+    ///
+    /// Only the page selected by `options` is dropped.
+    pub fn invalidate_search(&self, options: &SearchOptions) {
+        if let Some(cache) = self.cache {
+            cache.invalidate(&cache.search_key(options));
+        }
+    }
+
+    /// Counters of this client's cache, shared by its clones
+    ///
+    /// Hits, misses and insertions count lookups of this client; evictions, entries and
+    /// bytes come from the [`Cache`] backend. All zero if the client has no cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use jobsuche::Jobsuche;
+    /// # let client = Jobsuche::default_client().unwrap();
+    /// let stats = client.cache().stats();
+    /// println!(
+    ///     "{} entries, {} bytes, hit rate {:?}",
+    ///     stats.entries, stats.approximate_bytes, stats.hit_rate()
+    /// );
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        self.cache.map(ResponseCache::stats).unwrap_or_default()
+    }
+
+    /// Remove entries written by releases with another [`CACHE_SCHEMA_VERSION`]
+    ///
+    /// Returns how many were removed. Useful with a [`FileCache`] that outlives upgrades of
+    /// this crate; old entries are never read either way.
+    pub fn purge_old_versions(&self) -> usize {
+        self.cache
+            .map_or(0, |cache| cache.backend.purge_old_versions())
+    }
+
+    /// Drop all cached job details and searches
+    ///
+    /// With a shared [`cache_backend`](crate::ClientConfig::cache_backend), this also drops
+    /// the entries of other clients using it.
+    pub fn clear(&self) {
+        if let Some(cache) = self.cache {
+            cache.backend.clear();
+        }
+    }
+}
+
+/// Outcome of [`Jobsuche::warm_details_cache`](crate::Jobsuche::warm_details_cache)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WarmupReport {
+    /// Details fetched and stored, including stale details confirmed unchanged by a 304
+    pub fetched: usize,
+    /// Refnrs with fresh cached details or a remembered 404, so no request was sent
+    pub skipped: usize,
+    /// Refnrs the API answered with 404 Not Found
+    pub not_found: usize,
+    /// Refnrs that failed otherwise, e.g. invalid refnrs or server errors after retries
+    pub failed: usize,
+}
+
+impl WarmupReport {
+    /// Count the outcome of warming `refnr`: whether a request was sent, or the error
+    pub(crate) fn record(&mut self, refnr: &str, result: Result<bool>) {
+        match result {
+            Ok(true) => self.fetched += 1,
+            Ok(false) => self.skipped += 1,
+            Err(e) if e.is_not_found() => self.not_found += 1,
+            Err(e) => {
+                warn!("Failed to warm the cache for {}: {}", refnr, e);
+                self.failed += 1;
+            }
+        }
+    }
+
+    /// The error for warming a client without a cache
+    pub(crate) fn no_cache() -> Error {
+        Error::ConfigError {
+            message: "warming the cache requires ClientConfig::cache or cache_backend".to_string(),
+        }
+    }
+}
+
+/// Result of looking up job details in the cache
+pub(crate) enum DetailsLookup {
+    /// Fresh details, no request needed
+    Hit(JobDetails),
+    /// Details to revalidate with `If-None-Match`
+    Revalidate(StaleDetails),
+    /// A remembered 404, see [`CacheConfig::cache_not_found`]
+    NotFound,
+    /// Nothing usable cached
+    Miss,
+}
+
+impl DetailsLookup {
+    /// The error for a remembered 404 of `refnr` at `url`
+    pub(crate) fn not_found(refnr: &str, url: &str) -> Error {
+        Error::NotFound {
+            resource: ResourceKind::JobDetails,
+            id: Some(refnr.to_string()),
+            request: Some(Box::new(RequestContext::new(Endpoint::JobDetails, url))),
+        }
+    }
+}
+
+/// Cached job details with an ETag
+pub(crate) struct StaleDetails {
+    details: JobDetails,
+    entry: CachedEntry,
+}
+
+impl StaleDetails {
+    /// The ETag to send with `If-None-Match`
+    pub(crate) fn etag(&self) -> Option<&str> {
+        self.entry.etag.as_deref()
+    }
+}
+
+/// The cache of a client: typed access to a [`Cache`] backend
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    backend: Arc<dyn Cache>,
+    /// Prefix of all keys, so clients for different hosts can share a backend
+    host: String,
+    details_ttl: Duration,
+    search_ttl: Duration,
+    revalidate_for: Duration,
+    /// Zero unless [`CacheConfig::cache_not_found`] is set
+    negative_ttl: Duration,
+    /// See [`CacheConfig::cache_pages`]
+    pub(crate) cache_pages: bool,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    insertions: AtomicU64,
+    revalidations: AtomicU64,
+    negative_hits: AtomicU64,
+}
+
+impl ResponseCache {
+    /// The cache of a client for `host`, if `config` enables caching
+    pub(crate) fn from_config(config: &ClientConfig, host: &str) -> Result<Option<Self>> {
+        if config.cache.is_none() && config.cache_backend.is_none() {
+            return Ok(None);
+        }
+        let cache_config = config.cache.unwrap_or_default();
+        Self::new(cache_config, config.cache_backend.clone(), host).map(Some)
+    }
+
+    /// Cache for `host`, in memory unless a `backend` is given
+    pub(crate) fn new(
+        config: CacheConfig,
+        backend: Option<Arc<dyn Cache>>,
+        host: &str,
+    ) -> Result<Self> {
+        let backend = match backend {
+            Some(backend) => backend,
+            None => Arc::new(MemoryCache::new(config.max_entries)?),
+        };
+        Ok(Self {
+            backend,
+            host: host.to_string(),
+            details_ttl: config.details_ttl,
+            search_ttl: config.search_ttl,
+            revalidate_for: config.revalidate_for,
+            negative_ttl: if config.cache_not_found {
+                config.negative_ttl
+            } else {
+                Duration::ZERO
+            },
+            cache_pages: config.cache_pages,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            insertions: AtomicU64::new(0),
+            revalidations: AtomicU64::new(0),
+            negative_hits: AtomicU64::new(0),
+        })
+    }
+
+    /// Look up the details for `refnr`
+    ///
+    /// With `fresh`, even details within their TTL are revalidated (if they have an ETag)
+    /// or fetched again, and remembered 404s are ignored.
+    pub(crate) fn lookup_details(&self, refnr: &str, fresh: bool) -> DetailsLookup {
+        if !fresh && !self.negative_ttl.is_zero() {
+            let key = self.key("notfound", refnr);
+            if self.backend.get(&key).is_some() {
+                debug!("Negative cache hit for {}", key);
+                self.negative_hits.fetch_add(1, Ordering::Relaxed);
+                return DetailsLookup::NotFound;
+            }
+        }
+        let key = self.key("jobdetails", refnr);
+        let Some((details, entry)) = self.read::<JobDetails>(&key) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return DetailsLookup::Miss;
+        };
+        let age = entry.stored_at.elapsed().unwrap_or_default();
+        if !fresh && age < self.details_ttl {
+            debug!("Cache hit for {}", key);
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return DetailsLookup::Hit(details);
+        }
+        if entry.etag.is_some() {
+            debug!("Revalidating cache entry {}", key);
+            return DetailsLookup::Revalidate(StaleDetails { details, entry });
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        DetailsLookup::Miss
+    }
+
+    /// Update the cache with the response to a details request
+    ///
+    /// `stale` is the entry the request revalidated, if any. Returns the details to hand
+    /// out: the cached ones on 304 Not Modified, otherwise the new ones.
+    pub(crate) fn complete_details(
+        &self,
+        refnr: &str,
+        stale: Option<StaleDetails>,
+        response: Conditional<JobDetails>,
+    ) -> JobDetails {
+        let key = self.key("jobdetails", refnr);
+        match (response, stale) {
+            (Conditional::Modified { value, etag }, stale) => {
+                if stale.is_some() {
+                    self.misses.fetch_add(1, Ordering::Relaxed);
+                }
+                if !self.negative_ttl.is_zero() {
+                    self.backend.invalidate(&self.key("notfound", refnr));
+                }
+                let ttl = self.details_backend_ttl(etag.is_some());
+                self.store(&key, &value, ttl, etag);
+                value
+            }
+            (Conditional::NotModified, Some(stale)) => {
+                debug!("Cache entry {} not modified", key);
+                self.revalidations.fetch_add(1, Ordering::Relaxed);
+                let entry = CachedEntry {
+                    stored_at: SystemTime::now(),
+                    ..stale.entry
+                };
+                self.backend
+                    .put(&key, entry, self.details_backend_ttl(true));
+                stale.details
+            }
+            // Only requests with If-None-Match are answered with 304
+            (Conditional::NotModified, None) => unreachable!("304 without If-None-Match"),
+        }
+    }
+
+    /// Remember that the API answered 404 for `refnr`, if enabled
+    pub(crate) fn store_not_found(&self, refnr: &str) {
+        if self.negative_ttl.is_zero() {
+            return;
+        }
+        self.backend.invalidate(&self.key("jobdetails", refnr));
+        let key = self.key("notfound", refnr);
+        self.store(&key, &serde_json::Value::Null, self.negative_ttl, None);
+    }
+
+    /// How long the backend keeps job details: longer with an ETag, to revalidate them
+    fn details_backend_ttl(&self, has_etag: bool) -> Duration {
+        if has_etag && !self.details_ttl.is_zero() {
+            self.details_ttl + self.revalidate_for
+        } else {
+            self.details_ttl
+        }
+    }
+
+    /// Run `f` from async code, on the blocking thread pool if the backend blocks
+    #[cfg(feature = "async")]
+    pub(crate) async fn run<R, F>(self: &Arc<Self>, f: F) -> R
+    where
+        F: FnOnce(&ResponseCache) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        if !self.backend.is_blocking() {
+            return f(self);
+        }
+        let cache = Arc::clone(self);
+        tokio::task::spawn_blocking(move || f(&cache))
+            .await
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+    }
+
+    /// The cached response of a search, unless missing or expired
+    pub(crate) fn search(&self, options: &SearchOptions) -> Option<JobSearchResponse> {
+        self.load(&self.search_key(options))
+    }
+
+    /// Store the response of a search
+    pub(crate) fn store_search(&self, options: &SearchOptions, response: &JobSearchResponse) {
+        self.store(&self.search_key(options), response, self.search_ttl, None);
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            insertions: self.insertions.load(Ordering::Relaxed),
+            revalidations: self.revalidations.load(Ordering::Relaxed),
+            negative_hits: self.negative_hits.load(Ordering::Relaxed),
+            ..self.backend.stats()
+        }
+    }
+
+    fn invalidate(&self, key: &str) {
+        debug!("Invalidating cache entry {}", key);
+        self.backend.invalidate(key);
+    }
+
+    fn search_key(&self, options: &SearchOptions) -> String {
+        self.key("jobs", &options.serialize().unwrap_or_default())
+    }
+
+    fn key(&self, kind: &str, id: &str) -> String {
+        format!("v{} {} {} {}", CACHE_SCHEMA_VERSION, self.host, kind, id)
+    }
+
+    fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let value = self.read(key).map(|(value, _)| value);
+        if value.is_some() {
+            debug!("Cache hit for {}", key);
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        value
+    }
+
+    /// The entry for `key` and its deserialized value; unreadable entries are dropped
+    fn read<T: DeserializeOwned>(&self, key: &str) -> Option<(T, CachedEntry)> {
+        let entry = self.backend.get(key)?;
+        match T::deserialize(&entry.value) {
+            Ok(value) => Some((value, entry)),
+            Err(e) => {
+                debug!("Dropping unreadable cache entry {}: {}", key, e);
+                self.backend.invalidate(key);
+                None
+            }
+        }
+    }
+
+    fn store<T: Serialize>(&self, key: &str, value: &T, ttl: Duration, etag: Option<String>) {
+        if ttl.is_zero() {
+            return;
+        }
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                let entry = CachedEntry::new(value).with_etag(etag);
+                self.backend.put(key, entry, ttl);
+                self.insertions.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => warn!("Failed to serialize cache entry {}: {}", key, e),
+        }
+    }
+}
+
+/// Per-key locks, so concurrent async lookups of an uncached key send one request
+///
+/// The first caller holds the lock while it fetches and stores the response; the others
+/// wait and then find it in the cache. Responses that are not cached, such as errors, are
+/// fetched by each caller in turn.
+#[cfg(feature = "async")]
+#[derive(Debug, Default)]
+pub(crate) struct InFlight {
+    keys: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+#[cfg(feature = "async")]
+impl InFlight {
+    /// Wait until no other lookup of `key` is in flight
+    pub(crate) async fn lock(&self, key: &str) -> InFlightGuard<'_> {
+        let lock = Arc::clone(self.keys.lock().entry(key.to_string()).or_default());
+        InFlightGuard {
+            in_flight: self,
+            key: key.to_string(),
+            guard: lock.lock_owned().await,
+        }
+    }
+}
+
+/// Releases a key of [`InFlight`] when dropped
+#[cfg(feature = "async")]
+pub(crate) struct InFlightGuard<'a> {
+    in_flight: &'a InFlight,
+    key: String,
+    guard: tokio::sync::OwnedMutexGuard<()>,
+}
+
+#[cfg(feature = "async")]
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        let mut keys = self.in_flight.keys.lock();
+        // Only the map and this guard refer to the lock: nobody is waiting
+        if Arc::strong_count(tokio::sync::OwnedMutexGuard::mutex(&self.guard)) == 2 {
+            keys.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(value: &str) -> CachedEntry {
+        CachedEntry::new(serde_json::Value::from(value))
+    }
+
+    fn modified(details: &JobDetails, etag: Option<&str>) -> Conditional<JobDetails> {
+        Conditional::Modified {
+            value: details.clone(),
+            etag: etag.map(String::from),
+        }
+    }
+
+    /// The details for `refnr` if they are a cache hit
+    fn hit(cache: &ResponseCache, refnr: &str) -> Option<JobDetails> {
+        match cache.lookup_details(refnr, false) {
+            DetailsLookup::Hit(details) => Some(details),
+            _ => None,
+        }
+    }
+
+    fn temp_cache() -> (tempfile::TempDir, FileCache) {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = FileCache::new(dir.path().join("cache"));
+        (dir, cache)
+    }
+
+    #[test]
+    fn test_memory_hit_until_ttl() {
+        let cache = MemoryCache::new(10).unwrap();
+        let now = Instant::now();
+        let ttl = Duration::from_secs(60);
+        let stored = entry("a");
+
+        assert_eq!(cache.get_at("a", now), None);
+        cache.put_at("a", stored.clone(), ttl, now);
+        assert_eq!(
+            cache.get_at("a", now + Duration::from_secs(59)),
+            Some(stored)
+        );
+        assert_eq!(cache.get_at("a", now + ttl), None);
+        // Expired entries are removed on lookup
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_memory_evicts_expired_then_least_recent() {
+        let cache = MemoryCache::new(2).unwrap();
+        let now = Instant::now();
+        let ttl = Duration::from_secs(60);
+        cache.put_at("a", entry("a"), ttl, now);
+        cache.put_at("b", entry("b"), ttl, now + Duration::from_secs(30));
+
+        // Full: "a" is the least recently used
+        cache.put_at("c", entry("c"), ttl, now + Duration::from_secs(40));
+        assert_eq!(cache.get_at("a", now + Duration::from_secs(40)), None);
+        assert!(cache.get_at("b", now + Duration::from_secs(40)).is_some());
+
+        // Both expired: dropped together
+        let later = now + Duration::from_secs(200);
+        cache.put_at("d", entry("d"), ttl, later);
+        assert_eq!(cache.stats().entries, 1);
+        assert_eq!(cache.stats().evictions, 3);
+
+        // Replacing an entry does not evict others
+        cache.put_at("e", entry("e"), ttl, later);
+        cache.put_at("e", entry("e2"), ttl, later);
+        assert_eq!(cache.stats().entries, 2);
+        assert_eq!(cache.stats().evictions, 3);
+        assert_eq!(
+            cache.get_at("e", later).map(|e| e.value),
+            Some(serde_json::Value::from("e2"))
+        );
+    }
+
+    #[test]
+    fn test_memory_lru_eviction() {
+        let cache = MemoryCache::new(3).unwrap();
+        let ttl = Duration::from_secs(60);
+        for key in ["a", "b", "c"] {
+            cache.put(key, entry(key), ttl);
+        }
+        // Reading "a" makes "b" the least recently used
+        assert!(cache.get("a").is_some());
+
+        cache.put("d", entry("d"), ttl);
+        assert_eq!(cache.get("b"), None);
+        for key in ["a", "c", "d"] {
+            assert!(cache.get(key).is_some(), "{} was evicted", key);
+        }
+
+        // Now "a" is the least recently used
+        cache.put("e", entry("e"), ttl);
+        assert_eq!(cache.get("a"), None);
+
+        let stats = cache.stats();
+        assert_eq!(stats.evictions, 2);
+        assert_eq!(stats.entries, 3);
+    }
+
+    #[test]
+    fn test_memory_approximate_bytes() {
+        let cache = MemoryCache::new(10).unwrap();
+        let ttl = Duration::from_secs(60);
+        // "abc" is 5 bytes as JSON, plus 1 byte of key
+        cache.put("k", entry("abc"), ttl);
+        assert_eq!(cache.stats().approximate_bytes, 6);
+        cache.put("k", entry("abcdef"), ttl);
+        assert_eq!(cache.stats().approximate_bytes, 9);
+        cache.invalidate("k");
+        assert_eq!(cache.stats().approximate_bytes, 0);
+    }
+
+    #[test]
+    fn test_memory_invalidate_and_clear() {
+        let cache = MemoryCache::new(10).unwrap();
+        cache.put("a", entry("a"), Duration::from_secs(60));
+        cache.put("b", entry("b"), Duration::from_secs(60));
+        cache.invalidate("a");
+        assert_eq!(cache.get("a"), None);
+        assert!(cache.get("b").is_some());
+        cache.clear();
+        assert_eq!(cache.get("b"), None);
+    }
+
+    #[test]
+    fn test_zero_entries_rejected() {
+        assert!(matches!(
+            MemoryCache::new(0),
+            Err(Error::ConfigError { .. })
+        ));
+        let result = ResponseCache::new(
+            CacheConfig {
+                max_entries: 0,
+                ..Default::default()
+            },
+            None,
+            "https://example.org/",
+        );
+        assert!(matches!(result, Err(Error::ConfigError { .. })));
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let (_dir, cache) = temp_cache();
+        let stored = entry("a");
+
+        assert_eq!(cache.get("a"), None);
+        cache.put("a", stored.clone(), Duration::from_secs(60));
+        assert_eq!(cache.get("a"), Some(stored.clone()));
+
+        // Another instance on the same directory sees the entry
+        let reopened = FileCache::new(cache.dir());
+        assert_eq!(reopened.get("a"), Some(stored));
+
+        reopened.invalidate("a");
+        assert_eq!(cache.get("a"), None);
+    }
+
+    #[test]
+    fn test_file_expiry() {
+        let (_dir, cache) = temp_cache();
+        cache.put("a", entry("a"), Duration::from_millis(20));
+        std::thread::sleep(Duration::from_millis(50));
+
+        assert_eq!(cache.get("a"), None);
+        // The expired file is removed
+        assert!(!cache.path("a").exists());
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_file_corruption_is_a_miss() {
+        let (_dir, cache) = temp_cache();
+        cache.put("a", entry("a"), Duration::from_secs(60));
+        fs::write(cache.path("a"), b"{\"key\": \"a\", \"expires_at\": ").unwrap();
+
+        assert_eq!(cache.get("a"), None);
+        assert!(!cache.path("a").exists());
+
+        // Still usable afterwards
+        cache.put("a", entry("a2"), Duration::from_secs(60));
+        assert_eq!(
+            cache.get("a").map(|e| e.value),
+            Some(serde_json::Value::from("a2"))
+        );
+    }
+
+    #[test]
+    fn test_file_key_mismatch_is_a_miss() {
+        let (_dir, cache) = temp_cache();
+        cache.put("a", entry("a"), Duration::from_secs(60));
+        // Simulate a hash collision: the file for "b" holds the entry of "a"
+        fs::copy(cache.path("a"), cache.path("b")).unwrap();
+
+        assert_eq!(cache.get("b"), None);
+    }
+
+    #[test]
+    fn test_file_concurrent_writes_of_one_key() {
+        let (_dir, cache) = temp_cache();
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..25 {
+                        cache.put("a", entry("a"), Duration::from_secs(60));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            cache.get("a").map(|e| e.value),
+            Some(serde_json::Value::from("a"))
+        );
+        // Every temporary file was renamed into place
+        assert_eq!(fs::read_dir(cache.dir()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_file_clear() {
+        let (_dir, cache) = temp_cache();
+        // Clearing a directory that does not exist yet is fine
+        cache.clear();
+        cache.put("a", entry("a"), Duration::from_secs(60));
+        cache.put("b", entry("b"), Duration::from_secs(60));
+        assert_eq!(cache.stats().entries, 2);
+        assert!(cache.stats().approximate_bytes > 0);
+        cache.clear();
+
+        assert_eq!(fs::read_dir(cache.dir()).unwrap().count(), 0);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_fnv1a_is_stable() {
+        assert_eq!(fnv1a(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a("a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_details_round_trip() {
+        let cache =
+            ResponseCache::new(CacheConfig::default(), None, "https://example.org/").unwrap();
+        let details: JobDetails =
+            serde_json::from_str(include_str!("../tests/fixtures/job-details-full.json")).unwrap();
+
+        cache.complete_details("10001-1001601666-S", None, modified(&details, None));
+        assert_eq!(hit(&cache, "10001-1001601666-S"), Some(details));
+        assert_eq!(hit(&cache, "10001-OTHER-S"), None);
+    }
+
+    #[test]
+    fn test_revalidation() {
+        let cache = ResponseCache::new(
+            CacheConfig {
+                details_ttl: Duration::from_millis(20),
+                ..Default::default()
+            },
+            None,
+            "https://example.org/",
+        )
+        .unwrap();
+        let details = JobDetails::new("10001-A-S");
+        cache.complete_details("10001-A-S", None, modified(&details, Some("\"v1\"")));
+        assert_eq!(hit(&cache, "10001-A-S"), Some(details.clone()));
+
+        // Expired, but kept for revalidation
+        std::thread::sleep(Duration::from_millis(40));
+        let DetailsLookup::Revalidate(stale) = cache.lookup_details("10001-A-S", false) else {
+            panic!("expected a stale entry");
+        };
+        assert_eq!(stale.etag(), Some("\"v1\""));
+
+        // 304: the cached details are fresh again
+        let returned = cache.complete_details("10001-A-S", Some(stale), Conditional::NotModified);
+        assert_eq!(returned, details);
+        assert_eq!(hit(&cache, "10001-A-S"), Some(details));
+
+        // `fresh` revalidates even within the TTL; 200 replaces the entry and its ETag
+        let DetailsLookup::Revalidate(stale) = cache.lookup_details("10001-A-S", true) else {
+            panic!("expected a stale entry");
+        };
+        let changed = JobDetails::new("10001-A-S").with_titel("Neu");
+        cache.complete_details("10001-A-S", Some(stale), modified(&changed, Some("\"v2\"")));
+        assert_eq!(hit(&cache, "10001-A-S"), Some(changed));
+
+        let stats = cache.stats();
+        assert_eq!(stats.revalidations, 1);
+        assert_eq!(stats.insertions, 2);
+        assert_eq!(stats.hits, 3);
+        assert_eq!(stats.misses, 1);
+    }
+
+    #[test]
+    fn test_expired_without_etag_is_a_miss() {
+        let cache = ResponseCache::new(
+            CacheConfig {
+                details_ttl: Duration::from_millis(20),
+                ..Default::default()
+            },
+            None,
+            "https://example.org/",
+        )
+        .unwrap();
+        cache.complete_details(
+            "10001-A-S",
+            None,
+            modified(&JobDetails::new("10001-A-S"), None),
+        );
+        std::thread::sleep(Duration::from_millis(40));
+
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", false),
+            DetailsLookup::Miss
+        ));
+        // Entries with an ETag can be revalidated, others are fetched again
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", true),
+            DetailsLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn test_not_found_cached_only_if_enabled() {
+        let disabled =
+            ResponseCache::new(CacheConfig::default(), None, "https://example.org/").unwrap();
+        disabled.store_not_found("10001-A-S");
+        assert!(matches!(
+            disabled.lookup_details("10001-A-S", false),
+            DetailsLookup::Miss
+        ));
+
+        let cache = ResponseCache::new(
+            CacheConfig {
+                cache_not_found: true,
+                negative_ttl: Duration::from_millis(20),
+                ..Default::default()
+            },
+            None,
+            "https://example.org/",
+        )
+        .unwrap();
+        cache.complete_details(
+            "10001-A-S",
+            None,
+            modified(&JobDetails::new("10001-A-S"), None),
+        );
+        // The 404 replaces the cached details
+        cache.store_not_found("10001-A-S");
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", false),
+            DetailsLookup::NotFound
+        ));
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", true),
+            DetailsLookup::Miss
+        ));
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", false),
+            DetailsLookup::Miss
+        ));
+
+        let stats = cache.stats();
+        assert_eq!(stats.negative_hits, 1);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 2);
+    }
+
+    #[test]
+    fn test_zero_search_ttl_disables() {
+        let cache = ResponseCache::new(
+            CacheConfig {
+                search_ttl: Duration::ZERO,
+                ..Default::default()
+            },
+            None,
+            "https://example.org/",
+        )
+        .unwrap();
+        let options = SearchOptions::builder().was("Rust").build();
+
+        cache.store_search(&options, &JobSearchResponse::new(Vec::new()));
+        assert_eq!(cache.search(&options), None);
+    }
+
+    #[test]
+    fn test_unreadable_entry_is_dropped() {
+        let backend = Arc::new(MemoryCache::new(10).unwrap());
+        let cache = ResponseCache::new(
+            CacheConfig::default(),
+            Some(backend.clone()),
+            "https://example.org/",
+        )
+        .unwrap();
+        let key = cache.key("jobdetails", "10001-A-S");
+        backend.put(
+            &key,
+            CachedEntry::new(serde_json::json!({"stellenlokationen": "not a list"})),
+            Duration::from_secs(60),
+        );
+
+        assert_eq!(hit(&cache, "10001-A-S"), None);
+        assert_eq!(backend.get(&key), None);
+        assert_eq!(cache.stats().misses, 1);
+        assert_eq!(cache.stats().hits, 0);
+    }
+
+    #[test]
+    fn test_is_current_key() {
+        let current = format!("v{} https://example.org/ jobs q", CACHE_SCHEMA_VERSION);
+        assert!(is_current_key(&current));
+        assert!(!is_current_key("v0 https://example.org/ jobs q"));
+        assert!(!is_current_key(&format!("v{}0 jobs", CACHE_SCHEMA_VERSION)));
+        assert!(!is_current_key("https://example.org/ jobdetails 10001-A-S"));
+    }
+
+    #[test]
+    fn test_old_version_is_a_miss_and_replaced() {
+        let backend = Arc::new(MemoryCache::new(10).unwrap());
+        let cache = ResponseCache::new(
+            CacheConfig::default(),
+            Some(backend.clone()),
+            "https://example.org/",
+        )
+        .unwrap();
+        // Written by a release with an older layout
+        let old_key = "v0 https://example.org/ jobdetails 10001-A-S";
+        let old = JobDetails::new("10001-A-S").with_titel("Alt");
+        backend.put(
+            old_key,
+            CachedEntry::new(serde_json::to_value(&old).unwrap()),
+            Duration::from_secs(60),
+        );
+
+        assert!(matches!(
+            cache.lookup_details("10001-A-S", false),
+            DetailsLookup::Miss
+        ));
+        let details = JobDetails::new("10001-A-S").with_titel("Neu");
+        cache.complete_details("10001-A-S", None, modified(&details, None));
+        assert_eq!(hit(&cache, "10001-A-S"), Some(details));
+
+        assert_eq!(CacheHandle::new(Some(&cache)).purge_old_versions(), 1);
+        assert_eq!(backend.get(old_key), None);
+        assert_eq!(backend.stats().entries, 1);
+    }
+
+    #[test]
+    fn test_memory_purge_old_versions() {
+        let cache = MemoryCache::new(10).unwrap();
+        let current = format!("v{} jobs a", CACHE_SCHEMA_VERSION);
+        cache.put(&current, entry("a"), Duration::from_secs(60));
+        cache.put("v0 jobs a", entry("a"), Duration::from_secs(60));
+        cache.put("jobs a", entry("a"), Duration::from_secs(60));
+
+        assert_eq!(cache.purge_old_versions(), 2);
+        assert_eq!(
+            cache.get(&current).map(|e| e.value),
+            Some(serde_json::Value::from("a"))
+        );
+        assert_eq!(cache.stats().entries, 1);
+        assert_eq!(cache.stats().evictions, 0);
+    }
+
+    #[test]
+    fn test_file_purge_old_versions() {
+        let (_dir, cache) = temp_cache();
+        let current = format!("v{} jobs a", CACHE_SCHEMA_VERSION);
+        cache.put(&current, entry("a"), Duration::from_secs(60));
+        cache.put("v0 jobs a", entry("a"), Duration::from_secs(60));
+
+        assert_eq!(cache.purge_old_versions(), 1);
+        assert_eq!(
+            cache.get(&current).map(|e| e.value),
+            Some(serde_json::Value::from("a"))
+        );
+        assert_eq!(cache.stats().entries, 1);
+    }
+
+    #[test]
+    fn test_keys_include_host() {
+        let backend: Arc<dyn Cache> = Arc::new(MemoryCache::new(10).unwrap());
+        let a = ResponseCache::new(
+            CacheConfig::default(),
+            Some(backend.clone()),
+            "https://a.example.org/",
+        )
+        .unwrap();
+        let b = ResponseCache::new(
+            CacheConfig::default(),
+            Some(backend),
+            "https://b.example.org/",
+        )
+        .unwrap();
+
+        let details = JobDetails::new("10001-A-S");
+        a.complete_details("10001-A-S", None, modified(&details, None));
+        assert!(hit(&a, "10001-A-S").is_some());
+        assert_eq!(hit(&b, "10001-A-S"), None);
+    }
+
+    #[test]
+    fn test_search_key_ignores_call_order() {
+        use crate::Arbeitszeit;
+
+        let cache =
+            ResponseCache::new(CacheConfig::default(), None, "https://example.org/").unwrap();
+        let a = SearchOptions::builder()
+            .was("Rust")
+            .wo("Berlin")
+            .arbeitszeit(vec![Arbeitszeit::Teilzeit, Arbeitszeit::Vollzeit])
+            .build();
+        let b = SearchOptions::builder()
+            .arbeitszeit(vec![Arbeitszeit::Vollzeit, Arbeitszeit::Teilzeit])
+            .wo("Berlin")
+            .was("Rust")
+            .build();
+        let c = a.as_builder().wo("Hamburg").build();
+
+        assert_eq!(cache.search_key(&a), cache.search_key(&b));
+        assert_ne!(cache.search_key(&a), cache.search_key(&c));
+    }
+}
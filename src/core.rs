@@ -1,7 +1,14 @@
 //! Core shared functionality between sync and async implementations
 
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER};
 use serde::{Deserialize, Serialize};
-use tracing::debug;
+use tracing::{debug, warn};
 use url::Url;
 
 use crate::Error;
@@ -9,6 +16,24 @@ use crate::Error;
 /// Type alias for Result with the crate's Error type
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Base URL of the public Jobsuche API
+pub const DEFAULT_HOST: &str = "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service";
+
+/// Default header used to send the per-request correlation ID
+pub const DEFAULT_REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Default number of response body bytes kept in errors (`ClientConfig::error_body_limit`)
+pub const DEFAULT_ERROR_BODY_LIMIT: usize = 4096;
+
+/// Environment variable read by [`Credentials::from_env`]
+pub const API_KEY_ENV: &str = "JOBSUCHE_API_KEY";
+
+/// Environment variable holding the API host, read by `Jobsuche::from_env`
+pub const HOST_ENV: &str = "JOBSUCHE_HOST";
+
+/// Base of the job detail pages on arbeitsagentur.de, see [`Refnr::web_url`]
+pub const WEB_DETAIL_URL: &str = "https://www.arbeitsagentur.de/jobsuche/jobdetail/";
+
 /// An empty response structure, used for endpoints that return no data
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EmptyResponse;
@@ -18,6 +43,10 @@ pub struct EmptyResponse;
 /// The Jobsuche API uses a simple API key authentication scheme.
 /// The API key is sent via the `X-API-Key` HTTP header.
 ///
+/// Other bundesAPI services use OAuth2 client credentials instead. With
+/// [`Credentials::OAuth`] the clients fetch a bearer token from `token_url`, cache it,
+/// refresh it shortly before it expires and send it as `Authorization: Bearer …`.
+///
 /// # Default API Key
 ///
 /// The public API key is: `jobboerse-jobsuche`
@@ -25,12 +54,29 @@ pub struct EmptyResponse;
 pub enum Credentials {
     /// API Key authentication (default: "jobboerse-jobsuche")
     ApiKey(String),
+    /// OAuth2 client-credentials grant
+    OAuth {
+        client_id: String,
+        client_secret: String,
+        /// Token endpoint, e.g. `https://rest.arbeitsagentur.de/oauth/gettoken_cc`
+        token_url: String,
+    },
 }
 
 impl std::fmt::Debug for Credentials {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ApiKey(_) => f.debug_tuple("ApiKey").field(&"[REDACTED]").finish(),
+            Self::OAuth {
+                client_id,
+                token_url,
+                ..
+            } => f
+                .debug_struct("OAuth")
+                .field("client_id", client_id)
+                .field("client_secret", &"[REDACTED]")
+                .field("token_url", token_url)
+                .finish(),
         }
     }
 }
@@ -41,6 +87,34 @@ impl Default for Credentials {
     }
 }
 
+impl Credentials {
+    /// Read the API key from the `JOBSUCHE_API_KEY` environment variable
+    ///
+    /// Falls back to the default public API key if the variable is unset or empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::Credentials;
+    ///
+    /// let credentials = Credentials::from_env();
+    /// ```
+    pub fn from_env() -> Self {
+        match env_var(API_KEY_ENV) {
+            Some(key) => Self::ApiKey(key),
+            None => {
+                debug!("{} not set, using the default API key", API_KEY_ENV);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// Read an environment variable, treating empty values as unset
+pub(crate) fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|value| !value.is_empty())
+}
+
 /// Common data required for both sync and async clients
 #[derive(Clone, Debug)]
 pub struct ClientCore {
@@ -53,27 +127,36 @@ impl ClientCore {
     ///
     /// # Arguments
     ///
-    /// * `host` - The base URL of the Jobsuche API (e.g., "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service")
+    /// * `host` - The base URL of the Jobsuche API (typically [`DEFAULT_HOST`])
     /// * `credentials` - Authentication credentials (typically the default API key)
     ///
+    /// The host may include a path prefix, e.g. a gateway such as
+    /// `https://proxy.internal/ba/jobsuche-service`; endpoint paths are appended after it.
+    /// A trailing slash is stripped, so `…/jobsuche-service/` behaves the same.
+    ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::core::{ClientCore, Credentials};
+    /// use jobsuche::core::{ClientCore, Credentials, DEFAULT_HOST};
     ///
-    /// let core = ClientCore::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let core = ClientCore::new(DEFAULT_HOST, Credentials::default()).unwrap();
     /// ```
     pub fn new<H>(host: H, credentials: Credentials) -> Result<Self>
     where
         H: Into<String>,
     {
         let host_string = host.into();
-        let parsed_host = Url::parse(&host_string).inspect_err(|e| {
+        let mut parsed_host = Url::parse(&host_string).inspect_err(|e| {
             debug!("Failed to parse host URL '{}': {}", host_string, e);
         })?;
+        if let Some(trimmed) = parsed_host
+            .path()
+            .strip_suffix('/')
+            .filter(|p| !p.is_empty())
+        {
+            let trimmed = trimmed.to_string();
+            parsed_host.set_path(&trimmed);
+        }
 
         Ok(ClientCore {
             host: parsed_host,
@@ -81,23 +164,304 @@ impl ClientCore {
         })
     }
 
+    /// Return a copy of this core pointing at a different base URL
+    ///
+    /// The credentials are kept; the host is validated and normalized like in [`new`](Self::new).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jobsuche::core::{ClientCore, Credentials, DEFAULT_HOST};
+    ///
+    /// let core = ClientCore::new(DEFAULT_HOST, Credentials::default()).unwrap();
+    /// let staging = core.rebase("https://staging.example.com/jobsuche-service").unwrap();
+    /// assert_eq!(staging.host.host_str(), Some("staging.example.com"));
+    /// ```
+    pub fn rebase<H>(&self, host: H) -> Result<ClientCore>
+    where
+        H: Into<String>,
+    {
+        Self::new(host, self.credentials.clone())
+    }
+
     /// Get the API key from credentials
+    ///
+    /// Returns an empty string for [`Credentials::OAuth`], which does not use an API key.
     pub fn api_key(&self) -> &str {
         match &self.credentials {
             Credentials::ApiKey(key) => key,
+            Credentials::OAuth { .. } => "",
         }
     }
 
     /// Build a complete URL path
+    ///
+    /// Each segment is percent-encoded exactly once: `/`, `%`, spaces and other characters
+    /// that are not allowed in a path segment are escaped, while `=` and `+` are valid in
+    /// paths and kept as-is, so base64 values such as employer logo hashes stay readable.
     pub fn path(&self, segments: &[&str]) -> String {
+        self.url(segments).to_string()
+    }
+
+    /// Build a complete URL with query parameters
+    ///
+    /// `query` is a form-encoded query string such as the output of
+    /// [`SearchOptions::serialize`](crate::SearchOptions::serialize). Its parameters are
+    /// appended after any query the host URL already carries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jobsuche::core::{ClientCore, Credentials};
+    ///
+    /// let core = ClientCore::new("https://example.com/api/?tenant=1", Credentials::default()).unwrap();
+    /// assert_eq!(
+    ///     core.path_with_query(&["pc", "v4", "jobs"], Some("was=Rust")),
+    ///     "https://example.com/api/pc/v4/jobs?tenant=1&was=Rust"
+    /// );
+    /// ```
+    pub fn path_with_query(&self, segments: &[&str], query: Option<&str>) -> String {
+        let mut url = self.url(segments);
+        if let Some(query) = query.filter(|query| !query.is_empty()) {
+            url.query_pairs_mut()
+                .extend_pairs(url::form_urlencoded::parse(query.as_bytes()));
+        }
+        url.to_string()
+    }
+
+    fn url(&self, segments: &[&str]) -> Url {
         let mut url = self.host.clone();
         {
             let mut path_segments = url.path_segments_mut().expect("base URL is valid");
+            // A trailing slash on the host must not produce an empty segment
+            path_segments.pop_if_empty();
             for segment in segments {
                 path_segments.push(segment);
             }
         }
-        url.to_string()
+        url
+    }
+}
+
+/// Generate a fresh request ID and add it to `headers` under `header_name`
+///
+/// Returns the generated ID, or `None` if request IDs are disabled (`header_name` is `None`).
+pub(crate) fn insert_request_id(
+    headers: &mut HeaderMap,
+    header_name: Option<&str>,
+) -> Result<Option<String>> {
+    let Some(name) = header_name else {
+        return Ok(None);
+    };
+    let header = HeaderName::from_bytes(name.as_bytes()).map_err(|e| Error::ConfigError {
+        message: format!("invalid request ID header name {:?}: {}", name, e),
+    })?;
+    let id = uuid::Uuid::new_v4().to_string();
+    headers.insert(
+        header,
+        HeaderValue::from_str(&id).expect("UUIDs are valid header values"),
+    );
+    Ok(Some(id))
+}
+
+/// Parse the `Retry-After` header of a 429 response
+///
+/// Accepts both delay-seconds and an HTTP-date and returns the wait relative to `now`
+/// together with the absolute time. An HTTP-date in the past yields no wait (callers fall
+/// back to their backoff) but is still returned as the absolute time.
+pub(crate) fn parse_retry_after(
+    headers: &HeaderMap,
+    now: SystemTime,
+) -> (Option<Duration>, Option<SystemTime>) {
+    let Some(value) = headers.get(RETRY_AFTER) else {
+        return (None, None);
+    };
+    let value = value.to_str().unwrap_or_default().trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        let wait = Duration::from_secs(seconds);
+        return (Some(wait), now.checked_add(wait));
+    }
+    if let Ok(date) = httpdate::parse_http_date(value) {
+        return (date.duration_since(now).ok(), Some(date));
+    }
+
+    warn!("Retry-After header present but unparseable: {:?}", value);
+    (None, None)
+}
+
+/// Detect the file extension of an image from its leading bytes
+///
+/// Recognizes PNG, JPEG, GIF and WebP; returns `None` for anything else.
+#[cfg(feature = "async")]
+pub(crate) fn image_extension(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("png")
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("jpg")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("gif")
+    } else if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        Some("webp")
+    } else {
+        None
+    }
+}
+
+/// Reference number of a job listing, e.g. `10001-1001601666-S`
+///
+/// Listings are identified by their refnr, so `Refnr` is the key to use for
+/// deduplicating, diffing or caching them. It hashes and compares like the underlying
+/// string, which also makes `HashSet<Refnr>::contains("...")` work.
+///
+/// Construction from a `String` or `&str` does not check the value, as the API is the
+/// authority on which reference numbers exist; [`str::parse`] checks it with
+/// [`validate_refnr`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use jobsuche::Refnr;
+///
+/// let refnr: Refnr = "10001-1001601666-S".parse().unwrap();
+/// assert_eq!(refnr.encode(), "MTAwMDEtMTAwMTYwMTY2Ni1T");
+/// assert_eq!(Refnr::decode("MTAwMDEtMTAwMTYwMTY2Ni1T").unwrap(), refnr);
+///
+/// let seen: HashSet<Refnr> = [refnr.clone(), refnr].into_iter().collect();
+/// assert_eq!(seen.len(), 1);
+/// assert!(seen.contains("10001-1001601666-S"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct Refnr(String);
+
+impl Refnr {
+    /// Wrap a reference number without checking it
+    pub fn new(refnr: impl Into<String>) -> Self {
+        Refnr(refnr.into())
+    }
+
+    /// The reference number as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap into the underlying string
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Base64 form expected by the job details endpoint, see [`encode_refnr`]
+    pub fn encode(&self) -> String {
+        encode_refnr(&self.0)
+    }
+
+    /// URL-safe base64 form for web links, see [`encode_refnr_urlsafe`]
+    pub fn encode_urlsafe(&self) -> String {
+        encode_refnr_urlsafe(&self.0)
+    }
+
+    /// Public detail page of the job on arbeitsagentur.de
+    ///
+    /// Built from [`encode_urlsafe`](Self::encode_urlsafe) under [`WEB_DETAIL_URL`]. The
+    /// page answers with 404 once the job is no longer published.
+    ///
+    /// ```
+    /// use jobsuche::Refnr;
+    ///
+    /// let refnr = Refnr::new("10001-1001601666-S");
+    /// assert_eq!(
+    ///     refnr.web_url().as_str(),
+    ///     "https://www.arbeitsagentur.de/jobsuche/jobdetail/MTAwMDEtMTAwMTYwMTY2Ni1T"
+    /// );
+    /// ```
+    pub fn web_url(&self) -> Url {
+        let url = format!("{}{}", WEB_DETAIL_URL, self.encode_urlsafe());
+        Url::parse(&url).expect("URL-safe base64 is a valid path segment")
+    }
+
+    /// Decode a base64-encoded reference number, see [`decode_refnr`]
+    pub fn decode(encoded: &str) -> Result<Self> {
+        decode_refnr(encoded).map(Refnr)
+    }
+
+    /// Check the shape of the reference number, see [`validate_refnr`]
+    pub fn validate(&self) -> Result<()> {
+        validate_refnr(&self.0)
+    }
+}
+
+impl fmt::Display for Refnr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Parses and checks a reference number with [`validate_refnr`]
+impl FromStr for Refnr {
+    type Err = Error;
+
+    fn from_str(refnr: &str) -> Result<Self> {
+        validate_refnr(refnr)?;
+        Ok(Refnr(refnr.to_string()))
+    }
+}
+
+impl Deref for Refnr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Refnr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Refnr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Refnr {
+    fn from(refnr: String) -> Self {
+        Refnr(refnr)
+    }
+}
+
+impl From<&str> for Refnr {
+    fn from(refnr: &str) -> Self {
+        Refnr(refnr.to_string())
+    }
+}
+
+impl From<Refnr> for String {
+    fn from(refnr: Refnr) -> Self {
+        refnr.0
+    }
+}
+
+impl PartialEq<str> for Refnr {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Refnr {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Refnr {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
     }
 }
 
@@ -106,8 +470,8 @@ impl ClientCore {
 /// The Jobsuche API requires reference numbers to be base64-encoded when
 /// requesting job details. This is a known quirk of the API.
 ///
-/// Reference numbers are expected to contain only ASCII alphanumeric characters
-/// and hyphens (e.g., `10001-1001601666-S`). Inputs that are empty, longer than
+/// Reference numbers are expected to contain only ASCII alphanumeric characters,
+/// underscores and hyphens (e.g., `10001-1001601666-S` or `14386-1697_JB4166099-S`). Inputs that are empty, longer than
 /// 50 characters, or contain unexpected characters will trigger a warning log
 /// but will still be encoded to avoid breaking existing callers.
 ///
@@ -130,9 +494,16 @@ pub fn encode_refnr(refnr: &str) -> String {
             "encode_refnr called with unusually long input ({} chars)",
             refnr.len()
         );
+    } else if let Some(decoded) = already_encoded(refnr) {
+        tracing::warn!(
+            "encode_refnr called with an already encoded reference number {:?} (decodes to {:?}); \
+             pass the decoded form instead",
+            refnr,
+            decoded
+        );
     } else if !refnr
         .bytes()
-        .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
     {
         tracing::warn!(
             "encode_refnr called with non-standard characters: {:?}",
@@ -143,8 +514,31 @@ pub fn encode_refnr(refnr: &str) -> String {
     general_purpose::STANDARD.encode(refnr.as_bytes())
 }
 
+/// Encode a reference number with the URL-safe base64 alphabet and without padding
+///
+/// Use this when building web links; the API itself expects [`encode_refnr`].
+///
+/// # Example
+///
+/// ```
+/// use jobsuche::core::encode_refnr_urlsafe;
+///
+/// assert_eq!(encode_refnr_urlsafe("10001-TEST1-S"), "MTAwMDEtVEVTVDEtUw");
+/// ```
+pub fn encode_refnr_urlsafe(refnr: &str) -> String {
+    use base64::{engine::general_purpose, Engine as _};
+    general_purpose::URL_SAFE_NO_PAD.encode(refnr.as_bytes())
+}
+
 /// Decode a base64-encoded reference number back to its original form
 ///
+/// Accepts the standard and the URL-safe alphabet, with or without padding, so values
+/// copied from arbeitsagentur.de links decode as well.
+///
+/// Returns [`Error::InvalidRefnr`] if the decoded bytes are not valid UTF-8 or do not have
+/// the shape of a reference number (see [`validate_refnr`]), since such values only lead to
+/// confusing 404s later.
+///
 /// # Example
 ///
 /// ```
@@ -156,8 +550,95 @@ pub fn encode_refnr(refnr: &str) -> String {
 /// ```
 pub fn decode_refnr(encoded: &str) -> Result<String> {
     use base64::{engine::general_purpose, Engine as _};
-    let bytes = general_purpose::STANDARD.decode(encoded)?;
-    Ok(String::from_utf8_lossy(&bytes).to_string())
+
+    // Report the error of the canonical encoding if no variant matches
+    let bytes = general_purpose::STANDARD.decode(encoded).or_else(|e| {
+        [
+            &general_purpose::STANDARD_NO_PAD,
+            &general_purpose::URL_SAFE,
+            &general_purpose::URL_SAFE_NO_PAD,
+        ]
+        .iter()
+        .find_map(|engine| engine.decode(encoded).ok())
+        .ok_or(e)
+    })?;
+
+    let refnr = String::from_utf8(bytes).map_err(|e| Error::InvalidRefnr {
+        reason: format!("decoded bytes are not valid UTF-8: {}", e),
+    })?;
+    if !is_refnr_shaped(&refnr) {
+        return Err(Error::InvalidRefnr {
+            reason: format!("{:?} does not look like a reference number", refnr),
+        });
+    }
+    Ok(refnr)
+}
+
+/// Check that `refnr` looks like a reference number before it is sent to the API
+///
+/// Accepted are digits followed by hyphen-separated parts of ASCII letters, digits and
+/// underscores, which covers both `10001-1001601666-S` and `14386-1697_JB4166099-S`.
+/// Empty input is rejected, and so is input that is already base64-encoded (e.g. copied
+/// from a browser URL): the error then names the decoded refnr to pass instead.
+///
+/// The clients run this check in `job_details` unless
+/// [`ClientConfig::validate_refnr`](crate::ClientConfig::validate_refnr) is disabled.
+///
+/// # Example
+///
+/// ```
+/// use jobsuche::core::validate_refnr;
+///
+/// assert!(validate_refnr("10001-1001601666-S").is_ok());
+/// assert!(validate_refnr("MTAwMDEtMTAwMTYwMTY2Ni1T").is_err());
+/// assert!(validate_refnr("").is_err());
+/// ```
+pub fn validate_refnr(refnr: &str) -> Result<()> {
+    if refnr.is_empty() {
+        return Err(Error::InvalidRefnr {
+            reason: "reference number is empty".to_string(),
+        });
+    }
+    if is_refnr_shaped(refnr) {
+        return Ok(());
+    }
+    if let Some(decoded) = already_encoded(refnr) {
+        return Err(Error::InvalidRefnr {
+            reason: format!(
+                "{:?} is already base64-encoded; pass the decoded reference number {:?} instead",
+                refnr, decoded
+            ),
+        });
+    }
+    Err(Error::InvalidRefnr {
+        reason: format!(
+            "{:?} does not look like a reference number such as 10001-1001601666-S \
+             (disable ClientConfig::validate_refnr to send it anyway)",
+            refnr
+        ),
+    })
+}
+
+/// The decoded refnr if `refnr` is itself a base64-encoded reference number
+///
+/// Users often copy the encoded form out of browser URLs; encoding it again guarantees a 404.
+fn already_encoded(refnr: &str) -> Option<String> {
+    if is_refnr_shaped(refnr) {
+        return None;
+    }
+    decode_refnr(refnr).ok()
+}
+
+/// Whether `refnr` has the shape described in [`validate_refnr`]
+fn is_refnr_shaped(refnr: &str) -> bool {
+    let mut parts = refnr.split('-');
+    let prefix_is_numeric = parts
+        .next()
+        .is_some_and(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()));
+    let mut rest = parts.peekable();
+    prefix_is_numeric
+        && rest.peek().is_some()
+        && rest.all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_'))
 }
 
 #[cfg(test)]
@@ -165,6 +646,50 @@ mod tests {
     use super::*;
     use tracing_test::traced_test;
 
+    fn retry_after_headers(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        assert_eq!(
+            parse_retry_after(&retry_after_headers("120"), now),
+            (
+                Some(Duration::from_secs(120)),
+                Some(now + Duration::from_secs(120))
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let at = now + Duration::from_secs(90);
+        let headers = retry_after_headers(&httpdate::fmt_http_date(at));
+        assert_eq!(
+            parse_retry_after(&headers, now),
+            (Some(Duration::from_secs(90)), Some(at))
+        );
+
+        // A date in the past has no wait, but the absolute time is kept
+        let past = now - Duration::from_secs(90);
+        let headers = retry_after_headers(&httpdate::fmt_http_date(past));
+        assert_eq!(parse_retry_after(&headers, now), (None, Some(past)));
+    }
+
+    #[test]
+    fn test_parse_retry_after_absent_or_invalid() {
+        let now = SystemTime::now();
+        assert_eq!(parse_retry_after(&HeaderMap::new(), now), (None, None));
+        assert_eq!(
+            parse_retry_after(&retry_after_headers("soon"), now),
+            (None, None)
+        );
+    }
+
     #[test]
     fn test_encode_refnr() {
         let refnr = "10001-1001601666-S";
@@ -187,6 +712,127 @@ mod tests {
         assert_eq!(refnr, decoded);
     }
 
+    #[test]
+    fn test_decode_refnr_all_alphabets() {
+        // Both alphabets agree here; only the padding differs
+        for encoded in ["MTAwMDEtVEVTVDEtUw==", "MTAwMDEtVEVTVDEtUw"] {
+            assert_eq!(decode_refnr(encoded).unwrap(), "10001-TEST1-S");
+        }
+
+        // '?' encodes to '/' in the standard and '_' in the URL-safe alphabet; all variants
+        // decode, and the result is then rejected for its shape rather than as bad base64
+        for encoded in [
+            "MTAwMDEtQX4/LVM=",
+            "MTAwMDEtQX4/LVM",
+            "MTAwMDEtQX4_LVM=",
+            "MTAwMDEtQX4_LVM",
+        ] {
+            assert!(matches!(
+                decode_refnr(encoded),
+                Err(Error::InvalidRefnr { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_decode_refnr_invalid() {
+        assert!(matches!(
+            decode_refnr("not base64!"),
+            Err(Error::Base64Error(_))
+        ));
+        assert!(matches!(
+            decode_refnr("MTAwMDEtQX4/LVM_"),
+            Err(Error::Base64Error(_))
+        ));
+        assert!(matches!(decode_refnr("M"), Err(Error::Base64Error(_))));
+    }
+
+    #[test]
+    fn test_decode_refnr_invalid_utf8() {
+        use base64::{engine::general_purpose, Engine as _};
+
+        let encoded = general_purpose::STANDARD.encode(b"10001-\xFF\xFE-S");
+        match decode_refnr(&encoded) {
+            Err(Error::InvalidRefnr { reason }) => assert!(reason.contains("UTF-8")),
+            other => panic!("expected InvalidRefnr, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_refnr_empty() {
+        assert!(matches!(decode_refnr(""), Err(Error::InvalidRefnr { .. })));
+    }
+
+    #[test]
+    fn test_decode_refnr_shape() {
+        for valid in ["10001-1001601666-S", "12265-PP2Y1-S", "10000-1184867112"] {
+            assert_eq!(decode_refnr(&encode_refnr(valid)).unwrap(), valid);
+        }
+        for invalid in [
+            "hello world",
+            "S-10001",
+            "10001-",
+            "10001--S",
+            "-10001-S",
+            "10001",
+        ] {
+            assert!(
+                matches!(
+                    decode_refnr(&encode_refnr(invalid)),
+                    Err(Error::InvalidRefnr { .. })
+                ),
+                "{:?} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_refnr_formats() {
+        for refnr in [
+            "10001-1001601666-S",
+            "10000-1184867112-S",
+            "14386-1697_JB4166099-S",
+            "12265-PP2Y1-S",
+        ] {
+            assert!(validate_refnr(refnr).is_ok(), "{} should be valid", refnr);
+        }
+    }
+
+    #[test]
+    fn test_validate_refnr_double_encoded() {
+        for encoded in [
+            "MTAwMDEtMTAwMTYwMTY2Ni1T",
+            "MTQzODYtMTY5N19KQjQxNjYwOTktUw==",
+        ] {
+            match validate_refnr(encoded) {
+                Err(Error::InvalidRefnr { reason }) => {
+                    assert!(reason.contains("already base64-encoded"), "{}", reason)
+                }
+                other => panic!("expected InvalidRefnr, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_refnr_empty_and_garbage() {
+        assert!(matches!(
+            validate_refnr(""),
+            Err(Error::InvalidRefnr { .. })
+        ));
+        match validate_refnr("not a refnr") {
+            Err(Error::InvalidRefnr { reason }) => assert!(reason.contains("validate_refnr")),
+            other => panic!("expected InvalidRefnr, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_encode_refnr_urlsafe_roundtrip() {
+        let encoded = encode_refnr_urlsafe("10001-TEST1-S");
+        assert_eq!(encoded, "MTAwMDEtVEVTVDEtUw");
+        assert_eq!(decode_refnr(&encoded).unwrap(), "10001-TEST1-S");
+    }
+
     #[test]
     fn test_credentials_debug_redacts_key() {
         let creds = Credentials::default();
@@ -201,6 +847,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_oauth_credentials_debug_redacts_secret() {
+        let creds = Credentials::OAuth {
+            client_id: "client".to_string(),
+            client_secret: "top-secret".to_string(),
+            token_url: "https://auth.example.com/token".to_string(),
+        };
+        let debug_output = format!("{:?}", creds);
+        assert!(!debug_output.contains("top-secret"));
+        assert!(debug_output.contains("client"));
+    }
+
     #[test]
     fn test_encode_refnr_valid_formats() {
         let encoded = encode_refnr("10001-1001601666-S");
@@ -229,6 +887,189 @@ mod tests {
         assert!(!encoded.is_empty());
     }
 
+    #[test]
+    fn test_default_host_parses() {
+        let core = ClientCore::new(DEFAULT_HOST, Credentials::default()).unwrap();
+        assert_eq!(core.host.host_str(), Some("rest.arbeitsagentur.de"));
+        assert_eq!(
+            core.path(&["pc", "v4", "jobs"]),
+            "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service/pc/v4/jobs"
+        );
+    }
+
+    #[test]
+    fn test_path_with_trailing_slash_host() {
+        let core = ClientCore::new("https://example.com/api/", Credentials::default()).unwrap();
+        assert_eq!(
+            core.path(&["pc", "v4", "jobs"]),
+            "https://example.com/api/pc/v4/jobs"
+        );
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], Some("was=Rust")),
+            "https://example.com/api/pc/v4/jobs?was=Rust"
+        );
+    }
+
+    #[test]
+    fn test_path_preserves_base_path_prefix() {
+        let cases = [
+            (
+                "https://proxy.internal",
+                "https://proxy.internal/pc/v4/jobs",
+            ),
+            (
+                "https://proxy.internal/",
+                "https://proxy.internal/pc/v4/jobs",
+            ),
+            (
+                "https://proxy.internal/ba/jobsuche-service",
+                "https://proxy.internal/ba/jobsuche-service/pc/v4/jobs",
+            ),
+            (
+                "https://proxy.internal/ba/jobsuche-service/",
+                "https://proxy.internal/ba/jobsuche-service/pc/v4/jobs",
+            ),
+        ];
+        for (host, expected) in cases {
+            let core = ClientCore::new(host, Credentials::default()).unwrap();
+            assert_eq!(core.path(&["pc", "v4", "jobs"]), expected, "host {}", host);
+        }
+    }
+
+    #[test]
+    fn test_rebase() {
+        let core = ClientCore::new(
+            "https://example.com",
+            Credentials::ApiKey("custom-key".to_string()),
+        )
+        .unwrap();
+
+        let rebased = core.rebase("https://staging.example.com/api/").unwrap();
+        assert_eq!(rebased.path(&["pc"]), "https://staging.example.com/api/pc");
+        assert_eq!(rebased.api_key(), "custom-key");
+        assert_eq!(core.path(&["pc"]), "https://example.com/pc");
+
+        assert!(matches!(
+            core.rebase("not a url"),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_strips_trailing_slash() {
+        let core = ClientCore::new(
+            "https://proxy.internal/ba/jobsuche-service/",
+            Credentials::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            core.host.as_str(),
+            "https://proxy.internal/ba/jobsuche-service"
+        );
+    }
+
+    #[test]
+    fn test_path_with_query() {
+        let core = ClientCore::new(DEFAULT_HOST, Credentials::default()).unwrap();
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], Some("was=Software+Entwickler&page=2")),
+            "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service/pc/v4/jobs?was=Software+Entwickler&page=2"
+        );
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], None),
+            core.path(&["pc", "v4", "jobs"])
+        );
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], Some("")),
+            core.path(&["pc", "v4", "jobs"])
+        );
+    }
+
+    #[test]
+    fn test_path_with_query_keeps_host_query() {
+        let core =
+            ClientCore::new("https://example.com/api?tenant=1", Credentials::default()).unwrap();
+        assert_eq!(
+            core.path(&["pc", "v4", "jobs"]),
+            "https://example.com/api/pc/v4/jobs?tenant=1"
+        );
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], Some("was=M%C3%BCnchen")),
+            "https://example.com/api/pc/v4/jobs?tenant=1&was=M%C3%BCnchen"
+        );
+    }
+
+    #[test]
+    fn test_path_encodes_special_characters_once() {
+        let core = ClientCore::new("https://example.com", Credentials::default()).unwrap();
+        let path = core.path(&["ed", "a/b c+d=%"]);
+        assert_eq!(path, "https://example.com/ed/a%2Fb%20c+d=%25");
+
+        // Parsing the URL again neither re-encodes nor decodes the segment
+        let parsed = Url::parse(&path).unwrap();
+        assert_eq!(parsed.as_str(), path);
+        assert_eq!(
+            parsed.path_segments().unwrap().next_back(),
+            Some("a%2Fb%20c+d=%25")
+        );
+    }
+
+    #[test]
+    fn test_path_logo_hash() {
+        let core = ClientCore::new(DEFAULT_HOST, Credentials::default()).unwrap();
+        let hash = "VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=";
+        let path = core.path(&["ed", "v1", "arbeitgeberlogo", hash]);
+        assert_eq!(
+            path,
+            "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service/ed/v1/arbeitgeberlogo/VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo="
+        );
+        assert_eq!(Url::parse(&path).unwrap().as_str(), path);
+    }
+
+    #[test]
+    fn test_path_root_host() {
+        let core = ClientCore::new("http://127.0.0.1:1234", Credentials::default()).unwrap();
+        assert_eq!(
+            core.path_with_query(&["pc", "v4", "jobs"], Some("size=1")),
+            "http://127.0.0.1:1234/pc/v4/jobs?size=1"
+        );
+    }
+
+    #[test]
+    fn test_insert_request_id() {
+        let mut headers = HeaderMap::new();
+        let id = insert_request_id(&mut headers, Some(DEFAULT_REQUEST_ID_HEADER))
+            .unwrap()
+            .unwrap();
+        assert_eq!(id.len(), 36);
+        assert_eq!(headers.get("x-request-id").unwrap(), id.as_str());
+    }
+
+    #[test]
+    fn test_insert_request_id_disabled() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(insert_request_id(&mut headers, None).unwrap(), None);
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn test_insert_request_id_invalid_header_name() {
+        let mut headers = HeaderMap::new();
+        let result = insert_request_id(&mut headers, Some("not a header"));
+        assert!(matches!(result, Err(Error::ConfigError { .. })));
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_image_extension() {
+        assert_eq!(image_extension(b"\x89PNG\r\n\x1a\n\0\0"), Some("png"));
+        assert_eq!(image_extension(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("jpg"));
+        assert_eq!(image_extension(b"GIF89a..."), Some("gif"));
+        assert_eq!(image_extension(b"RIFF\0\0\0\0WEBPVP8 "), Some("webp"));
+        assert_eq!(image_extension(b"<html>"), None);
+        assert_eq!(image_extension(b""), None);
+    }
+
     // --- Mutation-killing tests ---
 
     #[test]
@@ -286,10 +1127,76 @@ mod tests {
         assert!(logs_contain("non-standard characters"));
     }
 
+    #[traced_test]
+    #[test]
+    fn test_encode_refnr_warns_on_already_encoded() {
+        let encoded = encode_refnr("10001-1001601666-S");
+        encode_refnr(&encoded);
+        assert!(logs_contain("already encoded"));
+        assert!(logs_contain("10001-1001601666-S"));
+    }
+
+    #[traced_test]
+    #[test]
+    fn test_encode_refnr_no_already_encoded_warn_for_refnr_or_garbage() {
+        encode_refnr("10001-1001601666-S");
+        encode_refnr("hello@world");
+        assert!(!logs_contain("already encoded"));
+    }
+
     #[traced_test]
     #[test]
     fn test_encode_refnr_warns_on_empty_traced() {
         encode_refnr("");
         assert!(logs_contain("empty string"));
     }
+
+    #[test]
+    fn test_refnr_identity() {
+        use std::collections::{HashMap, HashSet};
+
+        let listings = ["10001-A-S", "10001-B-S", "10001-A-S"];
+        let unique: HashSet<Refnr> = listings.iter().copied().map(Refnr::from).collect();
+        assert_eq!(unique.len(), 2);
+        assert!(unique.contains("10001-A-S"));
+        assert!(!unique.contains("10001-C-S"));
+
+        let mut seen: HashMap<Refnr, u32> = HashMap::new();
+        for refnr in listings {
+            *seen.entry(Refnr::new(refnr)).or_default() += 1;
+        }
+        assert_eq!(seen["10001-A-S"], 2);
+
+        // Compares like a string, in both directions of construction
+        let refnr = Refnr::from("10001-A-S".to_string());
+        assert_eq!(refnr, "10001-A-S");
+        assert_eq!(refnr, "10001-A-S".to_string());
+        assert_eq!(refnr.as_str(), "10001-A-S");
+        assert_eq!(refnr.len(), 9);
+        assert_eq!(refnr.to_string(), "10001-A-S");
+        assert_eq!(String::from(refnr.clone()), refnr.clone().into_string());
+        assert!(Refnr::new("10001-A-S") < Refnr::new("10001-B-S"));
+    }
+
+    #[test]
+    fn test_refnr_parse_and_encoding() {
+        let refnr: Refnr = "10001-1001601666-S".parse().unwrap();
+        assert_eq!(refnr.encode(), encode_refnr("10001-1001601666-S"));
+        assert_eq!(refnr.encode_urlsafe(), "MTAwMDEtMTAwMTYwMTY2Ni1T");
+        assert_eq!(Refnr::decode(&refnr.encode()).unwrap(), refnr);
+        assert!(refnr.validate().is_ok());
+
+        assert!(matches!(
+            "MTAwMDEtMTAwMTYwMTY2Ni1T".parse::<Refnr>(),
+            Err(Error::InvalidRefnr { .. })
+        ));
+        assert!("".parse::<Refnr>().is_err());
+        // Unchecked construction keeps unusual values the API may return
+        assert!(Refnr::new("FUTURE/FORMAT").validate().is_err());
+
+        // Serialized as a plain string
+        let json = serde_json::to_string(&refnr).unwrap();
+        assert_eq!(json, r#""10001-1001601666-S""#);
+        assert_eq!(serde_json::from_str::<Refnr>(&json).unwrap(), refnr);
+    }
 }
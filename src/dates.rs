@@ -0,0 +1,310 @@
+//! Typed access to date fields (requires the `chrono` feature)
+//!
+//! The API sends dates as strings, mostly `YYYY-MM-DD` for dates and RFC 3339 for
+//! timestamps. The accessors accept either form for every field and return `None` for
+//! values they cannot parse; the raw strings stay available on the structs.
+
+use std::time::SystemTime;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use tracing::debug;
+
+use crate::rep::{JobDetails, JobListing};
+
+impl JobListing {
+    /// Publication date, from `aktuelle_veroeffentlichungsdatum`
+    pub fn published_date(&self) -> Option<NaiveDate> {
+        parse_date(
+            "aktuelleVeroeffentlichungsdatum",
+            &self.aktuelle_veroeffentlichungsdatum,
+        )
+    }
+
+    /// Start date, from `eintrittsdatum`
+    pub fn start_date(&self) -> Option<NaiveDate> {
+        parse_date("eintrittsdatum", &self.eintrittsdatum)
+    }
+
+    /// Last modification, from `modifikations_timestamp`
+    ///
+    /// Timestamps without an offset are taken as UTC.
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        parse_timestamp("modifikationsTimestamp", &self.modifikations_timestamp)
+    }
+
+    /// Whether the listing changed after `since`, judged by [`modified_at`](Self::modified_at)
+    ///
+    /// A missing or unparseable timestamp counts as modified, so watchers don't skip
+    /// postings they can't judge.
+    ///
+    /// ```
+    /// # use jobsuche::JobListing;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// # let job: JobListing = serde_json::from_str(
+    /// #     r#"{"refnr": "10001-TEST-S", "arbeitsort": {}, "modifikationsTimestamp": "2026-03-20T10:15:30.123"}"#,
+    /// # ).unwrap();
+    /// let last_seen = Utc.with_ymd_and_hms(2026, 3, 19, 8, 0, 0).unwrap();
+    /// assert!(job.was_modified_since(last_seen));
+    /// ```
+    pub fn was_modified_since<Tz: TimeZone>(&self, since: DateTime<Tz>) -> bool {
+        modified_after(self.modified_at(), since)
+    }
+
+    /// Time since publication, in whole days
+    ///
+    /// `now` defaults to today (UTC). Negative for listings dated in the future, `None` if
+    /// the publication date is missing or unparseable.
+    ///
+    /// ```
+    /// # use jobsuche::JobListing;
+    /// use chrono::NaiveDate;
+    ///
+    /// # let job: JobListing = serde_json::from_str(
+    /// #     r#"{"refnr": "10001-TEST-S", "arbeitsort": {}, "aktuelleVeroeffentlichungsdatum": "2026-03-15"}"#,
+    /// # ).unwrap();
+    /// let today = NaiveDate::from_ymd_opt(2026, 3, 20);
+    /// assert_eq!(job.publication_age(today).unwrap().num_days(), 5);
+    /// ```
+    pub fn publication_age(&self, now: impl Into<Option<NaiveDate>>) -> Option<Duration> {
+        let now = now.into().unwrap_or_else(today);
+        Some(now - self.published_date()?)
+    }
+
+    /// Whether the listing was published at most `days` days ago (future dates included)
+    ///
+    /// Same window as the `veroeffentlichtseit` search filter, counted from today (UTC).
+    /// `false` if the publication date is missing or unparseable.
+    pub fn is_newer_than_days(&self, days: u32) -> bool {
+        self.publication_age(None)
+            .is_some_and(|age| age.num_days() <= i64::from(days))
+    }
+}
+
+/// Today's date in UTC
+fn today() -> NaiveDate {
+    DateTime::<Utc>::from(SystemTime::now()).date_naive()
+}
+
+impl JobDetails {
+    /// Date of first publication, from `erste_veroeffentlichungsdatum`
+    pub fn first_published_date(&self) -> Option<NaiveDate> {
+        parse_date(
+            "datumErsteVeroeffentlichung",
+            &self.erste_veroeffentlichungsdatum,
+        )
+    }
+
+    /// Last modification, from `aenderungsdatum`
+    ///
+    /// Timestamps without an offset are taken as UTC; a plain date means midnight UTC.
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        parse_timestamp("aenderungsdatum", &self.aenderungsdatum)
+    }
+
+    /// Whether the details changed after `since`, see [`JobListing::was_modified_since`]
+    pub fn was_modified_since<Tz: TimeZone>(&self, since: DateTime<Tz>) -> bool {
+        modified_after(self.modified_at(), since)
+    }
+}
+
+/// Strictly after `since`; unknown modification times count as modified
+fn modified_after<Tz: TimeZone>(
+    modified: Option<DateTime<FixedOffset>>,
+    since: DateTime<Tz>,
+) -> bool {
+    modified.is_none_or(|modified| modified > since)
+}
+
+/// Parse a date, also accepting a timestamp (its date in its own offset)
+fn parse_date(field: &str, value: &Option<String>) -> Option<NaiveDate> {
+    let value = value.as_deref()?.trim();
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .or_else(|| to_timestamp(value).map(|timestamp| timestamp.date_naive()));
+    if date.is_none() {
+        debug!("Ignoring unparseable {} {:?}", field, value);
+    }
+    date
+}
+
+/// Parse a timestamp, also accepting a plain date (midnight UTC)
+fn parse_timestamp(field: &str, value: &Option<String>) -> Option<DateTime<FixedOffset>> {
+    let value = value.as_deref()?.trim();
+    let timestamp = to_timestamp(value).or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN).and_utc().fixed_offset())
+    });
+    if timestamp.is_none() {
+        debug!("Ignoring unparseable {} {:?}", field, value);
+    }
+    timestamp
+}
+
+/// RFC 3339, or an ISO 8601 date and time without offset (taken as UTC)
+fn to_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value).ok().or_else(|| {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|timestamp| timestamp.and_utc().fixed_offset())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(published: Option<&str>, modified: Option<&str>) -> JobListing {
+        serde_json::from_value(serde_json::json!({
+            "refnr": "10001-TEST-S",
+            "arbeitsort": {},
+            "aktuelleVeroeffentlichungsdatum": published,
+            "modifikationsTimestamp": modified,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_date_only() {
+        let job = listing(Some("2026-03-15"), None);
+        assert_eq!(job.published_date(), NaiveDate::from_ymd_opt(2026, 3, 15));
+        assert_eq!(job.modified_at(), None);
+        assert_eq!(job.start_date(), None);
+    }
+
+    #[test]
+    fn test_timestamp_formats() {
+        let expected = DateTime::parse_from_rfc3339("2026-03-20T10:15:30Z").unwrap();
+
+        for raw in [
+            "2026-03-20T10:15:30.000Z",
+            "2026-03-20T10:15:30Z",
+            "2026-03-20T11:15:30+01:00",
+            "2026-03-20T10:15:30",
+            "2026-03-20T10:15:30.000",
+        ] {
+            let job = listing(None, Some(raw));
+            assert_eq!(job.modified_at(), Some(expected), "{}", raw);
+        }
+
+        let job = listing(Some("2026-03-20T23:30:00+01:00"), Some("2026-03-20"));
+        assert_eq!(job.published_date(), NaiveDate::from_ymd_opt(2026, 3, 20));
+        assert_eq!(
+            job.modified_at(),
+            Some(DateTime::parse_from_rfc3339("2026-03-20T00:00:00Z").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_garbage_is_none() {
+        for raw in [
+            "",
+            "sofort",
+            "15.03.2026",
+            "2026-13-01",
+            "2026-03-20T25:00:00Z",
+        ] {
+            let job = listing(Some(raw), Some(raw));
+            assert_eq!(job.published_date(), None, "{}", raw);
+            assert_eq!(job.modified_at(), None, "{}", raw);
+            // The raw value is untouched
+            assert_eq!(job.aktuelle_veroeffentlichungsdatum.as_deref(), Some(raw));
+        }
+    }
+
+    #[test]
+    fn test_was_modified_since() {
+        let since = Utc.with_ymd_and_hms(2026, 3, 20, 10, 15, 30).unwrap();
+
+        for raw in ["2026-03-20T10:15:31", "2026-03-20T10:15:30.123"] {
+            assert!(
+                listing(None, Some(raw)).was_modified_since(since),
+                "{}",
+                raw
+            );
+        }
+        for raw in [
+            "2026-03-20T10:15:30",
+            "2026-03-20T10:15:30.000",
+            "2026-03-20T09:00:00.5",
+        ] {
+            assert!(
+                !listing(None, Some(raw)).was_modified_since(since),
+                "{}",
+                raw
+            );
+        }
+
+        // Compared as instants across offsets
+        let since_berlin = DateTime::parse_from_rfc3339("2026-03-20T11:15:30+01:00").unwrap();
+        assert!(!listing(None, Some("2026-03-20T10:15:30")).was_modified_since(since_berlin));
+
+        // Unknown times count as modified
+        assert!(listing(None, None).was_modified_since(since));
+        assert!(listing(None, Some("gestern")).was_modified_since(since));
+
+        let details: JobDetails = serde_json::from_value(serde_json::json!({
+            "aenderungsdatum": "2026-03-20T10:15:30.123",
+        }))
+        .unwrap();
+        assert!(details.was_modified_since(since));
+        assert!(!details.was_modified_since(since + Duration::seconds(1)));
+    }
+
+    #[test]
+    fn test_publication_age() {
+        let now = NaiveDate::from_ymd_opt(2026, 3, 20);
+
+        let job = listing(Some("2026-03-13"), None);
+        assert_eq!(job.publication_age(now), Some(Duration::days(7)));
+        assert_eq!(
+            listing(Some("2026-03-20"), None).publication_age(now),
+            Some(Duration::zero())
+        );
+        // Across a month boundary
+        assert_eq!(
+            listing(Some("2026-02-27"), None).publication_age(now),
+            Some(Duration::days(21))
+        );
+
+        // Future-dated postings have a negative age
+        let future = listing(Some("2026-03-22"), None);
+        assert_eq!(future.publication_age(now), Some(Duration::days(-2)));
+
+        assert_eq!(listing(None, None).publication_age(now), None);
+        assert_eq!(listing(Some("bald"), None).publication_age(now), None);
+    }
+
+    #[test]
+    fn test_is_newer_than_days() {
+        let days_ago = |days: i64| {
+            let date = today() - Duration::days(days);
+            listing(Some(&date.format("%Y-%m-%d").to_string()), None)
+        };
+
+        assert!(days_ago(0).is_newer_than_days(0));
+        assert!(days_ago(7).is_newer_than_days(7));
+        assert!(!days_ago(8).is_newer_than_days(7));
+        assert!(days_ago(-3).is_newer_than_days(1));
+        assert!(days_ago(3).publication_age(None).is_some());
+        assert!(!listing(None, None).is_newer_than_days(365));
+    }
+
+    #[test]
+    fn test_job_details_dates() {
+        let details: JobDetails = serde_json::from_value(serde_json::json!({
+            "datumErsteVeroeffentlichung": "2026-03-01",
+            "aenderungsdatum": "2026-03-20T10:15:30.123+01:00",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            details.first_published_date(),
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+        );
+        let modified = details.modified_at().unwrap();
+        assert_eq!(modified.offset().local_minus_utc(), 3600);
+        assert_eq!(modified.timestamp_subsec_millis(), 123);
+    }
+}
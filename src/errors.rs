@@ -1,12 +1,41 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
 use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
 use thiserror::Error;
+use tracing::warn;
+
+use crate::hooks::Endpoint;
 
 /// An enumeration over potential errors that may happen when sending a request to the Jobsuche API
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm. For common
+/// branching, [`kind`](Error::kind), [`status_code`](Error::status_code) and
+/// [`is_not_found`](Error::is_not_found) avoid matching on variants altogether.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Error associated with HTTP request
+    ///
+    /// Timeouts and connection failures are reported as [`Error::Timeout`] and
+    /// [`Error::Connect`] instead.
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
+
+    /// The request timed out
+    #[error("Request timed out while {phase}: {source}")]
+    Timeout {
+        /// Whether the timeout hit while connecting or while waiting for the response
+        phase: TimeoutPhase,
+        source: reqwest::Error,
+    },
+
+    /// Could not connect to the API (e.g. connection refused or DNS failure)
+    #[error("Could not connect to Jobsuche API: {0}")]
+    Connect(#[source] reqwest::Error),
 
     /// Error associated with IO
     #[error("IO error: {0}")]
@@ -16,32 +45,137 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    /// A response body could not be decoded into the expected type
+    ///
+    /// Usually means the API changed a field; `path` names it.
+    #[error("Could not decode response body at `{path}`: {message}\nBody: {snippet}")]
+    Decode {
+        /// Path of the offending field, e.g. `stellenangebote[3].refnr` (`.` for the root)
+        path: String,
+        /// Error reported by the JSON decoder, including line and column
+        message: String,
+        /// Excerpt of the body around the error (see `ClientConfig::error_body_limit`)
+        snippet: String,
+    },
+
+    /// A response contained fields the client does not map
+    ///
+    /// Only returned with [`UnknownFieldPolicy::Deny`](crate::UnknownFieldPolicy::Deny).
+    #[error("Response contains unknown fields: {}", .fields.join(", "))]
+    UnknownFields {
+        /// Paths of the unknown fields, e.g. `stellenangebote[3].tarifvertrag`
+        fields: Vec<String>,
+    },
+
     /// Client request errors
-    #[error("Jobsuche API error ({code}):\n{errors:#?}")]
-    Fault { code: StatusCode, errors: ApiErrors },
+    #[error(
+        "Jobsuche API error ({code}, request id: {}){}: {}{}",
+        .request_id.as_deref().unwrap_or("none"),
+        RequestContext::suffix(.request),
+        .errors.first_message().unwrap_or("no error details"),
+        .body.as_deref().map(|body| format!("\nBody: {}", body)).unwrap_or_default()
+    )]
+    Fault {
+        code: StatusCode,
+        errors: ApiErrors,
+        /// Correlation ID sent with the failed request (see `ClientConfig::request_id_header`)
+        request_id: Option<String>,
+        /// The request that failed
+        request: Option<Box<RequestContext>>,
+        /// Raw response body, truncated to `ClientConfig::error_body_limit` bytes
+        ///
+        /// Kept even if the body parsed as [`ApiErrors`]; `None` if the body was empty or
+        /// could not be read.
+        body: Option<Box<str>>,
+    },
 
-    /// Unauthorized - invalid API key
-    #[error("Could not connect to Jobsuche API: Unauthorized (check your API key)")]
-    Unauthorized,
+    /// The API rejected the request parameters (400), e.g. a malformed `umkreis`
+    #[error(
+        "Invalid request{}: {}{}",
+        RequestContext::suffix(.request),
+        validation_message(.messages),
+        .body.as_deref().map(|body| format!("\nBody: {}", body)).unwrap_or_default()
+    )]
+    InvalidRequest {
+        /// Validation messages from the response body, empty if it had none
+        messages: Vec<String>,
+        /// The request that was rejected
+        request: Option<Box<RequestContext>>,
+        /// Raw response body, truncated to `ClientConfig::error_body_limit` bytes
+        body: Option<Box<str>>,
+    },
+
+    /// Unauthorized - invalid API key, or an OAuth token could not be obtained
+    #[error(
+        "Could not connect to Jobsuche API: Unauthorized ({}){}",
+        .context.as_deref().unwrap_or("check your API key"),
+        RequestContext::suffix(.request)
+    )]
+    Unauthorized {
+        /// Details, e.g. why an OAuth token request failed
+        context: Option<String>,
+        /// The request that was rejected; `None` if no API request was sent
+        request: Option<Box<RequestContext>>,
+    },
 
     /// Rate limiting or temporary block
-    #[error("Jobsuche API request blocked: Forbidden (possible rate limiting)")]
-    Forbidden,
+    #[error(
+        "Jobsuche API request blocked: Forbidden (possible rate limiting){}",
+        RequestContext::suffix(.request)
+    )]
+    Forbidden {
+        /// The request that was rejected
+        request: Option<Box<RequestContext>>,
+    },
 
     /// Rate limited - too many requests
-    #[error("Rate limited by API. Retry after: {retry_after:?} seconds")]
+    #[error(
+        "Rate limited by API, {}{}",
+        .retry_after.map(|wait| format!("retry in {}", human_duration(wait)))
+            .unwrap_or_else(|| "no retry time given".to_string()),
+        RequestContext::suffix(.request)
+    )]
     RateLimited {
-        /// Number of seconds to wait before retrying (from Retry-After header)
-        retry_after: Option<u64>,
+        /// How long to wait before retrying, from the `Retry-After` header
+        ///
+        /// Before 0.5 this was `Option<u64>` in seconds; use `retry_after.map(|d| d.as_secs())`
+        /// for the old value.
+        retry_after: Option<Duration>,
+        /// When retrying is allowed again, from either form of the `Retry-After` header
+        ///
+        /// Unlike `retry_after` this is also set if the header named a date in the past.
+        retry_at: Option<SystemTime>,
+        /// The request that was rejected
+        request: Option<Box<RequestContext>>,
     },
 
     /// Resource not found (common for job details that have expired)
-    #[error("Resource not found (job may have expired or been removed)")]
-    NotFound,
+    ///
+    /// Use `resource` to tell an expired job or a missing logo (both expected) from a 404 on
+    /// the search endpoint, which usually means the host URL is wrong.
+    #[error(
+        "{}{}",
+        not_found_message(*.resource, .id.as_deref()),
+        RequestContext::suffix(.request)
+    )]
+    NotFound {
+        /// Kind of resource that was requested
+        resource: ResourceKind,
+        /// Reference number or employer hash ID, if the request was for a single resource
+        id: Option<String>,
+        /// The request that failed; its URL contains the encoded refnr or logo hash
+        request: Option<Box<RequestContext>>,
+    },
 
     /// HTTP method is not allowed
-    #[error("Jobsuche API error: MethodNotAllowed")]
-    MethodNotAllowed,
+    #[error(
+        "Jobsuche API error: MethodNotAllowed{}",
+        RequestContext::suffix(.request)
+    )]
+    MethodNotAllowed {
+        /// The request that was rejected
+        request: Option<Box<RequestContext>>,
+    },
 
     /// URI parse error
     #[error("Could not connect to Jobsuche API: {0}")]
@@ -55,19 +189,1642 @@ pub enum Error {
     #[error("Builder validation failed: {message}")]
     BuilderError { message: String },
 
+    /// A decoded reference number is not valid UTF-8 or does not look like a refnr
+    #[error("Invalid reference number: {reason}")]
+    InvalidRefnr { reason: String },
+
     /// Base64 encoding/decoding error
     #[error("Base64 error: {0}")]
     Base64Error(#[from] base64::DecodeError),
+
+    /// An error tagged with what the caller was doing, see [`ResultExt`]
+    ///
+    /// [`kind`](Error::kind), [`status_code`](Error::status_code) and the other accessors
+    /// look through the context; use [`root`](Error::root) to match on the original variant.
+    #[error("{context}: {source}")]
+    Context {
+        /// What failed, e.g. `fetching details for 10001-1001601666-S`
+        context: String,
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// The built-in retry loop gave up after retrying at least once
+    ///
+    /// Requests that fail on the first attempt, or with an error that is not retryable,
+    /// return the error as-is. The accessors look through this wrapper like through
+    /// [`Error::Context`], except that [`is_retryable`](Error::is_retryable) is always false.
+    #[error(
+        "Giving up after {attempts} attempts in {}: {source}",
+        human_duration(*.elapsed)
+    )]
+    RetriesExhausted {
+        /// Number of requests sent, including the first
+        attempts: u32,
+        /// Time from the first request until giving up, including backoff sleeps
+        elapsed: Duration,
+        /// Error of the last attempt
+        #[source]
+        source: Box<Error>,
+    },
+
+    /// The first attempt was still running when `ClientConfig::retry_deadline` ran out
+    ///
+    /// Only returned by the async client. If an earlier attempt failed, its error is
+    /// returned instead, wrapped in [`Error::RetriesExhausted`].
+    #[error(
+        "Request did not finish within the retry deadline of {}",
+        human_duration(*.deadline)
+    )]
+    DeadlineExceeded {
+        /// The configured deadline
+        deadline: Duration,
+    },
+}
+
+/// Attach domain context to errors
+///
+/// Used by the pagination streams and bulk helpers so that a failure in the middle of a batch
+/// says which job or operation it belongs to.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{Jobsuche, ResultExt};
+///
+/// let client = Jobsuche::default_client().unwrap();
+/// let refnr = "10001-1001601666-S";
+/// if let Err(e) = client.job_details(refnr).context_refnr(refnr) {
+///     // "fetching details for 10001-1001601666-S: Job 10001-1001601666-S not found ..."
+///     eprintln!("{}", e);
+///     assert!(e.is_not_found());
+/// }
+/// ```
+pub trait ResultExt<T> {
+    /// Tag an error with the reference number whose details were being fetched
+    fn context_refnr(self, refnr: &str) -> Result<T>;
+
+    /// Tag an error with a short description of the operation, e.g. `"fetching search results"`
+    fn context_op(self, operation: &'static str) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn context_refnr(self, refnr: &str) -> Result<T> {
+        self.map_err(|e| e.context(format!("fetching details for {}", refnr)))
+    }
+
+    fn context_op(self, operation: &'static str) -> Result<T> {
+        self.map_err(|e| e.context(operation.to_string()))
+    }
+}
+
+/// Phase of a request in which a timeout occurred
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeoutPhase {
+    /// Establishing the connection took longer than `ClientConfig::connect_timeout`
+    Connect,
+    /// Sending the request or reading the response took longer than `ClientConfig::timeout`
+    Read,
+}
+
+impl std::fmt::Display for TimeoutPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeoutPhase::Connect => write!(f, "connecting"),
+            TimeoutPhase::Read => write!(f, "reading the response"),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            let phase = if e.is_connect() {
+                TimeoutPhase::Connect
+            } else {
+                TimeoutPhase::Read
+            };
+            Error::Timeout { phase, source: e }
+        } else if e.is_connect() {
+            Error::Connect(e)
+        } else {
+            Error::Http(e)
+        }
+    }
+}
+
+/// Kind of resource reported by [`Error::NotFound`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    /// Job details; the job has most likely expired
+    JobDetails,
+    /// Employer logo; many employers have none
+    EmployerLogo,
+    /// The job search endpoint; usually a wrong host URL
+    Search,
+    /// Anything else
+    Other,
+}
+
+impl From<Endpoint> for ResourceKind {
+    fn from(endpoint: Endpoint) -> Self {
+        match endpoint {
+            Endpoint::Jobs => ResourceKind::Search,
+            Endpoint::JobDetails => ResourceKind::JobDetails,
+            Endpoint::EmployerLogo => ResourceKind::EmployerLogo,
+        }
+    }
+}
+
+fn validation_message(messages: &[String]) -> String {
+    if messages.is_empty() {
+        "no validation details".to_string()
+    } else {
+        messages.join("; ")
+    }
+}
+
+fn not_found_message(resource: ResourceKind, id: Option<&str>) -> String {
+    let id = id.map(|id| format!(" {}", id)).unwrap_or_default();
+    match resource {
+        ResourceKind::JobDetails => {
+            format!("Job{} not found (job may have expired or been removed)", id)
+        }
+        ResourceKind::EmployerLogo => format!("No logo found for employer{}", id),
+        ResourceKind::Search => {
+            "Search endpoint not found (check the host URL of the client)".to_string()
+        }
+        ResourceKind::Other => format!("Resource{} not found", id),
+    }
+}
+
+/// The API request an error belongs to
+///
+/// Attached to errors derived from an HTTP status, so failures in a batch can be traced
+/// back to a refnr or search. The URL never contains credentials; the API key and bearer
+/// tokens are sent as headers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Endpoint the request was sent to
+    pub endpoint: Endpoint,
+    /// Full request URL, including the encoded refnr or the search query
+    pub url: String,
+}
+
+impl RequestContext {
+    pub(crate) fn new(endpoint: Endpoint, url: &str) -> Self {
+        Self {
+            endpoint,
+            url: url.to_string(),
+        }
+    }
+
+    /// Display suffix for an optional context, empty if there is none
+    fn suffix(request: &Option<Box<RequestContext>>) -> String {
+        request
+            .as_ref()
+            .map(|request| format!(" [{}]", request))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} request to {}", self.endpoint.as_str(), self.url)
+    }
+}
+
+/// Broad classification of an [`Error`](enum@Error), see [`Error::kind`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Invalid credentials or access denied (401, 403, failed OAuth token request)
+    Auth,
+    /// The resource does not exist (404), e.g. an expired job
+    NotFound,
+    /// The API asked the client to slow down (429)
+    RateLimited,
+    /// Temporary failure: connection errors, timeouts, 500/502/503/504
+    Transient,
+    /// The request itself is wrong (other 4xx, invalid refnr) or failed locally
+    Client,
+    /// A response body could not be decoded
+    Decode,
+    /// Invalid client configuration, host URL or search options
+    Config,
+}
+
+impl ErrorKind {
+    /// Short name of the kind, as serialized (e.g. `rate_limited`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Auth => "auth",
+            ErrorKind::NotFound => "not_found",
+            ErrorKind::RateLimited => "rate_limited",
+            ErrorKind::Transient => "transient",
+            ErrorKind::Client => "client",
+            ErrorKind::Decode => "decode",
+            ErrorKind::Config => "config",
+        }
+    }
+}
+
+impl Error {
+    /// Classify this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jobsuche::{Error, ErrorKind};
+    /// use std::time::Duration;
+    ///
+    /// let error = Error::RateLimited {
+    ///     retry_after: Some(Duration::from_secs(5)),
+    ///     retry_at: None,
+    ///     request: None,
+    /// };
+    /// assert_eq!(error.kind(), ErrorKind::RateLimited);
+    /// assert!(error.is_retryable());
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Http(e) if e.is_decode() => ErrorKind::Decode,
+            Error::Http(e) if e.is_builder() => ErrorKind::Config,
+            Error::Http(e) => e.status().map_or(ErrorKind::Transient, status_kind),
+            Error::Timeout { .. } | Error::Connect(_) | Error::DeadlineExceeded { .. } => {
+                ErrorKind::Transient
+            }
+            Error::IO(_) => ErrorKind::Client,
+            Error::Serde(_) | Error::Decode { .. } | Error::UnknownFields { .. } => {
+                ErrorKind::Decode
+            }
+            Error::Fault { code, .. } => status_kind(*code),
+            Error::InvalidRequest { .. } => ErrorKind::Client,
+            Error::Unauthorized { .. } | Error::Forbidden { .. } => ErrorKind::Auth,
+            Error::RateLimited { .. } => ErrorKind::RateLimited,
+            Error::NotFound { .. } => ErrorKind::NotFound,
+            Error::MethodNotAllowed { .. } => ErrorKind::Client,
+            Error::ParseError(_) | Error::ConfigError { .. } | Error::BuilderError { .. } => {
+                ErrorKind::Config
+            }
+            Error::InvalidRefnr { .. } | Error::Base64Error(_) => ErrorKind::Client,
+            Error::Context { source, .. } | Error::RetriesExhausted { source, .. } => source.kind(),
+        }
+    }
+
+    /// The original error below any [`Error::Context`] and [`Error::RetriesExhausted`] layers
+    ///
+    /// ```
+    /// use jobsuche::{Error, ResultExt};
+    ///
+    /// let result: jobsuche::Result<()> = Err(Error::Forbidden { request: None });
+    /// let error = result.context_op("fetching search results").unwrap_err();
+    /// assert!(matches!(error.root(), Error::Forbidden { .. }));
+    /// ```
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } | Error::RetriesExhausted { source, .. } => source.root(),
+            error => error,
+        }
+    }
+
+    /// Wrap this error in an [`Error::Context`]
+    fn context(self, context: String) -> Self {
+        Error::Context {
+            context,
+            source: Box::new(self),
+        }
+    }
+
+    /// Wrap the last error of a retry loop in an [`Error::RetriesExhausted`]
+    ///
+    /// Returns the error unchanged if no retry happened (`attempts` is 1).
+    pub(crate) fn retries_exhausted(self, attempts: u32, elapsed: Duration) -> Self {
+        if attempts < 2 {
+            return self;
+        }
+        Error::RetriesExhausted {
+            attempts,
+            elapsed,
+            source: Box::new(self),
+        }
+    }
+
+    /// HTTP status of the API response that caused this error
+    ///
+    /// `None` for errors without a response, such as timeouts, decode errors or invalid
+    /// configuration. [`Error::Unauthorized`] always reports 401, including when an OAuth
+    /// token could not be obtained.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{Jobsuche, StatusCode};
+    ///
+    /// let client = Jobsuche::default_client().unwrap();
+    /// match client.job_details("10001-1001601666-S") {
+    ///     Ok(job) => println!("{:?}", job.titel),
+    ///     Err(e) if e.status_code() == Some(StatusCode::NOT_FOUND) => println!("expired"),
+    ///     Err(e) => eprintln!("{}", e),
+    /// }
+    /// ```
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Error::Http(e) => e.status(),
+            Error::Fault { code, .. } => Some(*code),
+            Error::InvalidRequest { .. } => Some(StatusCode::BAD_REQUEST),
+            Error::Unauthorized { .. } => Some(StatusCode::UNAUTHORIZED),
+            Error::Forbidden { .. } => Some(StatusCode::FORBIDDEN),
+            Error::NotFound { .. } => Some(StatusCode::NOT_FOUND),
+            Error::MethodNotAllowed { .. } => Some(StatusCode::METHOD_NOT_ALLOWED),
+            Error::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Error::Context { source, .. } | Error::RetriesExhausted { source, .. } => {
+                source.status_code()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this is an [`Error::NotFound`] for any kind of resource
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.root(), Error::NotFound { .. })
+    }
+
+    /// Set the reference number or hash ID of an [`Error::NotFound`]
+    pub(crate) fn with_id(mut self, resource_id: &str) -> Self {
+        if let Error::NotFound { id, .. } = &mut self {
+            *id = Some(resource_id.to_string());
+        }
+        self
+    }
+
+    /// Whether a retry of the failed request could plausibly succeed
+    ///
+    /// True for [`ErrorKind::Transient`] and [`ErrorKind::RateLimited`]: connection errors,
+    /// timeouts, 429 and 500/502/503/504 responses. Client errors such as 400, 401, 403, 404
+    /// and 405 are never retried. This is exactly the policy of the built-in retry loops, so
+    /// applications with their own retry or queueing layer can reuse it.
+    ///
+    /// Always false for [`Error::RetriesExhausted`]: the client already retried.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::RetriesExhausted { .. } => false,
+            Error::Context { source, .. } => source.is_retryable(),
+            _ => matches!(self.kind(), ErrorKind::Transient | ErrorKind::RateLimited),
+        }
+    }
+
+    /// The API request this error belongs to, if it was caused by an error status
+    pub fn request(&self) -> Option<&RequestContext> {
+        match self {
+            Error::Fault { request, .. }
+            | Error::InvalidRequest { request, .. }
+            | Error::Unauthorized { request, .. }
+            | Error::Forbidden { request }
+            | Error::RateLimited { request, .. }
+            | Error::NotFound { request, .. }
+            | Error::MethodNotAllowed { request } => request.as_deref(),
+            Error::Context { source, .. } | Error::RetriesExhausted { source, .. } => {
+                source.request()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Structured form for logging, e.g. with `serde_json::to_string(&error)`
+///
+/// The shape is stable: `error` names the variant in snake case, `kind` is the
+/// [`ErrorKind`], `message` is the `Display` output, and variant-specific fields such as
+/// `status`, `retry_after` (seconds), `retry_at` (Unix seconds), `url` or `errors` are only
+/// present when known. Sources from reqwest, IO and serde appear only in `message`.
+/// Errors wrapped by [`ResultExt`] serialize like the original error plus a `context` list,
+/// outermost first; [`Error::RetriesExhausted`] adds `attempts` and `elapsed_ms`.
+///
+/// ```
+/// use jobsuche::Error;
+/// use std::time::Duration;
+///
+/// let error = Error::RateLimited {
+///     retry_after: Some(Duration::from_secs(60)),
+///     retry_at: None,
+///     request: None,
+/// };
+/// let json = serde_json::to_value(&error).unwrap();
+/// assert_eq!(json["kind"], "rate_limited");
+/// assert_eq!(json["status"], 429);
+/// assert_eq!(json["retry_after"], 60);
+/// ```
+impl Serialize for Error {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("error", self.root().variant_name())?;
+        map.serialize_entry("kind", &self.kind())?;
+        if let Some(status) = self.status_code() {
+            map.serialize_entry("status", &status.as_u16())?;
+        }
+
+        match self.root() {
+            Error::Timeout { phase, .. } => map.serialize_entry("phase", phase)?,
+            Error::Decode { path, snippet, .. } => {
+                map.serialize_entry("path", path)?;
+                map.serialize_entry("snippet", snippet)?;
+            }
+            Error::UnknownFields { fields } => map.serialize_entry("fields", fields)?,
+            Error::Fault {
+                errors,
+                request_id,
+                body,
+                ..
+            } => {
+                map.serialize_entry("errors", errors)?;
+                if let Some(request_id) = request_id {
+                    map.serialize_entry("request_id", request_id)?;
+                }
+                if let Some(body) = body {
+                    map.serialize_entry("body", body)?;
+                }
+            }
+            Error::InvalidRequest { messages, body, .. } => {
+                map.serialize_entry("messages", messages)?;
+                if let Some(body) = body {
+                    map.serialize_entry("body", body)?;
+                }
+            }
+            Error::RateLimited {
+                retry_after,
+                retry_at,
+                ..
+            } => {
+                if let Some(retry_after) = retry_after {
+                    map.serialize_entry("retry_after", &retry_after.as_secs())?;
+                }
+                if let Some(retry_at) =
+                    retry_at.and_then(|at| at.duration_since(SystemTime::UNIX_EPOCH).ok())
+                {
+                    map.serialize_entry("retry_at", &retry_at.as_secs())?;
+                }
+            }
+            Error::NotFound { resource, id, .. } => {
+                map.serialize_entry("resource", resource)?;
+                if let Some(id) = id {
+                    map.serialize_entry("id", id)?;
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(request) = self.request() {
+            map.serialize_entry("endpoint", request.endpoint.as_str())?;
+            map.serialize_entry("url", &request.url)?;
+        }
+
+        let mut context = Vec::new();
+        let mut error = self;
+        loop {
+            match error {
+                Error::Context {
+                    context: layer,
+                    source,
+                } => {
+                    context.push(layer.as_str());
+                    error = source.as_ref();
+                }
+                Error::RetriesExhausted {
+                    attempts,
+                    elapsed,
+                    source,
+                } => {
+                    map.serialize_entry("attempts", attempts)?;
+                    map.serialize_entry("elapsed_ms", &(elapsed.as_millis() as u64))?;
+                    error = source.as_ref();
+                }
+                _ => break,
+            }
+        }
+        if !context.is_empty() {
+            map.serialize_entry("context", &context)?;
+        }
+        map.serialize_entry("message", &self.to_string())?;
+        map.end()
+    }
+}
+
+impl Error {
+    /// Snake-case name of the variant, used as `error` in the serialized form
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Error::Http(_) => "http",
+            Error::Timeout { .. } => "timeout",
+            Error::Connect(_) => "connect",
+            Error::IO(_) => "io",
+            Error::Serde(_) => "serde",
+            Error::Decode { .. } => "decode",
+            Error::UnknownFields { .. } => "unknown_fields",
+            Error::Fault { .. } => "fault",
+            Error::InvalidRequest { .. } => "invalid_request",
+            Error::Unauthorized { .. } => "unauthorized",
+            Error::Forbidden { .. } => "forbidden",
+            Error::RateLimited { .. } => "rate_limited",
+            Error::NotFound { .. } => "not_found",
+            Error::MethodNotAllowed { .. } => "method_not_allowed",
+            Error::ParseError(_) => "parse_error",
+            Error::ConfigError { .. } => "config_error",
+            Error::BuilderError { .. } => "builder_error",
+            Error::InvalidRefnr { .. } => "invalid_refnr",
+            Error::Base64Error(_) => "base64_error",
+            Error::Context { .. } => "context",
+            Error::RetriesExhausted { .. } => "retries_exhausted",
+            Error::DeadlineExceeded { .. } => "deadline_exceeded",
+        }
+    }
+}
+
+/// Classify an error status code
+fn status_kind(status: StatusCode) -> ErrorKind {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorKind::Auth,
+        StatusCode::NOT_FOUND => ErrorKind::NotFound,
+        StatusCode::TOO_MANY_REQUESTS => ErrorKind::RateLimited,
+        status if is_transient_status(status) => ErrorKind::Transient,
+        _ => ErrorKind::Client,
+    }
+}
+
+/// Format a wait such as `45s`, `2m 5s` or `1h 30m`
+fn human_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    match seconds {
+        0..60 => format!("{}s", seconds),
+        60..3600 => format!("{}m {}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+/// Placeholder for secrets found in captured response bodies
+const REDACTED: &str = "[redacted]";
+
+/// Lossy UTF-8 excerpt of at most `limit` bytes of `body`, starting near `offset`
+///
+/// The excerpt starts up to `limit / 2` bytes before `offset` so the context of a decode
+/// error is visible. Clipped ends are marked with `…`, and occurrences of `secret` (the
+/// client's API key) are replaced.
+pub(crate) fn body_snippet(body: &[u8], offset: usize, limit: usize, secret: &str) -> String {
+    let offset = offset.min(body.len());
+    let start = offset.saturating_sub(limit / 2);
+    let end = body.len().min(start.saturating_add(limit));
+
+    let mut snippet = String::from_utf8_lossy(&body[start..end]).into_owned();
+    if !secret.is_empty() {
+        snippet = snippet.replace(secret, REDACTED);
+    }
+    if start > 0 {
+        snippet.insert(0, '…');
+    }
+    if end < body.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+/// Handling of response fields not mapped by the response types
+///
+/// See [`ClientConfig::unknown_fields`](crate::ClientConfig::unknown_fields).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownFieldPolicy {
+    /// Drop unknown fields
+    #[default]
+    Ignore,
+    /// Log unknown fields at `warn` level and decode the response anyway
+    Warn,
+    /// Fail with [`Error::UnknownFields`] listing the unknown fields
+    Deny,
+}
+
+/// Decode a JSON response body, reporting failures as [`Error::Decode`]
+///
+/// The clients always buffer the whole body before decoding (as `Response::json` does
+/// internally), so keeping it for the error costs nothing extra on the success path.
+/// Fields that `T` does not map are handled according to `unknown_fields`.
+pub(crate) fn decode_json<T: DeserializeOwned>(
+    body: &[u8],
+    limit: usize,
+    unknown_fields: UnknownFieldPolicy,
+    secret: &str,
+) -> Result<T> {
+    let decode_error = |path: String, e: serde_json::Error| Error::Decode {
+        path,
+        message: e.to_string(),
+        snippet: body_snippet(body, byte_offset(body, e.line(), e.column()), limit, secret),
+    };
+
+    let mut unknown = Vec::new();
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = if unknown_fields == UnknownFieldPolicy::Ignore {
+        serde_path_to_error::deserialize(&mut deserializer)
+    } else {
+        let mut record = |path: serde_ignored::Path| unknown.push(field_path(&path));
+        serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+            &mut deserializer,
+            &mut record,
+        ))
+    }
+    .map_err(|e| {
+        let path = e.path().to_string();
+        decode_error(path, e.into_inner())
+    })?;
+    // Reject trailing data like `serde_json::from_slice` does
+    deserializer
+        .end()
+        .map_err(|e| decode_error(".".to_string(), e))?;
+
+    if !unknown.is_empty() {
+        if unknown_fields == UnknownFieldPolicy::Deny {
+            return Err(Error::UnknownFields { fields: unknown });
+        }
+        warn!("Response contains unknown fields: {}", unknown.join(", "));
+    }
+    Ok(value)
+}
+
+/// Format an ignored field's path like `serde_path_to_error` does, e.g. `items[1].name`
+fn field_path(path: &serde_ignored::Path) -> String {
+    use serde_ignored::Path;
+
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, index } => format!("{}[{}]", field_path(parent), index),
+        Path::Map { parent, key } => match field_path(parent) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{}.{}", parent, key),
+        },
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => field_path(parent),
+    }
+}
+
+/// Byte offset of a 1-based line and column as reported by `serde_json`
+fn byte_offset(body: &[u8], line: usize, column: usize) -> usize {
+    let line_start: usize = body
+        .split_inclusive(|&b| b == b'\n')
+        .take(line.saturating_sub(1))
+        .map(<[u8]>::len)
+        .sum();
+    line_start + column.saturating_sub(1)
+}
+
+/// Raw body of an error response for [`Error::Fault`], `None` if empty or capture is disabled
+pub(crate) fn fault_body(body: &[u8], limit: usize, secret: &str) -> Option<Box<str>> {
+    (limit > 0 && !body.is_empty()).then(|| body_snippet(body, 0, limit, secret).into())
+}
+
+/// HTTP statuses worth retrying
+fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
 }
 
 /// API error response structure
-#[derive(Debug, serde::Deserialize)]
+///
+/// The API sends `{"errors": [{"code": "...", "message": "..."}], "errorMessages": [...]}`;
+/// plain strings in `errors` are accepted as well.
+#[derive(Debug, Default, Serialize, serde::Deserialize)]
 pub struct ApiErrors {
     #[serde(default)]
-    pub errors: Vec<String>,
-    #[serde(default)]
+    pub errors: Vec<ApiError>,
+    #[serde(default, alias = "errorMessages")]
     pub error_messages: Vec<String>,
 }
 
+impl ApiErrors {
+    /// The first human-readable message, falling back to the first error code
+    pub fn first_message(&self) -> Option<&str> {
+        self.errors
+            .iter()
+            .find_map(|error| error.message.as_deref())
+            .or_else(|| self.error_messages.first().map(String::as_str))
+            .or_else(|| self.errors.iter().find_map(|error| error.code.as_deref()))
+    }
+
+    /// All messages, in order; entries of `errors` without a message contribute their code
+    pub fn messages(&self) -> Vec<String> {
+        self.errors
+            .iter()
+            .filter_map(|error| error.message.as_ref().or(error.code.as_ref()))
+            .chain(&self.error_messages)
+            .cloned()
+            .collect()
+    }
+}
+
+/// A single entry of [`ApiErrors::errors`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+#[serde(from = "RawApiError")]
+pub struct ApiError {
+    /// Machine-readable code, e.g. `INTERNAL_ERROR`
+    pub code: Option<String>,
+    /// Human-readable description
+    pub message: Option<String>,
+}
+
+/// Accepted shapes of an API error entry
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum RawApiError {
+    Message(String),
+    Object {
+        #[serde(default)]
+        code: Option<String>,
+        #[serde(default)]
+        message: Option<String>,
+    },
+}
+
+impl From<RawApiError> for ApiError {
+    fn from(raw: RawApiError) -> Self {
+        match raw {
+            RawApiError::Message(message) => ApiError {
+                code: None,
+                message: Some(message),
+            },
+            RawApiError::Object { code, message } => ApiError { code, message },
+        }
+    }
+}
+
 /// Type alias for Result with the crate's Error type
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reqwest_error() -> reqwest::Error {
+        reqwest::Client::new()
+            .get("http://[invalid")
+            .build()
+            .unwrap_err()
+    }
+
+    fn fault(code: StatusCode) -> Error {
+        Error::Fault {
+            code,
+            errors: ApiErrors::default(),
+            request_id: None,
+            request: None,
+            body: None,
+        }
+    }
+
+    fn invalid_request(messages: &[&str]) -> Error {
+        Error::InvalidRequest {
+            messages: messages.iter().map(|m| m.to_string()).collect(),
+            request: None,
+            body: None,
+        }
+    }
+
+    #[test]
+    fn test_error_kind_as_str_matches_serde() {
+        for kind in [
+            ErrorKind::Auth,
+            ErrorKind::NotFound,
+            ErrorKind::RateLimited,
+            ErrorKind::Transient,
+            ErrorKind::Client,
+            ErrorKind::Decode,
+            ErrorKind::Config,
+        ] {
+            assert_eq!(
+                serde_json::to_value(kind).unwrap(),
+                serde_json::Value::from(kind.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_error_kind_every_variant() {
+        let cases = [
+            (Error::Http(reqwest_error()), ErrorKind::Config),
+            (
+                Error::Timeout {
+                    phase: TimeoutPhase::Read,
+                    source: reqwest_error(),
+                },
+                ErrorKind::Transient,
+            ),
+            (Error::Connect(reqwest_error()), ErrorKind::Transient),
+            (
+                Error::IO(std::io::Error::other("disk full")),
+                ErrorKind::Client,
+            ),
+            (
+                Error::Serde(serde_json::from_str::<u8>("x").unwrap_err()),
+                ErrorKind::Decode,
+            ),
+            (
+                Error::Decode {
+                    path: ".".to_string(),
+                    message: "expected value".to_string(),
+                    snippet: "x".to_string(),
+                },
+                ErrorKind::Decode,
+            ),
+            (fault(StatusCode::BAD_REQUEST), ErrorKind::Client),
+            (fault(StatusCode::UNAUTHORIZED), ErrorKind::Auth),
+            (fault(StatusCode::NOT_FOUND), ErrorKind::NotFound),
+            (fault(StatusCode::TOO_MANY_REQUESTS), ErrorKind::RateLimited),
+            (
+                fault(StatusCode::INTERNAL_SERVER_ERROR),
+                ErrorKind::Transient,
+            ),
+            (fault(StatusCode::BAD_GATEWAY), ErrorKind::Transient),
+            (fault(StatusCode::SERVICE_UNAVAILABLE), ErrorKind::Transient),
+            (fault(StatusCode::GATEWAY_TIMEOUT), ErrorKind::Transient),
+            (fault(StatusCode::NOT_IMPLEMENTED), ErrorKind::Client),
+            (invalid_request(&[]), ErrorKind::Client),
+            (
+                Error::Unauthorized {
+                    context: None,
+                    request: None,
+                },
+                ErrorKind::Auth,
+            ),
+            (Error::Forbidden { request: None }, ErrorKind::Auth),
+            (
+                Error::RateLimited {
+                    retry_after: None,
+                    retry_at: None,
+                    request: None,
+                },
+                ErrorKind::RateLimited,
+            ),
+            (
+                Error::NotFound {
+                    resource: ResourceKind::Other,
+                    id: None,
+                    request: None,
+                },
+                ErrorKind::NotFound,
+            ),
+            (Error::MethodNotAllowed { request: None }, ErrorKind::Client),
+            (
+                Error::ParseError(url::Url::parse("nope").unwrap_err()),
+                ErrorKind::Config,
+            ),
+            (
+                Error::ConfigError {
+                    message: "bad".to_string(),
+                },
+                ErrorKind::Config,
+            ),
+            (
+                Error::BuilderError {
+                    message: "bad".to_string(),
+                },
+                ErrorKind::Config,
+            ),
+            (
+                Error::InvalidRefnr {
+                    reason: "bad".to_string(),
+                },
+                ErrorKind::Client,
+            ),
+            (
+                Error::Base64Error(base64::DecodeError::InvalidLength(1)),
+                ErrorKind::Client,
+            ),
+            (
+                Error::Context {
+                    context: "fetching search results".to_string(),
+                    source: Box::new(fault(StatusCode::SERVICE_UNAVAILABLE)),
+                },
+                ErrorKind::Transient,
+            ),
+        ];
+
+        for (error, kind) in cases {
+            assert_eq!(error.kind(), kind, "{:?}", error);
+            assert_eq!(
+                error.is_retryable(),
+                matches!(kind, ErrorKind::Transient | ErrorKind::RateLimited),
+                "{:?}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_status_code_every_variant() {
+        let cases = [
+            (Error::Http(reqwest_error()), None),
+            (
+                Error::Timeout {
+                    phase: TimeoutPhase::Connect,
+                    source: reqwest_error(),
+                },
+                None,
+            ),
+            (Error::Connect(reqwest_error()), None),
+            (Error::IO(std::io::Error::other("disk full")), None),
+            (
+                Error::Serde(serde_json::from_str::<u8>("x").unwrap_err()),
+                None,
+            ),
+            (
+                Error::Decode {
+                    path: ".".to_string(),
+                    message: "expected value".to_string(),
+                    snippet: "x".to_string(),
+                },
+                None,
+            ),
+            (
+                fault(StatusCode::BAD_GATEWAY),
+                Some(StatusCode::BAD_GATEWAY),
+            ),
+            (invalid_request(&[]), Some(StatusCode::BAD_REQUEST)),
+            (
+                Error::Unauthorized {
+                    context: Some("token request failed".to_string()),
+                    request: None,
+                },
+                Some(StatusCode::UNAUTHORIZED),
+            ),
+            (
+                Error::Forbidden { request: None },
+                Some(StatusCode::FORBIDDEN),
+            ),
+            (
+                Error::RateLimited {
+                    retry_after: None,
+                    retry_at: None,
+                    request: None,
+                },
+                Some(StatusCode::TOO_MANY_REQUESTS),
+            ),
+            (
+                Error::NotFound {
+                    resource: ResourceKind::EmployerLogo,
+                    id: None,
+                    request: None,
+                },
+                Some(StatusCode::NOT_FOUND),
+            ),
+            (
+                Error::MethodNotAllowed { request: None },
+                Some(StatusCode::METHOD_NOT_ALLOWED),
+            ),
+            (
+                Error::ParseError(url::Url::parse("nope").unwrap_err()),
+                None,
+            ),
+            (
+                Error::ConfigError {
+                    message: "bad".to_string(),
+                },
+                None,
+            ),
+            (
+                Error::BuilderError {
+                    message: "bad".to_string(),
+                },
+                None,
+            ),
+            (
+                Error::InvalidRefnr {
+                    reason: "bad".to_string(),
+                },
+                None,
+            ),
+            (
+                Error::Base64Error(base64::DecodeError::InvalidLength(1)),
+                None,
+            ),
+            (
+                Error::Context {
+                    context: "fetching details for 10001-TEST-S".to_string(),
+                    source: Box::new(Error::Forbidden { request: None }),
+                },
+                Some(StatusCode::FORBIDDEN),
+            ),
+            (
+                fault(StatusCode::SERVICE_UNAVAILABLE).retries_exhausted(4, Duration::from_secs(7)),
+                Some(StatusCode::SERVICE_UNAVAILABLE),
+            ),
+        ];
+
+        for (error, status) in cases {
+            assert_eq!(error.status_code(), status, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_serialize_status_variants() {
+        use serde_json::json;
+
+        let request = Some(Box::new(RequestContext::new(
+            Endpoint::JobDetails,
+            "https://example.com/pc/v4/jobdetails/MTAwMDEtVEVTVC1T",
+        )));
+        let cases = [
+            (
+                Error::NotFound {
+                    resource: ResourceKind::JobDetails,
+                    id: Some("10001-TEST-S".to_string()),
+                    request: request.clone(),
+                },
+                json!({
+                    "error": "not_found",
+                    "kind": "not_found",
+                    "status": 404,
+                    "resource": "job_details",
+                    "id": "10001-TEST-S",
+                    "endpoint": "jobdetails",
+                    "url": "https://example.com/pc/v4/jobdetails/MTAwMDEtVEVTVC1T",
+                    "message": "Job 10001-TEST-S not found (job may have expired or been removed) \
+                        [jobdetails request to https://example.com/pc/v4/jobdetails/MTAwMDEtVEVTVC1T]",
+                }),
+            ),
+            (
+                Error::RateLimited {
+                    retry_after: Some(Duration::from_secs(60)),
+                    retry_at: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+                    request: None,
+                },
+                json!({
+                    "error": "rate_limited",
+                    "kind": "rate_limited",
+                    "status": 429,
+                    "retry_after": 60,
+                    "retry_at": 1_700_000_000u64,
+                    "message": "Rate limited by API, retry in 1m 0s",
+                }),
+            ),
+            (
+                Error::Fault {
+                    code: StatusCode::SERVICE_UNAVAILABLE,
+                    errors: serde_json::from_str(
+                        r#"{"errors": [{"code": "E1", "message": "down"}]}"#,
+                    )
+                    .unwrap(),
+                    request_id: Some("req-1".to_string()),
+                    request: None,
+                    body: Some("raw".into()),
+                },
+                json!({
+                    "error": "fault",
+                    "kind": "transient",
+                    "status": 503,
+                    "errors": {
+                        "errors": [{"code": "E1", "message": "down"}],
+                        "error_messages": [],
+                    },
+                    "request_id": "req-1",
+                    "body": "raw",
+                    "message": "Jobsuche API error (503 Service Unavailable, request id: req-1): down\nBody: raw",
+                }),
+            ),
+            (
+                Error::Unauthorized {
+                    context: None,
+                    request: None,
+                },
+                json!({
+                    "error": "unauthorized",
+                    "kind": "auth",
+                    "status": 401,
+                    "message": "Could not connect to Jobsuche API: Unauthorized (check your API key)",
+                }),
+            ),
+            (
+                Error::Forbidden { request: None },
+                json!({
+                    "error": "forbidden",
+                    "kind": "auth",
+                    "status": 403,
+                    "message": "Jobsuche API request blocked: Forbidden (possible rate limiting)",
+                }),
+            ),
+            (
+                invalid_request(&["umkreis must be a number"]),
+                json!({
+                    "error": "invalid_request",
+                    "kind": "client",
+                    "status": 400,
+                    "messages": ["umkreis must be a number"],
+                    "message": "Invalid request: umkreis must be a number",
+                }),
+            ),
+            (
+                Error::MethodNotAllowed { request: None },
+                json!({
+                    "error": "method_not_allowed",
+                    "kind": "client",
+                    "status": 405,
+                    "message": "Jobsuche API error: MethodNotAllowed",
+                }),
+            ),
+            (
+                Error::Decode {
+                    path: "stellenangebote[0]".to_string(),
+                    message: "missing field `refnr`".to_string(),
+                    snippet: "{}".to_string(),
+                },
+                json!({
+                    "error": "decode",
+                    "kind": "decode",
+                    "path": "stellenangebote[0]",
+                    "snippet": "{}",
+                    "message": "Could not decode response body at `stellenangebote[0]`: missing field `refnr`\nBody: {}",
+                }),
+            ),
+            (
+                Error::InvalidRefnr {
+                    reason: "empty".to_string(),
+                },
+                json!({
+                    "error": "invalid_refnr",
+                    "kind": "client",
+                    "message": "Invalid reference number: empty",
+                }),
+            ),
+            (
+                Error::ConfigError {
+                    message: "bad".to_string(),
+                },
+                json!({
+                    "error": "config_error",
+                    "kind": "config",
+                    "message": "Configuration error: bad",
+                }),
+            ),
+            (
+                Error::BuilderError {
+                    message: "bad".to_string(),
+                },
+                json!({
+                    "error": "builder_error",
+                    "kind": "config",
+                    "message": "Builder validation failed: bad",
+                }),
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(serde_json::to_value(&error).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_serialize_flattens_sources() {
+        let cases = [
+            (Error::Http(reqwest_error()), "http"),
+            (
+                Error::Timeout {
+                    phase: TimeoutPhase::Read,
+                    source: reqwest_error(),
+                },
+                "timeout",
+            ),
+            (Error::Connect(reqwest_error()), "connect"),
+            (Error::IO(std::io::Error::other("disk full")), "io"),
+            (
+                Error::Serde(serde_json::from_str::<u8>("x").unwrap_err()),
+                "serde",
+            ),
+            (
+                Error::ParseError(url::Url::parse("nope").unwrap_err()),
+                "parse_error",
+            ),
+            (
+                Error::Base64Error(base64::DecodeError::InvalidLength(1)),
+                "base64_error",
+            ),
+        ];
+
+        for (error, name) in cases {
+            let json = serde_json::to_value(&error).unwrap();
+            assert_eq!(json["error"], name);
+            assert_eq!(json["message"], error.to_string());
+            assert!(json.get("source").is_none());
+        }
+
+        let json = serde_json::to_value(Error::Timeout {
+            phase: TimeoutPhase::Connect,
+            source: reqwest_error(),
+        })
+        .unwrap();
+        assert_eq!(json["phase"], "connect");
+        assert_eq!(json["kind"], "transient");
+    }
+
+    #[test]
+    fn test_context_wraps_and_delegates() {
+        let not_found: Result<()> = Err(Error::NotFound {
+            resource: ResourceKind::JobDetails,
+            id: Some("10001-TEST-S".to_string()),
+            request: None,
+        });
+        let error = not_found
+            .context_refnr("10001-TEST-S")
+            .context_op("fetching search results")
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "fetching search results: fetching details for 10001-TEST-S: \
+             Job 10001-TEST-S not found (job may have expired or been removed)"
+        );
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert_eq!(error.status_code(), Some(StatusCode::NOT_FOUND));
+        assert!(error.is_not_found());
+        assert!(!error.is_retryable());
+        assert!(matches!(error.root(), Error::NotFound { .. }));
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source
+            .to_string()
+            .starts_with("fetching details for 10001-TEST-S: "));
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["error"], "not_found");
+        assert_eq!(json["id"], "10001-TEST-S");
+        assert_eq!(
+            json["context"],
+            serde_json::json!([
+                "fetching search results",
+                "fetching details for 10001-TEST-S"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_retries_exhausted_wraps_and_is_not_retryable() {
+        let error = fault(StatusCode::SERVICE_UNAVAILABLE).retries_exhausted(1, Duration::ZERO);
+        assert!(matches!(error, Error::Fault { .. }));
+
+        let error = error.retries_exhausted(4, Duration::from_secs(90));
+        assert_eq!(
+            error.to_string(),
+            "Giving up after 4 attempts in 1m 30s: Jobsuche API error \
+             (503 Service Unavailable, request id: none): no error details"
+        );
+        assert_eq!(error.kind(), ErrorKind::Transient);
+        assert!(!error.is_retryable());
+        assert!(matches!(error.root(), Error::Fault { .. }));
+
+        let wrapped: Result<()> = Err(error);
+        let error = wrapped.context_op("fetching search results").unwrap_err();
+        assert!(!error.is_retryable());
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["error"], "fault");
+        assert_eq!(json["status"], 503);
+        assert_eq!(json["attempts"], 4);
+        assert_eq!(json["elapsed_ms"], 90_000);
+        assert_eq!(
+            json["context"],
+            serde_json::json!(["fetching search results"])
+        );
+    }
+
+    #[test]
+    fn test_request_context_display() {
+        let request = RequestContext::new(
+            Endpoint::JobDetails,
+            "https://example.com/pc/v4/jobdetails/MTAwMDEtVEVTVC1T",
+        );
+        let error = Error::NotFound {
+            resource: ResourceKind::JobDetails,
+            id: None,
+            request: Some(Box::new(request.clone())),
+        };
+        assert_eq!(error.request(), Some(&request));
+        assert!(error.to_string().ends_with(
+            " [jobdetails request to https://example.com/pc/v4/jobdetails/MTAwMDEtVEVTVC1T]"
+        ));
+
+        let error = Error::NotFound {
+            resource: ResourceKind::JobDetails,
+            id: None,
+            request: None,
+        };
+        assert_eq!(error.request(), None);
+        assert!(!error.to_string().contains('['));
+    }
+
+    #[test]
+    fn test_not_found_display_per_resource() {
+        let not_found = |resource, id: Option<&str>| Error::NotFound {
+            resource,
+            id: id.map(String::from),
+            request: None,
+        };
+
+        let error = not_found(ResourceKind::JobDetails, Some("10001-TEST-S"));
+        assert!(error.is_not_found());
+        assert_eq!(
+            error.to_string(),
+            "Job 10001-TEST-S not found (job may have expired or been removed)"
+        );
+        assert_eq!(
+            not_found(ResourceKind::EmployerLogo, Some("abc")).to_string(),
+            "No logo found for employer abc"
+        );
+        assert!(not_found(ResourceKind::Search, None)
+            .to_string()
+            .contains("check the host URL"));
+        assert_eq!(
+            not_found(ResourceKind::Other, None).to_string(),
+            "Resource not found"
+        );
+        assert!(!Error::Forbidden { request: None }.is_not_found());
+    }
+
+    #[test]
+    fn test_with_id_only_touches_not_found() {
+        let error = Error::NotFound {
+            resource: ResourceKind::JobDetails,
+            id: None,
+            request: None,
+        }
+        .with_id("10001-TEST-S");
+        assert!(matches!(error, Error::NotFound { id: Some(ref id), .. } if id == "10001-TEST-S"));
+
+        let error = Error::Forbidden { request: None }.with_id("10001-TEST-S");
+        assert!(error
+            .to_string()
+            .starts_with("Jobsuche API request blocked"));
+    }
+
+    #[test]
+    fn test_body_snippet_truncates_and_redacts() {
+        assert_eq!(body_snippet(b"short", 0, 4096, ""), "short");
+        assert_eq!(body_snippet(b"0123456789", 0, 4, ""), "0123…");
+        assert_eq!(body_snippet(b"0123456789", 6, 4, ""), "…4567…");
+        assert_eq!(body_snippet(b"0123456789", 10, 4, ""), "…89");
+        assert_eq!(
+            body_snippet(b"key=my-key was rejected", 0, 4096, "my-key"),
+            "key=[redacted] was rejected"
+        );
+        // Cutting through a multi-byte character must not panic
+        assert_eq!(body_snippet("äöü".as_bytes(), 0, 3, ""), "ä\u{fffd}…");
+    }
+
+    #[test]
+    fn test_fault_body() {
+        assert_eq!(fault_body(b"", 4096, ""), None);
+        assert_eq!(fault_body(b"<html>", 0, ""), None);
+        assert_eq!(fault_body(b"<html>", 4096, "").as_deref(), Some("<html>"));
+    }
+
+    #[test]
+    fn test_decode_json_points_at_error() {
+        let body = b"{\n  \"a\": 1,\n  \"b\": oops\n}";
+        let error =
+            decode_json::<serde_json::Value>(body, 8, UnknownFieldPolicy::Ignore, "").unwrap_err();
+        match error {
+            Error::Decode {
+                path,
+                message,
+                snippet,
+            } => {
+                assert_eq!(path, "b");
+                assert!(message.contains("line 3"), "{}", message);
+                assert!(snippet.contains("oo"), "{}", snippet);
+                assert!(!snippet.contains("\"a\""), "{}", snippet);
+            }
+            other => panic!("expected Decode, got {:?}", other),
+        }
+
+        let value: u8 = decode_json(b"7", 4096, UnknownFieldPolicy::Ignore, "").unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn test_decode_json_reports_field_path() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Listing {
+            #[allow(dead_code)]
+            refnr: String,
+        }
+        #[derive(Debug, serde::Deserialize)]
+        struct Response {
+            #[allow(dead_code)]
+            items: Vec<Listing>,
+        }
+
+        let body = br#"{"items": [{"refnr": "a"}, {"refnr": 5}]}"#;
+        match decode_json::<Response>(body, 4096, UnknownFieldPolicy::Ignore, "").unwrap_err() {
+            Error::Decode { path, message, .. } => {
+                assert_eq!(path, "items[1].refnr");
+                assert!(message.contains("invalid type"), "{}", message);
+            }
+            other => panic!("expected Decode, got {:?}", other),
+        }
+
+        let error = decode_json::<u8>(b"7 8", 4096, UnknownFieldPolicy::Ignore, "").unwrap_err();
+        assert!(matches!(error, Error::Decode { ref path, .. } if path == "."));
+    }
+
+    const LISTINGS_WITH_EXTRA_FIELDS: &[u8] = br#"{
+        "stellenangebote": [
+            {"refnr": "10001-A-S", "arbeitsort": {"ort": "Berlin", "stockwerk": "3"}},
+            {"refnr": "10001-B-S", "arbeitsort": {}, "tarifvertrag": "TVoeD"}
+        ],
+        "maxErgebnisse": 2,
+        "traceId": "abc"
+    }"#;
+
+    #[test]
+    fn test_decode_json_unknown_fields_ignored() {
+        let response: crate::JobSearchResponse = decode_json(
+            LISTINGS_WITH_EXTRA_FIELDS,
+            4096,
+            UnknownFieldPolicy::Ignore,
+            "",
+        )
+        .unwrap();
+        assert_eq!(response.stellenangebote.len(), 2);
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn test_decode_json_unknown_fields_warn() {
+        let response: crate::JobSearchResponse = decode_json(
+            LISTINGS_WITH_EXTRA_FIELDS,
+            4096,
+            UnknownFieldPolicy::Warn,
+            "",
+        )
+        .unwrap();
+        assert_eq!(response.stellenangebote.len(), 2);
+        assert!(logs_contain("unknown fields"));
+        assert!(logs_contain("stellenangebote[0].arbeitsort.stockwerk"));
+        assert!(logs_contain("stellenangebote[1].tarifvertrag"));
+        assert!(logs_contain("traceId"));
+    }
+
+    #[test]
+    fn test_decode_json_unknown_fields_deny() {
+        let error = decode_json::<crate::JobSearchResponse>(
+            LISTINGS_WITH_EXTRA_FIELDS,
+            4096,
+            UnknownFieldPolicy::Deny,
+            "",
+        )
+        .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Decode);
+        assert!(!error.is_retryable());
+        match &error {
+            Error::UnknownFields { fields } => assert_eq!(
+                fields,
+                &[
+                    "stellenangebote[0].arbeitsort.stockwerk",
+                    "stellenangebote[1].tarifvertrag",
+                    "traceId",
+                ]
+            ),
+            other => panic!("expected UnknownFields, got {:?}", other),
+        }
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["error"], "unknown_fields");
+        assert_eq!(json["fields"][2], "traceId");
+
+        // Known fields only, including unknown facet groups kept in `Facetten::other`
+        let body = include_bytes!("../tests/fixtures/search-facets.json");
+        let response: crate::JobSearchResponse =
+            decode_json(body, 4096, UnknownFieldPolicy::Deny, "").unwrap();
+        assert_eq!(response.stellenangebote.len(), 1);
+    }
+
+    #[test]
+    fn test_rate_limited_display() {
+        let rate_limited = |retry_after: Option<u64>| Error::RateLimited {
+            retry_after: retry_after.map(Duration::from_secs),
+            retry_at: None,
+            request: None,
+        };
+        assert_eq!(
+            rate_limited(Some(45)).to_string(),
+            "Rate limited by API, retry in 45s"
+        );
+        assert_eq!(
+            rate_limited(Some(125)).to_string(),
+            "Rate limited by API, retry in 2m 5s"
+        );
+        assert_eq!(
+            rate_limited(Some(5400)).to_string(),
+            "Rate limited by API, retry in 1h 30m"
+        );
+        assert_eq!(
+            rate_limited(None).to_string(),
+            "Rate limited by API, no retry time given"
+        );
+    }
+
+    #[test]
+    fn test_api_errors_object_form() {
+        let errors: ApiErrors = serde_json::from_str(
+            r#"{
+                "errors": [{"code": "INTERNAL_ERROR", "message": "Internal server error"}],
+                "errorMessages": ["Something went wrong"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            errors.errors,
+            vec![ApiError {
+                code: Some("INTERNAL_ERROR".to_string()),
+                message: Some("Internal server error".to_string()),
+            }]
+        );
+        assert_eq!(errors.error_messages, vec!["Something went wrong"]);
+        assert_eq!(errors.first_message(), Some("Internal server error"));
+    }
+
+    #[test]
+    fn test_api_errors_legacy_string_form() {
+        let errors: ApiErrors =
+            serde_json::from_str(r#"{"errors": ["invalid parameter umkreis"]}"#).unwrap();
+        assert_eq!(errors.errors[0].code, None);
+        assert_eq!(errors.first_message(), Some("invalid parameter umkreis"));
+    }
+
+    #[test]
+    fn test_api_errors_first_message_fallbacks() {
+        assert_eq!(ApiErrors::default().first_message(), None);
+
+        let errors: ApiErrors =
+            serde_json::from_str(r#"{"errors": [{"code": "E42"}], "error_messages": ["text"]}"#)
+                .unwrap();
+        assert_eq!(errors.first_message(), Some("text"));
+
+        let errors: ApiErrors = serde_json::from_str(r#"{"errors": [{"code": "E42"}]}"#).unwrap();
+        assert_eq!(errors.first_message(), Some("E42"));
+    }
+
+    #[test]
+    fn test_api_errors_messages() {
+        assert!(ApiErrors::default().messages().is_empty());
+
+        let errors: ApiErrors = serde_json::from_str(
+            r#"{
+                "errors": [{"code": "E42"}, {"code": "E43", "message": "umkreis is invalid"}, {}],
+                "errorMessages": ["angebotsart is invalid"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            errors.messages(),
+            vec!["E42", "umkreis is invalid", "angebotsart is invalid"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_request_display() {
+        assert_eq!(
+            invalid_request(&[]).to_string(),
+            "Invalid request: no validation details"
+        );
+        assert_eq!(
+            invalid_request(&["umkreis is invalid", "size too large"]).to_string(),
+            "Invalid request: umkreis is invalid; size too large"
+        );
+    }
+
+    #[test]
+    fn test_fault_display_uses_first_message() {
+        let mut error = fault(StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(
+            error.to_string(),
+            "Jobsuche API error (500 Internal Server Error, request id: none): no error details"
+        );
+
+        if let Error::Fault { errors, .. } = &mut error {
+            errors.errors.push(ApiError {
+                code: Some("INTERNAL_ERROR".to_string()),
+                message: Some("Datenbank nicht erreichbar".to_string()),
+            });
+        }
+        assert!(error
+            .to_string()
+            .ends_with("request id: none): Datenbank nicht erreichbar"));
+    }
+
+    #[test]
+    fn test_timeout_phase_display() {
+        assert_eq!(TimeoutPhase::Connect.to_string(), "connecting");
+        assert_eq!(TimeoutPhase::Read.to_string(), "reading the response");
+    }
+}
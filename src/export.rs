@@ -0,0 +1,359 @@
+//! CSV export of search results and job details (requires the `csv` feature)
+//!
+//! Columns are written in a fixed order, listed in [`LISTING_COLUMNS`] and
+//! [`DETAILS_COLUMNS`]; missing values are written as empty cells. Fields are quoted only
+//! where needed (delimiters, quotes, line breaks).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use jobsuche::export::{write_csv_with, CsvOptions};
+//! use jobsuche::{Jobsuche, SearchOptions};
+//!
+//! let client = Jobsuche::default_client().unwrap();
+//! let page = client.search().list(SearchOptions::builder().was("Koch").build()).unwrap();
+//!
+//! let file = std::fs::File::create("jobs.csv").unwrap();
+//! write_csv_with(file, &page.stellenangebote, &CsvOptions::excel()).unwrap();
+//! ```
+
+use std::io::{self, Write};
+
+use crate::{JobDetails, JobListing, LocationAddress, Result, Skill};
+
+/// Column order of [`write_csv`]
+pub const LISTING_COLUMNS: [&str; 12] = [
+    "refnr",
+    "beruf",
+    "titel",
+    "arbeitgeber",
+    "ort",
+    "plz",
+    "region",
+    "land",
+    "published",
+    "start_date",
+    "external_url",
+    "entfernung",
+];
+
+/// Column order of [`write_details_csv`]
+///
+/// A job can have several locations; their `ort`, `plz`, `region` and `land` values are
+/// joined with `"; "` in the same order, with an empty value where a location lacks the
+/// field. `arbeitszeit` lists the German names of [`JobDetails::arbeitszeit_modelle`].
+/// `fertigkeiten` lists each skill's hierarchy name followed by its values in parentheses,
+/// e.g. `Softwareentwicklung (Rust, Java); Teamfähigkeit`.
+pub const DETAILS_COLUMNS: [&str; 16] = [
+    "refnr",
+    "titel",
+    "arbeitgeber",
+    "stellenangebotsart",
+    "ort",
+    "plz",
+    "region",
+    "land",
+    "arbeitszeit",
+    "vertragsdauer",
+    "verguetung",
+    "published",
+    "start_date",
+    "external_url",
+    "fertigkeiten",
+    "beschreibung",
+];
+
+/// Formatting options for the CSV writers
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// Field delimiter (default: `,`)
+    pub delimiter: u8,
+    /// Start the output with a UTF-8 byte order mark (default: false)
+    ///
+    /// Excel needs it to read umlauts correctly.
+    pub utf8_bom: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            utf8_bom: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Options for Excel with German regional settings: `;` as delimiter and a byte order mark
+    pub fn excel() -> Self {
+        Self {
+            delimiter: b';',
+            utf8_bom: true,
+        }
+    }
+}
+
+/// Write search results as CSV with a header row, see [`LISTING_COLUMNS`]
+pub fn write_csv<'a, W: Write>(
+    writer: W,
+    listings: impl IntoIterator<Item = &'a JobListing>,
+) -> Result<()> {
+    write_csv_with(writer, listings, &CsvOptions::default())
+}
+
+/// Like [`write_csv`], with formatting options
+pub fn write_csv_with<'a, W: Write>(
+    writer: W,
+    listings: impl IntoIterator<Item = &'a JobListing>,
+    options: &CsvOptions,
+) -> Result<()> {
+    write_rows(
+        writer,
+        options,
+        &LISTING_COLUMNS,
+        listings.into_iter().map(|job| {
+            let location = &job.arbeitsort;
+            vec![
+                job.refnr.to_string(),
+                cell(&job.beruf),
+                cell(&job.titel),
+                cell(&job.arbeitgeber),
+                cell(&location.ort),
+                cell(&location.plz),
+                cell(&location.region),
+                cell(&location.land),
+                cell(&job.aktuelle_veroeffentlichungsdatum),
+                cell(&job.eintrittsdatum),
+                cell(&job.externe_url),
+                cell(&location.entfernung),
+            ]
+        }),
+    )
+}
+
+/// Write job details as CSV with a header row, see [`DETAILS_COLUMNS`]
+pub fn write_details_csv<'a, W: Write>(
+    writer: W,
+    details: impl IntoIterator<Item = &'a JobDetails>,
+) -> Result<()> {
+    write_details_csv_with(writer, details, &CsvOptions::default())
+}
+
+/// Like [`write_details_csv`], with formatting options
+pub fn write_details_csv_with<'a, W: Write>(
+    writer: W,
+    details: impl IntoIterator<Item = &'a JobDetails>,
+    options: &CsvOptions,
+) -> Result<()> {
+    write_rows(
+        writer,
+        options,
+        &DETAILS_COLUMNS,
+        details.into_iter().map(|job| {
+            let addresses: Vec<_> = job
+                .arbeitsorte
+                .iter()
+                .map(|location| location.adresse.as_ref())
+                .collect();
+            let arbeitszeit: Vec<_> = job
+                .arbeitszeit_modelle()
+                .iter()
+                .map(|model| model.german_name())
+                .collect();
+            vec![
+                cell(&job.refnr),
+                cell(&job.titel),
+                cell(&job.arbeitgeber),
+                cell(&job.stellenangebots_art),
+                joined(&addresses, |address| address.ort.as_deref()),
+                joined(&addresses, |address| address.plz.as_deref()),
+                joined(&addresses, |address| address.region.as_deref()),
+                joined(&addresses, |address| address.land.as_deref()),
+                arbeitszeit.join("; "),
+                cell(&job.vertragsdauer),
+                cell(&job.verguetung),
+                cell(&job.erste_veroeffentlichungsdatum),
+                job.eintrittszeitraum
+                    .as_ref()
+                    .map(|range| cell(&range.von))
+                    .unwrap_or_default(),
+                cell(&job.externe_url),
+                job.fertigkeiten
+                    .iter()
+                    .map(skill_cell)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                cell(&job.stellenbeschreibung),
+            ]
+        }),
+    )
+}
+
+fn cell(value: &Option<String>) -> String {
+    value.clone().unwrap_or_default()
+}
+
+/// One field of every address, joined with `"; "`
+///
+/// Missing values are written as empty strings so the n-th value of every location column
+/// belongs to the same location.
+fn joined(
+    addresses: &[Option<&LocationAddress>],
+    field: impl Fn(&LocationAddress) -> Option<&str>,
+) -> String {
+    addresses
+        .iter()
+        .map(|address| address.and_then(&field).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// `Name (value, value)`, or just the name for a skill without values
+fn skill_cell(skill: &Skill) -> String {
+    let values: Vec<_> = skill.values().map(|(_, value)| value).collect();
+    if values.is_empty() {
+        skill.hierarchie_name.clone()
+    } else {
+        format!("{} ({})", skill.hierarchie_name, values.join(", "))
+    }
+}
+
+fn write_rows<W: Write>(
+    mut writer: W,
+    options: &CsvOptions,
+    header: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) -> Result<()> {
+    if options.utf8_bom {
+        writer.write_all("\u{feff}".as_bytes())?;
+    }
+    let mut out = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .from_writer(writer);
+    out.write_record(header).map_err(io::Error::from)?;
+    for row in rows {
+        out.write_record(&row).map_err(io::Error::from)?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorkLocation;
+
+    fn listings() -> Vec<JobListing> {
+        vec![
+            JobListing::new(
+                "10000-1197745438-S",
+                WorkLocation::default()
+                    .with_ort("Köln")
+                    .with_plz("50667")
+                    .with_region("Nordrhein-Westfalen")
+                    .with_land("Deutschland"),
+            )
+            .with_beruf("Koch/Köchin")
+            .with_titel("Koch (m/w/d), Vollzeit")
+            .with_arbeitgeber("Brauhaus \"Zur Glocke\" GmbH")
+            .with_veroeffentlichungsdatum("2026-03-24"),
+            JobListing::new("10001-EXT-S", WorkLocation::default())
+                .with_externe_url("https://jobs.example.de/4711"),
+        ]
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &listings()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "refnr,beruf,titel,arbeitgeber,ort,plz,region,land,published,start_date,external_url,entfernung\n\
+             10000-1197745438-S,Koch/Köchin,\"Koch (m/w/d), Vollzeit\",\"Brauhaus \"\"Zur Glocke\"\" GmbH\",Köln,50667,Nordrhein-Westfalen,Deutschland,2026-03-24,,,\n\
+             10001-EXT-S,,,,,,,,,,https://jobs.example.de/4711,\n"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_excel() {
+        let mut out = Vec::new();
+        write_csv_with(&mut out, &listings()[..1], &CsvOptions::excel()).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("\u{feff}refnr;beruf;titel;"), "{}", text);
+        // The comma no longer needs quoting, the embedded quotes still do
+        assert!(text.contains(";Koch (m/w/d), Vollzeit;\"Brauhaus \"\"Zur Glocke\"\" GmbH\";"));
+    }
+
+    #[test]
+    fn test_write_csv_empty() {
+        let mut out = Vec::new();
+        write_csv(&mut out, std::iter::empty()).unwrap();
+        assert_eq!(out, format!("{}\n", LISTING_COLUMNS.join(",")).as_bytes());
+    }
+
+    #[test]
+    fn test_write_details_csv() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{
+                "referenznummer": "10001-1001601666-S",
+                "stellenangebotsTitel": "Lagerhelfer; Früh- und Spätschicht",
+                "firma": "Logistik Müller",
+                "stellenangebotsart": "ARBEIT",
+                "stellenlokationen": [
+                    {"adresse": {"ort": "Hamburg", "plz": "20095", "land": "Deutschland"}},
+                    {"adresse": {"ort": "Lübeck", "plz": "23552", "land": "Deutschland"}}
+                ],
+                "arbeitszeitVollzeit": true,
+                "arbeitszeitSchichtNachtWochenende": true,
+                "vertragsdauer": "unbefristet",
+                "eintrittszeitraum": {"von": "2026-04-01"},
+                "datumErsteVeroeffentlichung": "2026-03-15",
+                "fertigkeiten": [
+                    {"hierarchieName": "Lagerlogistik", "auspraegungen": {"Kenntnisse": ["Staplerschein", "SAP"]}},
+                    {"hierarchieName": "Teamfähigkeit"}
+                ],
+                "stellenangebotsBeschreibung": "Zeile 1\nZeile 2"
+            }"#,
+        )
+        .unwrap();
+
+        let mut out = Vec::new();
+        write_details_csv(&mut out, [&details]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "refnr,titel,arbeitgeber,stellenangebotsart,ort,plz,region,land,arbeitszeit,vertragsdauer,verguetung,published,start_date,external_url,fertigkeiten,beschreibung\n\
+             10001-1001601666-S,Lagerhelfer; Früh- und Spätschicht,Logistik Müller,ARBEIT,Hamburg; Lübeck,20095; 23552,; ,Deutschland; Deutschland,Vollzeit; Schicht/Nachtarbeit/Wochenende,unbefristet,,2026-03-15,2026-04-01,,\"Lagerlogistik (Staplerschein, SAP); Teamfähigkeit\",\"Zeile 1\nZeile 2\"\n"
+        );
+    }
+
+    #[test]
+    fn test_write_details_csv_keeps_location_columns_aligned() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{
+                "referenznummer": "10001-1001601667-S",
+                "stellenlokationen": [
+                    {"adresse": {"ort": "Berlin", "land": "Deutschland"}},
+                    {"adresse": {"ort": "Potsdam", "plz": "14467", "land": "Deutschland"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut out = Vec::new();
+        write_details_csv(&mut out, [&details]).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let row = text.lines().nth(1).unwrap();
+        // The first location has no plz, so Potsdam's plz stays in the second slot
+        assert!(
+            row.starts_with(
+                "10001-1001601667-S,,,,Berlin; Potsdam,; 14467,; ,Deutschland; Deutschland,"
+            ),
+            "{}",
+            row
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Request/response hooks for inspecting HTTP traffic
+//!
+//! Hooks are configured on [`ClientConfig`] and are invoked by both the
+//! sync and async clients. They are primarily intended for debugging API drift: the exact
+//! request URL and (optionally) the raw response body can be captured without patching the crate.
+//!
+//! The `X-API-Key` and `Authorization` header values are always redacted before they are
+//! handed to a hook.
+//!
+//! Hooks run for every attempt, including retries and employer logo downloads. A panicking
+//! hook is logged and otherwise ignored, so it cannot fail a request.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+use reqwest::{Method, StatusCode};
+use tracing::warn;
+
+use crate::sync::ClientConfig;
+
+/// Name of the header carrying the API key
+pub(crate) const API_KEY_HEADER: &str = "X-API-Key";
+
+/// Placeholder used instead of secret header values
+const REDACTED: &str = "[REDACTED]";
+
+/// Callback invoked before a request is sent
+pub type RequestHook = Arc<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// Callback invoked after a response has been received
+pub type ResponseHook = Arc<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Callback receiving a [`RequestMetric`] for every request attempt
+pub type MetricsHook = Arc<dyn Fn(&RequestMetric) + Send + Sync>;
+
+/// API endpoint a request was sent to
+///
+/// Used as a low-cardinality label for metrics: reference numbers and logo hashes are
+/// never part of the template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Endpoint {
+    /// Job search (`/pc/v4/jobs`)
+    Jobs,
+    /// Job details (`/pc/v4/jobdetails/{refnr}`)
+    JobDetails,
+    /// Employer logo (`/ed/v1/arbeitgeberlogo/{hash_id}`)
+    EmployerLogo,
+}
+
+impl Endpoint {
+    /// Short name of the endpoint (`jobs`, `jobdetails` or `logo`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Endpoint::Jobs => "jobs",
+            Endpoint::JobDetails => "jobdetails",
+            Endpoint::EmployerLogo => "logo",
+        }
+    }
+
+    /// Path template of the endpoint, with placeholders instead of identifiers
+    pub fn template(&self) -> &'static str {
+        match self {
+            Endpoint::Jobs => "/pc/v4/jobs",
+            Endpoint::JobDetails => "/pc/v4/jobdetails/{refnr}",
+            Endpoint::EmployerLogo => "/ed/v1/arbeitgeberlogo/{hash_id}",
+        }
+    }
+}
+
+/// Information about an outgoing request
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    /// HTTP method
+    pub method: Method,
+    /// Endpoint the request is sent to
+    pub endpoint: Endpoint,
+    /// Full request URL including the query string
+    ///
+    /// The API key is sent in a header, never in the URL.
+    pub url: String,
+    /// Number of the attempt, starting at 1 and counting retries
+    pub attempt: u32,
+    /// Request headers as (name, value) pairs, with the API key and bearer token redacted
+    pub headers: Vec<(String, String)>,
+}
+
+/// Information about a received response
+#[derive(Debug, Clone)]
+pub struct ResponseInfo {
+    /// HTTP method of the originating request
+    pub method: Method,
+    /// Endpoint the request was sent to
+    pub endpoint: Endpoint,
+    /// Full request URL including the query string
+    pub url: String,
+    /// Number of the attempt, starting at 1 and counting retries
+    pub attempt: u32,
+    /// Response status code
+    pub status: StatusCode,
+    /// Time between sending the request and receiving the response headers
+    pub duration: Duration,
+    /// Size of the response body: the bytes read if the client buffered the body (successful
+    /// responses), otherwise the `Content-Length` header, if any (error responses, streamed
+    /// logos)
+    pub bytes: Option<u64>,
+    /// Response body, truncated to [`ClientConfig::capture_response_body`] bytes
+    ///
+    /// Only populated for successful responses when body capture is enabled.
+    pub body: Option<String>,
+}
+
+/// Latency and outcome of a single request attempt
+#[derive(Debug, Clone)]
+pub struct RequestMetric {
+    /// HTTP method
+    pub method: Method,
+    /// Endpoint the request was sent to
+    pub endpoint: Endpoint,
+    /// Response status, or `None` if no response was received (connection error, timeout)
+    pub status: Option<StatusCode>,
+    /// Time between sending the request and receiving the response headers (or failing)
+    pub duration: Duration,
+}
+
+/// Convert a header map into (name, value) pairs with secret values redacted
+pub(crate) fn redacted_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value =
+                if name.as_str().eq_ignore_ascii_case(API_KEY_HEADER) || name == AUTHORIZATION {
+                    REDACTED.to_string()
+                } else {
+                    value.to_str().unwrap_or("<non-ascii>").to_string()
+                };
+            (name.as_str().to_string(), value)
+        })
+        .collect()
+}
+
+/// The request attempt a hook call belongs to
+pub(crate) struct Attempt<'a> {
+    pub(crate) method: &'a Method,
+    pub(crate) endpoint: Endpoint,
+    pub(crate) url: &'a str,
+    pub(crate) number: u32,
+}
+
+/// Invoke the request hook, if configured
+pub(crate) fn notify_request(config: &ClientConfig, attempt: &Attempt<'_>, headers: &HeaderMap) {
+    if let Some(hook) = &config.on_request {
+        let info = RequestInfo {
+            method: attempt.method.clone(),
+            endpoint: attempt.endpoint,
+            url: attempt.url.to_string(),
+            attempt: attempt.number,
+            headers: redacted_headers(headers),
+        };
+        isolate("on_request", || hook(&info));
+    }
+}
+
+/// Invoke the response hook, if configured
+///
+/// `body` is the buffered body of a successful response, `bytes` the body size otherwise.
+pub(crate) fn notify_response(
+    config: &ClientConfig,
+    attempt: &Attempt<'_>,
+    status: StatusCode,
+    duration: Duration,
+    body: Option<&[u8]>,
+    bytes: Option<u64>,
+) {
+    if let Some(hook) = &config.on_response {
+        let info = ResponseInfo {
+            method: attempt.method.clone(),
+            endpoint: attempt.endpoint,
+            url: attempt.url.to_string(),
+            attempt: attempt.number,
+            status,
+            duration,
+            bytes: body.map(|body| body.len() as u64).or(bytes),
+            body: body
+                .zip(config.capture_response_body)
+                .map(|(bytes, limit)| {
+                    String::from_utf8_lossy(&bytes[..bytes.len().min(limit)]).into_owned()
+                }),
+        };
+        isolate("on_response", || hook(&info));
+    }
+}
+
+/// Invoke the metrics hook, if configured
+pub(crate) fn notify_metric(
+    config: &ClientConfig,
+    method: &Method,
+    endpoint: Endpoint,
+    status: Option<StatusCode>,
+    duration: Duration,
+) {
+    if let Some(hook) = &config.on_metric {
+        let metric = RequestMetric {
+            method: method.clone(),
+            endpoint,
+            status,
+            duration,
+        };
+        isolate("on_metric", || hook(&metric));
+    }
+}
+
+/// Run a user hook, logging instead of propagating a panic
+fn isolate(name: &str, hook: impl FnOnce()) {
+    if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(hook)) {
+        warn!("{} hook panicked: {}", name, panic_message(&*panic));
+    }
+}
+
+/// The message of a panic payload, if it is a string
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("<non-string payload>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, ACCEPT};
+
+    #[test]
+    fn test_redacted_headers_hides_api_key() {
+        let mut headers = HeaderMap::new();
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("secret-key"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        let redacted = redacted_headers(&headers);
+        assert!(redacted
+            .iter()
+            .any(|(name, value)| name == "x-api-key" && value == REDACTED));
+        assert!(redacted
+            .iter()
+            .any(|(name, value)| name == "accept" && value == "application/json"));
+        assert!(!format!("{:?}", redacted).contains("secret-key"));
+    }
+
+    #[test]
+    fn test_notify_response_truncates_body() {
+        use std::sync::Mutex;
+
+        let captured = Arc::new(Mutex::new(None));
+        let sink = captured.clone();
+        let config = ClientConfig {
+            on_response: Some(Arc::new(move |info: &ResponseInfo| {
+                *sink.lock().unwrap() = info.body.clone();
+            })),
+            capture_response_body: Some(4),
+            ..Default::default()
+        };
+
+        notify_response(
+            &config,
+            &attempt(),
+            StatusCode::OK,
+            Duration::from_millis(1),
+            Some(&b"abcdefgh"[..]),
+            None,
+        );
+
+        assert_eq!(captured.lock().unwrap().as_deref(), Some("abcd"));
+    }
+
+    #[test]
+    fn test_notify_response_reports_body_size() {
+        use std::sync::Mutex;
+
+        let sizes = Arc::new(Mutex::new(Vec::new()));
+        let sink = sizes.clone();
+        let config = ClientConfig {
+            on_response: Some(Arc::new(move |info: &ResponseInfo| {
+                sink.lock().unwrap().push((info.bytes, info.body.is_some()));
+            })),
+            ..Default::default()
+        };
+
+        let ms = Duration::from_millis(1);
+        notify_response(&config, &attempt(), StatusCode::OK, ms, Some(b"abc"), None);
+        notify_response(
+            &config,
+            &attempt(),
+            StatusCode::NOT_FOUND,
+            ms,
+            None,
+            Some(7),
+        );
+        notify_response(&config, &attempt(), StatusCode::NOT_FOUND, ms, None, None);
+
+        assert_eq!(
+            *sizes.lock().unwrap(),
+            [(Some(3), false), (Some(7), false), (None, false)]
+        );
+    }
+
+    #[test]
+    fn test_panicking_hooks_are_isolated() {
+        let config = ClientConfig {
+            on_request: Some(Arc::new(|_: &RequestInfo| panic!("request hook failed"))),
+            on_response: Some(Arc::new(|info: &ResponseInfo| {
+                panic!("response hook failed for {}", info.url)
+            })),
+            on_metric: Some(Arc::new(|_: &RequestMetric| std::panic::panic_any(42))),
+            ..Default::default()
+        };
+
+        let ms = Duration::from_millis(1);
+        notify_request(&config, &attempt(), &HeaderMap::new());
+        notify_response(&config, &attempt(), StatusCode::OK, ms, None, None);
+        notify_metric(&config, &Method::GET, Endpoint::Jobs, None, ms);
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let message = |f: fn()| {
+            let panic = panic::catch_unwind(f).unwrap_err();
+            panic_message(&*panic).to_string()
+        };
+        assert_eq!(message(|| panic!("static")), "static");
+        assert_eq!(message(|| panic!("formatted {}", 1)), "formatted 1");
+        assert_eq!(
+            message(|| std::panic::panic_any(1u8)),
+            "<non-string payload>"
+        );
+    }
+
+    fn attempt() -> Attempt<'static> {
+        static GET: Method = Method::GET;
+        Attempt {
+            method: &GET,
+            endpoint: Endpoint::Jobs,
+            url: "https://example.com",
+            number: 1,
+        }
+    }
+}
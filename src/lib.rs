@@ -10,6 +10,7 @@
 //! - 📄 **Job Details**: Get comprehensive information about specific job postings
 //! - 🏢 **Employer Logos**: Download employer logos when available
 //! - 🔄 **Pagination**: Automatic pagination support for large result sets (with lazy iteration)
+//! - 👀 **Watching**: Poll a search and get notified about new listings ([`JobWatcher`])
 //! - 🦀 **Type-Safe**: Strongly typed API with enums for all parameters
 //! - ⚡ **Sync & Async**: Both synchronous and asynchronous clients (async with `async` feature flag)
 //! - 🔁 **Retry Logic**: Automatic retry with exponential backoff for transient failures
@@ -27,14 +28,11 @@
 //! ## Basic Usage
 //!
 //! ```no_run
-//! use jobsuche::{Jobsuche, Credentials, SearchOptions, Arbeitszeit};
+//! use jobsuche::{Jobsuche, SearchOptions, Arbeitszeit};
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! // Create a client with the default API key
-//! let client = Jobsuche::new(
-//!     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-//!     Credentials::default()
-//! )?;
+//! let client = Jobsuche::default_client()?;
 //!
 //! // Search for jobs
 //! let results = client.search().list(SearchOptions::builder()
@@ -81,13 +79,10 @@
 //! ## Pagination
 //!
 //! ```no_run
-//! use jobsuche::{Jobsuche, Credentials, SearchOptions};
+//! use jobsuche::{Jobsuche, SearchOptions};
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
-//! let client = Jobsuche::new(
-//!     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-//!     Credentials::default()
-//! )?;
+//! let client = Jobsuche::default_client()?;
 //!
 //! // Manual pagination
 //! let page1 = client.search().list(SearchOptions::builder()
@@ -128,39 +123,100 @@
 //! - Falls back to exponential backoff if no `Retry-After` header
 //! - Configurable retry attempts (default: 3)
 //!
+//! # Tracing
+//!
+//! Every API request runs in an info-level `jobsuche.request` span (fields `endpoint`,
+//! `method`, `attempts`, `duration_ms`, `error`) with one `jobsuche.attempt` child span per
+//! attempt (`attempt`, `status`, `duration_ms`, `retry_after_ms`, `error`). `endpoint` is the
+//! [`Endpoint`] name and `error` the [`ErrorKind`] name. Details lookups are wrapped in a
+//! debug-level `jobsuche.job_details` span with the `refnr`, so reference numbers are only
+//! recorded at debug verbosity.
+//!
 //! # Features
 //!
 //! - `async`: Enable asynchronous client (requires tokio runtime)
-//! - `cache`: Enable response caching
-//! - `metrics`: Enable performance metrics collection
+//! - `cache`: In-memory TTL cache for job details and searches, see `ClientConfig::cache`
+//! - `metrics`: Request counters and latency histograms via the `metrics` facade
+//!   (`jobsuche::metrics`)
+//! - `prometheus`: `jobsuche::metrics::prometheus_handle()` renders the metrics in the
+//!   Prometheus text format (implies `metrics`)
+//! - `tower`: Expose the async client as a `tower::Service` (implies `async`)
+//! - `chrono`: Typed accessors for date fields, e.g. `JobListing::published_date()`
+//! - `csv`: CSV export of search results and job details (`jobsuche::export`)
+//! - `schemars`: JSON Schemas of the response types, e.g. `JobDetails::schema()`
 //! - `full`: Enable all features
 
+mod auth;
 pub mod builder;
 pub mod core;
 mod errors;
+pub mod hooks;
 pub mod pagination;
+pub mod rate_limit;
 pub mod rep;
 pub mod search;
 pub mod sync;
+mod telemetry;
+pub mod translate;
+pub mod watch;
 
 #[cfg(feature = "async")]
 pub mod async_client;
+#[cfg(feature = "cache")]
+pub mod cache;
+#[cfg(feature = "chrono")]
+mod dates;
+#[cfg(feature = "csv")]
+pub mod export;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "schemars")]
+mod schema;
+
+#[cfg(feature = "async")]
+pub mod request_options;
+#[cfg(feature = "tower")]
+pub mod service;
 
 // Re-export main types for convenience
 pub use builder::{SearchOptions, SearchOptionsBuilder};
-pub use core::{decode_refnr, encode_refnr, ClientCore, Credentials};
-pub use errors::{ApiErrors, Error, Result};
+pub use core::{
+    decode_refnr, encode_refnr, encode_refnr_urlsafe, validate_refnr, ClientCore, Credentials,
+    Refnr, API_KEY_ENV, DEFAULT_ERROR_BODY_LIMIT, DEFAULT_HOST, DEFAULT_REQUEST_ID_HEADER,
+    HOST_ENV, WEB_DETAIL_URL,
+};
+pub use errors::{
+    ApiError, ApiErrors, Error, ErrorKind, RequestContext, ResourceKind, Result, ResultExt,
+    TimeoutPhase, UnknownFieldPolicy,
+};
+pub use hooks::{Endpoint, RequestInfo, RequestMetric, ResponseInfo};
+pub use rate_limit::RateLimit;
 pub use rep::{
-    Address, Angebotsart, Arbeitszeit, Befristung, Coordinates, Facet, FacetData, JobDetails,
-    JobListing, JobSearchResponse, LeadershipSkills, Mobility, Skill, WorkLocation,
+    Address, Angebotsart, Arbeitszeit, Befristung, Betriebsgroesse, Conditional, Coordinates,
+    Facet, FacetData, Facetten, JobDetails, JobListing, JobLocation, JobSearchResponse,
+    LeadershipSkills, LocationAddress, Mobility, ParseEnumError, ParsePlzError, Plz,
+    Reisebereitschaft, Skill, WorkLocation,
 };
 pub use search::Search;
 pub use sync::{ClientConfig, Jobsuche};
+pub use watch::JobWatcher;
 
 #[cfg(feature = "async")]
 pub use async_client::JobsucheAsync;
+#[cfg(feature = "cache")]
+pub use cache::{
+    Cache, CacheConfig, CacheHandle, CacheStats, CachedEntry, FileCache, MemoryCache, WarmupReport,
+    CACHE_SCHEMA_VERSION,
+};
+#[cfg(feature = "async")]
+pub use request_options::RequestOptions;
 #[cfg(feature = "async")]
 pub use search::SearchAsync;
+#[cfg(feature = "async")]
+pub use watch::JobWatcherAsync;
+
+// Re-export the status type returned by `Error::status_code`
+pub use reqwest::StatusCode;
 
 // Re-export tracing for users who want logging
 pub use tracing;
@@ -178,6 +234,12 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[test]
+    fn test_create_default_client() {
+        let client = Jobsuche::default_client();
+        assert!(client.is_ok());
+    }
+
     #[test]
     fn test_search_options() {
         let options = SearchOptions::builder()
@@ -0,0 +1,680 @@
+//! Request metrics via the [`metrics`](https://docs.rs/metrics) facade (requires the `metrics`
+//! feature)
+//!
+//! Both clients record every request attempt with the recorder installed by the
+//! application, e.g. `metrics-exporter-prometheus`. Without a recorder, recording does
+//! nothing. All metrics carry an `endpoint` label with the [`Endpoint::as_str`] name
+//! (`jobs`, `jobdetails` or `logo`); reference numbers and logo hashes never appear in labels.
+//!
+//! | Name | Type | Labels |
+//! |------|------|--------|
+//! | [`REQUESTS_TOTAL`] | counter | `endpoint` |
+//! | [`SUCCESSES_TOTAL`] | counter | `endpoint` |
+//! | [`ERRORS_TOTAL`] | counter | `endpoint`, `class` (`4xx`, `5xx` or `transport`) |
+//! | [`RETRIES_TOTAL`] | counter | `endpoint` |
+//! | [`RATE_LIMITED_TOTAL`] | counter | `endpoint` |
+//! | [`SLOW_REQUESTS_TOTAL`] | counter | `endpoint` |
+//! | [`REQUEST_DURATION_SECONDS`] | histogram | `endpoint`, `class` (see [`StatusClass`]) |
+//! | [`RETRY_WAIT_SECONDS`] | histogram | `endpoint`, `reason` (`retry_after` or `backoff`) |
+//!
+//! The names follow the Prometheus conventions. Without a metrics stack of your own, enable
+//! the `prometheus` feature and serve the output of [`prometheus_handle`].
+//!
+//! Independent of any recorder, each client also keeps its own counters and latency
+//! statistics, available through `metrics()` on [`Jobsuche`](crate::Jobsuche) and
+//! `JobsucheAsync` as a [`MetricsSnapshot`]. It breaks the latency down by endpoint and
+//! [`StatusClass`], keeps the slowest requests and adds up the time spent sleeping before
+//! retries.
+//!
+//! The callback-based [`ClientConfig::on_metric`](crate::ClientConfig::on_metric) hook
+//! works without this feature.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "prometheus")]
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use ::metrics::{counter, histogram};
+#[cfg(feature = "prometheus")]
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use parking_lot::Mutex;
+use reqwest::StatusCode;
+
+use crate::hooks::Endpoint;
+use crate::telemetry::WaitReason;
+
+#[cfg(feature = "prometheus")]
+pub use metrics_exporter_prometheus::PrometheusHandle;
+
+/// Request attempts, including retries
+pub const REQUESTS_TOTAL: &str = "jobsuche_requests_total";
+/// Attempts answered with a status below 400 (including 304 Not Modified)
+pub const SUCCESSES_TOTAL: &str = "jobsuche_requests_success_total";
+/// Failed attempts, by `class`: `4xx`, `5xx` or `transport` (no response)
+pub const ERRORS_TOTAL: &str = "jobsuche_requests_errors_total";
+/// Retries scheduled after a failed attempt
+pub const RETRIES_TOTAL: &str = "jobsuche_retries_total";
+/// Attempts answered with 429 Too Many Requests
+pub const RATE_LIMITED_TOTAL: &str = "jobsuche_rate_limited_total";
+/// Attempts slower than [`ClientConfig::slow_request_threshold`](crate::ClientConfig::slow_request_threshold)
+pub const SLOW_REQUESTS_TOTAL: &str = "jobsuche_slow_requests_total";
+/// Time until the response headers arrived (or the attempt failed), in seconds
+pub const REQUEST_DURATION_SECONDS: &str = "jobsuche_request_duration_seconds";
+/// Sleeps before retries, in seconds, by `reason`: `retry_after` (requested by the server)
+/// or `backoff`
+///
+/// The `_count` and `_sum` series give the number of sleeps and the total time slept.
+pub const RETRY_WAIT_SECONDS: &str = "jobsuche_retry_wait_seconds";
+
+/// Upper bounds of the [`REQUEST_DURATION_SECONDS`] buckets in the Prometheus exposition
+#[cfg(feature = "prometheus")]
+const DURATION_BUCKETS: &[f64] = &[0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Install a Prometheus recorder and return the handle rendering the collected metrics
+/// (requires the `prometheus` feature)
+///
+/// The recorder is installed globally on the first call, so it also collects metrics of
+/// other libraries using the `metrics` facade. Later calls return the same handle.
+/// [`PrometheusHandle::render`] returns the text exposition format for a `/metrics`
+/// endpoint. Call [`PrometheusHandle::run_upkeep`] every few seconds in long-running
+/// services so histogram data is drained.
+///
+/// # Errors
+///
+/// [`Error::ConfigError`](crate::Error::ConfigError) if another global recorder was
+/// installed first.
+///
+/// # Example
+///
+/// Serving the metrics with axum:
+///
+/// ```no_run
+/// use axum::{routing::get, Router};
+/// use jobsuche::metrics::prometheus_handle;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let handle = prometheus_handle()?;
+///     let app = Router::new().route("/metrics", get(move || async move { handle.render() }));
+///
+///     let listener = tokio::net::TcpListener::bind("0.0.0.0:9000").await?;
+///     axum::serve(listener, app).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "prometheus")]
+pub fn prometheus_handle() -> crate::Result<PrometheusHandle> {
+    static HANDLE: OnceLock<Result<PrometheusHandle, String>> = OnceLock::new();
+
+    HANDLE
+        .get_or_init(|| {
+            let handle = PrometheusBuilder::new()
+                .set_buckets_for_metric(
+                    Matcher::Full(REQUEST_DURATION_SECONDS.to_string()),
+                    DURATION_BUCKETS,
+                )
+                .and_then(PrometheusBuilder::install_recorder)
+                .map_err(|e| format!("failed to install the Prometheus recorder: {}", e))?;
+            describe();
+            Ok(handle)
+        })
+        .clone()
+        .map_err(|message| crate::Error::ConfigError { message })
+}
+
+/// Register units and help texts with the installed recorder
+#[cfg(feature = "prometheus")]
+fn describe() {
+    use ::metrics::{describe_counter, describe_histogram, Unit};
+
+    describe_counter!(REQUESTS_TOTAL, "Request attempts, including retries");
+    describe_counter!(
+        SUCCESSES_TOTAL,
+        "Request attempts answered with a status below 400"
+    );
+    describe_counter!(
+        ERRORS_TOTAL,
+        "Failed request attempts by class (4xx, 5xx, transport)"
+    );
+    describe_counter!(RETRIES_TOTAL, "Retries after a failed request attempt");
+    describe_counter!(
+        RATE_LIMITED_TOTAL,
+        "Request attempts answered with 429 Too Many Requests"
+    );
+    describe_counter!(
+        SLOW_REQUESTS_TOTAL,
+        "Request attempts slower than the configured threshold"
+    );
+    describe_histogram!(
+        REQUEST_DURATION_SECONDS,
+        Unit::Seconds,
+        "Time until the response headers arrived or the attempt failed"
+    );
+    describe_histogram!(
+        RETRY_WAIT_SECONDS,
+        Unit::Seconds,
+        "Sleeps before retries by reason (retry_after, backoff)"
+    );
+}
+
+/// Record one request attempt with the installed recorder
+fn record_request(endpoint: Endpoint, status: Option<StatusCode>, duration: Duration) {
+    let label = endpoint.as_str();
+    let class = StatusClass::of(status);
+    counter!(REQUESTS_TOTAL, "endpoint" => label).increment(1);
+    histogram!(REQUEST_DURATION_SECONDS, "endpoint" => label, "class" => class.as_str())
+        .record(duration.as_secs_f64());
+    if class.is_error() {
+        counter!(ERRORS_TOTAL, "endpoint" => label, "class" => class.as_str()).increment(1);
+    } else {
+        counter!(SUCCESSES_TOTAL, "endpoint" => label).increment(1);
+    }
+    if status == Some(StatusCode::TOO_MANY_REQUESTS) {
+        counter!(RATE_LIMITED_TOTAL, "endpoint" => label).increment(1);
+    }
+}
+
+/// Record a retry and its sleep with the installed recorder
+fn record_retry(endpoint: Endpoint, reason: WaitReason, delay: Duration) {
+    let label = endpoint.as_str();
+    counter!(RETRIES_TOTAL, "endpoint" => label).increment(1);
+    histogram!(RETRY_WAIT_SECONDS, "endpoint" => label, "reason" => reason.as_str())
+        .record(delay.as_secs_f64());
+}
+
+/// Number of recent latencies kept for the percentiles of [`LatencyStats`]
+const RESERVOIR_SIZE: usize = 1024;
+/// Number of recent latencies kept per [`EndpointMetrics`] series
+const SERIES_RESERVOIR_SIZE: usize = 256;
+/// Number of requests kept in [`MetricsSnapshot::slowest`]
+const SLOWEST_SIZE: usize = 16;
+
+/// Coarse class of a response status, used to label latencies
+///
+/// One of a handful of values, so series stay bounded no matter which statuses occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum StatusClass {
+    /// 2xx
+    Success,
+    /// 3xx, e.g. 304 Not Modified after a revalidation
+    Redirection,
+    /// 4xx
+    ClientError,
+    /// 5xx
+    ServerError,
+    /// No response (connection error, timeout)
+    Transport,
+}
+
+impl StatusClass {
+    /// Class of an attempt's status, `None` if no response arrived
+    pub fn of(status: Option<StatusCode>) -> StatusClass {
+        match status {
+            None => StatusClass::Transport,
+            Some(status) if status.is_server_error() => StatusClass::ServerError,
+            Some(status) if status.is_client_error() => StatusClass::ClientError,
+            Some(status) if status.is_redirection() => StatusClass::Redirection,
+            Some(_) => StatusClass::Success,
+        }
+    }
+
+    /// Label value (`2xx`, `3xx`, `4xx`, `5xx` or `transport`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusClass::Success => "2xx",
+            StatusClass::Redirection => "3xx",
+            StatusClass::ClientError => "4xx",
+            StatusClass::ServerError => "5xx",
+            StatusClass::Transport => "transport",
+        }
+    }
+
+    /// Whether the attempt counts as failed
+    pub fn is_error(&self) -> bool {
+        matches!(
+            self,
+            StatusClass::ClientError | StatusClass::ServerError | StatusClass::Transport
+        )
+    }
+}
+
+/// Counters and latency statistics of one client, returned by `metrics()`
+///
+/// Clones of a client share their counters, so the snapshot covers all of them. Like the
+/// facade metrics, every attempt counts, including retries and 304 revalidations; cache
+/// hits never reach the API and are not counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MetricsSnapshot {
+    /// Request attempts, including retries
+    pub requests: u64,
+    /// Attempts answered with a status below 400
+    pub successes: u64,
+    /// Attempts answered with a 4xx status, including [`rate_limited`](Self::rate_limited)
+    pub client_errors: u64,
+    /// Attempts answered with a 5xx status
+    pub server_errors: u64,
+    /// Attempts that failed without a response (connection errors, timeouts)
+    pub transport_errors: u64,
+    /// Attempts answered with 429 Too Many Requests
+    pub rate_limited: u64,
+    /// Attempts slower than
+    /// [`ClientConfig::slow_request_threshold`](crate::ClientConfig::slow_request_threshold)
+    pub slow_requests: u64,
+    /// Retries scheduled after a failed attempt
+    pub retries: u64,
+    /// Total time slept before retries because the server sent `Retry-After`
+    pub retry_after_wait: Duration,
+    /// Retries preceded by an exponential backoff sleep instead of a `Retry-After` wait
+    pub backoff_sleeps: u64,
+    /// Total time slept for exponential backoff
+    pub backoff_wait: Duration,
+    /// Longest single sleep before a retry, for either reason
+    pub longest_wait: Duration,
+    /// Statistics of the attempt durations
+    pub latency: LatencyStats,
+    /// Latency per endpoint and status class, ordered by endpoint, then class
+    pub by_endpoint: Vec<EndpointMetrics>,
+    /// The slowest attempts so far (at most 16), slowest first
+    pub slowest: Vec<SlowRequest>,
+}
+
+impl MetricsSnapshot {
+    /// The `n` slowest attempts so far, slowest first
+    ///
+    /// At most 16 attempts are kept, so larger values of `n` return at most 16.
+    pub fn slowest_requests(&self, n: usize) -> &[SlowRequest] {
+        &self.slowest[..n.min(self.slowest.len())]
+    }
+
+    /// Latency series of `endpoint` and `class`, if such an attempt was recorded
+    pub fn series(&self, endpoint: Endpoint, class: StatusClass) -> Option<&EndpointMetrics> {
+        self.by_endpoint
+            .iter()
+            .find(|series| series.endpoint == endpoint && series.class == class)
+    }
+}
+
+/// Latency of one endpoint and status class in a [`MetricsSnapshot`]
+///
+/// The percentiles cover the most recent 256 attempts of the series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EndpointMetrics {
+    /// Endpoint template of the attempts
+    pub endpoint: Endpoint,
+    /// Status class of the attempts
+    pub class: StatusClass,
+    /// Statistics of the attempt durations
+    pub latency: LatencyStats,
+}
+
+/// One attempt in [`MetricsSnapshot::slowest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SlowRequest {
+    /// Endpoint template; reference numbers and URLs are not kept
+    pub endpoint: Endpoint,
+    /// Response status, `None` if no response arrived
+    pub status: Option<StatusCode>,
+    /// Time until the response headers arrived or the attempt failed
+    pub duration: Duration,
+}
+
+/// Latency statistics of a [`MetricsSnapshot`]
+///
+/// `count`, `sum`, `min` and `max` cover all attempts. The percentiles are computed from
+/// the most recent 1024 attempts. All durations are zero before the first attempt.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LatencyStats {
+    /// Number of recorded attempts
+    pub count: u64,
+    /// Total duration of all attempts
+    pub sum: Duration,
+    /// Shortest attempt
+    pub min: Duration,
+    /// Longest attempt
+    pub max: Duration,
+    /// Median of the recent attempts
+    pub p50: Duration,
+    /// 95th percentile of the recent attempts
+    pub p95: Duration,
+}
+
+/// Per-client counters behind [`MetricsSnapshot`], shared by clones through the client's `Arc`
+#[derive(Debug, Default)]
+pub(crate) struct ClientMetrics {
+    requests: AtomicU64,
+    successes: AtomicU64,
+    client_errors: AtomicU64,
+    server_errors: AtomicU64,
+    transport_errors: AtomicU64,
+    rate_limited: AtomicU64,
+    slow_requests: AtomicU64,
+    retries: AtomicU64,
+    retry_after_wait_nanos: AtomicU64,
+    backoff_sleeps: AtomicU64,
+    backoff_wait_nanos: AtomicU64,
+    longest_wait_nanos: AtomicU64,
+    latency: Mutex<LatencyState>,
+}
+
+#[derive(Debug, Default)]
+struct LatencyState {
+    overall: Latencies,
+    series: BTreeMap<(Endpoint, StatusClass), Latencies>,
+    /// The slowest attempts, slowest first
+    slowest: Vec<SlowRequest>,
+}
+
+#[derive(Debug, Default)]
+struct Latencies {
+    count: u64,
+    sum: Duration,
+    min: Duration,
+    max: Duration,
+    /// Ring buffer of the most recent durations
+    recent: Vec<Duration>,
+    /// Position of the next write once `recent` is full
+    next: usize,
+}
+
+impl ClientMetrics {
+    /// Record one request attempt, here and with the installed recorder
+    pub(crate) fn record(
+        &self,
+        endpoint: Endpoint,
+        status: Option<StatusCode>,
+        duration: Duration,
+    ) {
+        record_request(endpoint, status, duration);
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let counter = match StatusClass::of(status) {
+            StatusClass::Success | StatusClass::Redirection => &self.successes,
+            StatusClass::ClientError => &self.client_errors,
+            StatusClass::ServerError => &self.server_errors,
+            StatusClass::Transport => &self.transport_errors,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        if status == Some(StatusCode::TOO_MANY_REQUESTS) {
+            self.rate_limited.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency.lock().record(endpoint, status, duration);
+    }
+
+    /// Record an attempt slower than the configured threshold, here and with the installed
+    /// recorder
+    pub(crate) fn record_slow(&self, endpoint: Endpoint) {
+        counter!(SLOW_REQUESTS_TOTAL, "endpoint" => endpoint.as_str()).increment(1);
+        self.slow_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record that a failed attempt will be retried after sleeping for `delay`, here and with
+    /// the installed recorder
+    pub(crate) fn record_retry(&self, endpoint: Endpoint, reason: WaitReason, delay: Duration) {
+        record_retry(endpoint, reason, delay);
+
+        self.retries.fetch_add(1, Ordering::Relaxed);
+        let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+        let total = match reason {
+            WaitReason::RetryAfter => &self.retry_after_wait_nanos,
+            WaitReason::Backoff => {
+                self.backoff_sleeps.fetch_add(1, Ordering::Relaxed);
+                &self.backoff_wait_nanos
+            }
+        };
+        total.fetch_add(nanos, Ordering::Relaxed);
+        self.longest_wait_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            requests: self.requests.load(Ordering::Relaxed),
+            successes: self.successes.load(Ordering::Relaxed),
+            client_errors: self.client_errors.load(Ordering::Relaxed),
+            server_errors: self.server_errors.load(Ordering::Relaxed),
+            transport_errors: self.transport_errors.load(Ordering::Relaxed),
+            rate_limited: self.rate_limited.load(Ordering::Relaxed),
+            slow_requests: self.slow_requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            retry_after_wait: load_duration(&self.retry_after_wait_nanos),
+            backoff_sleeps: self.backoff_sleeps.load(Ordering::Relaxed),
+            backoff_wait: load_duration(&self.backoff_wait_nanos),
+            longest_wait: load_duration(&self.longest_wait_nanos),
+            ..self.latency.lock().snapshot()
+        }
+    }
+}
+
+impl LatencyState {
+    fn record(&mut self, endpoint: Endpoint, status: Option<StatusCode>, duration: Duration) {
+        self.overall.record(duration, RESERVOIR_SIZE);
+        self.series
+            .entry((endpoint, StatusClass::of(status)))
+            .or_default()
+            .record(duration, SERIES_RESERVOIR_SIZE);
+
+        if self.slowest.len() == SLOWEST_SIZE
+            && self.slowest.last().is_some_and(|s| s.duration >= duration)
+        {
+            return;
+        }
+        let position = self.slowest.partition_point(|s| s.duration >= duration);
+        self.slowest.insert(
+            position,
+            SlowRequest {
+                endpoint,
+                status,
+                duration,
+            },
+        );
+        self.slowest.truncate(SLOWEST_SIZE);
+    }
+
+    /// Snapshot with the latency fields filled in and all counters zero
+    fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            latency: self.overall.stats(),
+            by_endpoint: self
+                .series
+                .iter()
+                .map(|(&(endpoint, class), latencies)| EndpointMetrics {
+                    endpoint,
+                    class,
+                    latency: latencies.stats(),
+                })
+                .collect(),
+            slowest: self.slowest.clone(),
+            ..MetricsSnapshot::default()
+        }
+    }
+}
+
+impl Latencies {
+    fn record(&mut self, duration: Duration, capacity: usize) {
+        self.min = if self.count == 0 {
+            duration
+        } else {
+            self.min.min(duration)
+        };
+        self.max = self.max.max(duration);
+        self.count += 1;
+        self.sum += duration;
+        if self.recent.len() < capacity {
+            self.recent.push(duration);
+        } else {
+            self.recent[self.next] = duration;
+            self.next = (self.next + 1) % capacity;
+        }
+    }
+
+    fn stats(&self) -> LatencyStats {
+        let mut sorted = self.recent.clone();
+        sorted.sort_unstable();
+        LatencyStats {
+            count: self.count,
+            sum: self.sum,
+            min: self.min,
+            max: self.max,
+            p50: percentile(&sorted, 50),
+            p95: percentile(&sorted, 95),
+        }
+    }
+}
+
+fn load_duration(nanos: &AtomicU64) -> Duration {
+    Duration::from_nanos(nanos.load(Ordering::Relaxed))
+}
+
+/// Nearest-rank percentile of sorted durations, zero if there are none
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (sorted.len() * p).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_class() {
+        let class = |status| StatusClass::of(Some(status));
+        assert_eq!(StatusClass::of(None).as_str(), "transport");
+        assert_eq!(class(StatusCode::OK).as_str(), "2xx");
+        assert_eq!(class(StatusCode::NOT_MODIFIED).as_str(), "3xx");
+        assert_eq!(class(StatusCode::NOT_FOUND).as_str(), "4xx");
+        assert_eq!(class(StatusCode::TOO_MANY_REQUESTS).as_str(), "4xx");
+        assert_eq!(class(StatusCode::BAD_GATEWAY).as_str(), "5xx");
+
+        assert!(StatusClass::of(None).is_error());
+        assert!(!class(StatusCode::OK).is_error());
+        assert!(!class(StatusCode::NOT_MODIFIED).is_error());
+        assert!(class(StatusCode::NOT_FOUND).is_error());
+        assert!(class(StatusCode::BAD_GATEWAY).is_error());
+    }
+
+    #[test]
+    fn test_snapshot_counts_by_class() {
+        let metrics = ClientMetrics::default();
+        assert_eq!(metrics.snapshot(), MetricsSnapshot::default());
+
+        let ms = Duration::from_millis;
+        metrics.record(Endpoint::Jobs, Some(StatusCode::OK), ms(30));
+        metrics.record(Endpoint::JobDetails, Some(StatusCode::NOT_FOUND), ms(10));
+        metrics.record(Endpoint::Jobs, Some(StatusCode::TOO_MANY_REQUESTS), ms(20));
+        metrics.record(Endpoint::Jobs, Some(StatusCode::BAD_GATEWAY), ms(50));
+        metrics.record(Endpoint::Jobs, None, ms(40));
+        metrics.record_retry(Endpoint::Jobs, WaitReason::Backoff, ms(1000));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests, 5);
+        assert_eq!(snapshot.successes, 1);
+        assert_eq!(snapshot.client_errors, 2);
+        assert_eq!(snapshot.rate_limited, 1);
+        assert_eq!(snapshot.server_errors, 1);
+        assert_eq!(snapshot.transport_errors, 1);
+        assert_eq!(snapshot.retries, 1);
+        assert_eq!(snapshot.backoff_sleeps, 1);
+        assert_eq!(snapshot.latency.count, 5);
+        assert_eq!(snapshot.latency.sum, ms(150));
+        assert_eq!(snapshot.latency.min, ms(10));
+        assert_eq!(snapshot.latency.max, ms(50));
+        assert_eq!(snapshot.latency.p50, ms(30));
+        assert_eq!(snapshot.latency.p95, ms(50));
+
+        assert_eq!(snapshot.by_endpoint.len(), 5);
+        let details = snapshot
+            .series(Endpoint::JobDetails, StatusClass::ClientError)
+            .unwrap();
+        assert_eq!(details.latency.count, 1);
+        assert_eq!(details.latency.max, ms(10));
+        let jobs = snapshot
+            .series(Endpoint::Jobs, StatusClass::ClientError)
+            .unwrap();
+        assert_eq!(jobs.latency.count, 1);
+        assert_eq!(jobs.latency.max, ms(20));
+        assert!(snapshot
+            .series(Endpoint::JobDetails, StatusClass::Success)
+            .is_none());
+
+        let slowest = snapshot.slowest_requests(2);
+        assert_eq!(slowest.len(), 2);
+        assert_eq!(slowest[0].duration, ms(50));
+        assert_eq!(slowest[0].status, Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(slowest[1].duration, ms(40));
+        assert_eq!(slowest[1].status, None);
+        assert_eq!(snapshot.slowest_requests(100).len(), 5);
+    }
+
+    #[test]
+    fn test_retry_waits() {
+        let metrics = ClientMetrics::default();
+        let secs = Duration::from_secs;
+        metrics.record_retry(Endpoint::Jobs, WaitReason::RetryAfter, secs(5));
+        metrics.record_retry(Endpoint::JobDetails, WaitReason::RetryAfter, secs(2));
+        metrics.record_retry(Endpoint::Jobs, WaitReason::Backoff, secs(1));
+        metrics.record_retry(Endpoint::Jobs, WaitReason::Backoff, secs(3));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.retries, 4);
+        assert_eq!(snapshot.retry_after_wait, secs(7));
+        assert_eq!(snapshot.backoff_sleeps, 2);
+        assert_eq!(snapshot.backoff_wait, secs(4));
+        assert_eq!(snapshot.longest_wait, secs(5));
+    }
+
+    #[test]
+    fn test_slowest_keeps_the_slowest() {
+        let mut state = LatencyState::default();
+        for ms in (1..=100).rev() {
+            state.record(
+                Endpoint::Jobs,
+                Some(StatusCode::OK),
+                Duration::from_millis(ms),
+            );
+        }
+        for ms in 1..=100 {
+            state.record(
+                Endpoint::JobDetails,
+                Some(StatusCode::OK),
+                Duration::from_millis(ms),
+            );
+        }
+
+        let slowest = state.snapshot().slowest;
+        assert_eq!(slowest.len(), SLOWEST_SIZE);
+        assert_eq!(slowest[0].duration, Duration::from_millis(100));
+        assert!(slowest.windows(2).all(|w| w[0].duration >= w[1].duration));
+        assert_eq!(
+            slowest.last().unwrap().duration,
+            Duration::from_millis(100 - SLOWEST_SIZE as u64 / 2 + 1)
+        );
+    }
+
+    #[test]
+    fn test_percentiles_use_recent_latencies() {
+        let mut latencies = Latencies::default();
+        for ms in 1..=100 {
+            latencies.record(Duration::from_millis(ms), RESERVOIR_SIZE);
+        }
+        let stats = latencies.stats();
+        assert_eq!(stats.p50, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+
+        // Overwrite the whole reservoir; min and max still cover every attempt
+        for _ in 0..RESERVOIR_SIZE {
+            latencies.record(Duration::from_millis(500), RESERVOIR_SIZE);
+        }
+        let stats = latencies.stats();
+        assert_eq!(stats.count, 100 + RESERVOIR_SIZE as u64);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.p50, Duration::from_millis(500));
+    }
+}
@@ -6,7 +6,94 @@
 use tracing::debug;
 
 use crate::sync::Jobsuche;
-use crate::{JobListing, Result, SearchOptions};
+use crate::{JobListing, Result, ResultExt, SearchOptions};
+
+#[cfg(feature = "async")]
+use crate::JobSearchResponse;
+
+/// API limit: maximum 100 pages total (Issue #14 in bundesAPI/jobsuche-api)
+pub(crate) const MAX_PAGES: u64 = 100;
+
+/// Page size the API uses when the request doesn't set `size`
+pub(crate) const DEFAULT_PAGE_SIZE: u64 = 50;
+
+/// Page-by-page progress of a paginated search
+///
+/// Shared termination logic: pagination stops after a partial page, once `maxErgebnisse`
+/// results have been received, or at the [`MAX_PAGES`] API limit.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub(crate) struct PageCursor {
+    options: SearchOptions,
+    next_page: u64,
+    page_size: u64,
+    received: u64,
+    max_results: Option<u64>,
+    finished: bool,
+}
+
+#[cfg(feature = "async")]
+impl PageCursor {
+    pub(crate) fn new(options: SearchOptions) -> Self {
+        let page_size = options.size().unwrap_or(DEFAULT_PAGE_SIZE);
+        PageCursor {
+            options,
+            next_page: 1,
+            page_size,
+            received: 0,
+            max_results: None,
+            finished: false,
+        }
+    }
+
+    /// Options for the next page to fetch, or `None` if pagination is complete
+    pub(crate) fn next_options(&mut self) -> Option<SearchOptions> {
+        if self.finished {
+            return None;
+        }
+        if self.next_page > MAX_PAGES {
+            debug!("Reached API limit: maximum {} pages", MAX_PAGES);
+            self.finished = true;
+            return None;
+        }
+
+        let options = self
+            .options
+            .as_builder()
+            .page(self.next_page)
+            .size(self.page_size)
+            .build();
+        self.next_page += 1;
+        Some(options)
+    }
+
+    /// Record a fetched page and decide whether another page is needed
+    pub(crate) fn record(&mut self, response: &JobSearchResponse) {
+        if self.max_results.is_none() {
+            self.max_results = response.max_ergebnisse;
+        }
+
+        let jobs_count = response.stellenangebote.len() as u64;
+        self.received += jobs_count;
+
+        // Stop if we got fewer results than requested (last page)
+        if jobs_count < self.page_size {
+            self.finished = true;
+        }
+
+        // Check if we've reached the maximum results
+        if let Some(max) = self.max_results {
+            if self.received >= max {
+                self.finished = true;
+            }
+        }
+    }
+
+    /// Stop pagination, e.g. after an error
+    pub(crate) fn finish(&mut self) {
+        self.finished = true;
+    }
+}
 
 /// A lazy iterator over job search results
 ///
@@ -17,12 +104,9 @@ use crate::{JobListing, Result, SearchOptions};
 /// # Example
 ///
 /// ```no_run
-/// use jobsuche::{Jobsuche, Credentials, SearchOptions};
+/// use jobsuche::{Jobsuche, SearchOptions};
 ///
-/// let client = Jobsuche::new(
-///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-///     Credentials::default()
-/// ).unwrap();
+/// let client = Jobsuche::default_client().unwrap();
 ///
 /// let options = SearchOptions::builder()
 ///     .was("Rust Developer")
@@ -52,7 +136,7 @@ pub struct JobIterator {
 impl JobIterator {
     /// Create a new lazy job iterator
     pub(crate) fn new(client: &Jobsuche, options: SearchOptions) -> Result<Self> {
-        let page_size = options.size().unwrap_or(50);
+        let page_size = options.size().unwrap_or(DEFAULT_PAGE_SIZE);
 
         Ok(JobIterator {
             client: client.clone(),
@@ -75,9 +159,8 @@ impl JobIterator {
 
         self.current_page += 1;
 
-        // API limit: maximum 100 pages total (Issue #14 in bundesAPI/jobsuche-api)
-        if self.current_page > 100 {
-            debug!("Reached API limit: maximum 100 pages");
+        if self.current_page > MAX_PAGES {
+            debug!("Reached API limit: maximum {} pages", MAX_PAGES);
             self.finished = true;
             return Ok(false);
         }
@@ -91,7 +174,11 @@ impl JobIterator {
 
         debug!("Fetching page {}", self.current_page);
 
-        let response = self.client.search().list(page_options)?;
+        let response = self
+            .client
+            .search()
+            .list_page(page_options)
+            .context_op("fetching a page of search results")?;
 
         // Store max_results from first page
         if self.current_page == 1 {
@@ -163,4 +250,53 @@ mod tests {
         let iterator = JobIterator::new(&client, options);
         assert!(iterator.is_ok());
     }
+
+    #[cfg(feature = "async")]
+    fn page(count: usize, max: Option<u64>) -> JobSearchResponse {
+        let listing: JobListing =
+            serde_json::from_str(r#"{"refnr": "REF", "arbeitsort": {}}"#).unwrap();
+        JobSearchResponse {
+            stellenangebote: vec![listing; count],
+            max_ergebnisse: max,
+            page: None,
+            size: None,
+            facetten: None,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_page_cursor_stops_after_partial_page() {
+        let mut cursor = PageCursor::new(SearchOptions::builder().size(2).build());
+
+        assert_eq!(cursor.next_options().unwrap().page(), Some(1));
+        cursor.record(&page(2, None));
+        assert_eq!(cursor.next_options().unwrap().page(), Some(2));
+        cursor.record(&page(1, None));
+        assert!(cursor.next_options().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_page_cursor_stops_at_max_results() {
+        let mut cursor = PageCursor::new(SearchOptions::builder().size(2).build());
+
+        cursor.next_options().unwrap();
+        cursor.record(&page(2, Some(4)));
+        cursor.next_options().unwrap();
+        cursor.record(&page(2, Some(4)));
+        assert!(cursor.next_options().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_page_cursor_page_limit() {
+        let mut cursor = PageCursor::new(SearchOptions::builder().size(1).build());
+
+        for _ in 0..MAX_PAGES {
+            cursor.next_options().unwrap();
+            cursor.record(&page(1, None));
+        }
+        assert!(cursor.next_options().is_none());
+    }
 }
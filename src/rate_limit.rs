@@ -0,0 +1,115 @@
+//! Client-side rate limiting
+//!
+//! Configured via [`ClientConfig::rate_limit`](crate::ClientConfig::rate_limit). The limiter is
+//! shared by all clones of a client, and every attempt (including retries) consumes a slot, so
+//! the configured rate is never exceeded even while the client is retrying.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{Error, Result};
+
+/// Maximum request rate for a client
+///
+/// At most `max_requests` requests are started within any window of length `per`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests allowed per window
+    pub max_requests: u32,
+    /// Length of the window
+    pub per: Duration,
+}
+
+impl RateLimit {
+    /// Allow `max_requests` requests per second
+    pub fn per_second(max_requests: u32) -> Self {
+        Self {
+            max_requests,
+            per: Duration::from_secs(1),
+        }
+    }
+
+    /// Allow `max_requests` requests per minute
+    pub fn per_minute(max_requests: u32) -> Self {
+        Self {
+            max_requests,
+            per: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Sliding-window limiter handing out start times for requests
+///
+/// Callers reserve a slot and then sleep for the returned duration with whatever sleep
+/// primitive suits them (`thread::sleep` or `tokio::time::sleep`).
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    /// Start times of the most recent `max_requests` reservations (possibly in the future)
+    slots: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Result<Self> {
+        if limit.max_requests == 0 {
+            return Err(Error::ConfigError {
+                message: "rate_limit.max_requests must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            limit,
+            slots: Mutex::new(VecDeque::with_capacity(limit.max_requests as usize)),
+        })
+    }
+
+    /// Reserve the next free slot and return how long to wait before using it
+    pub(crate) fn reserve(&self) -> Duration {
+        self.reserve_at(Instant::now())
+    }
+
+    fn reserve_at(&self, now: Instant) -> Duration {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        let start = if slots.len() < self.limit.max_requests as usize {
+            now
+        } else {
+            let oldest = slots.pop_front().expect("window is full");
+            (oldest + self.limit.per).max(now)
+        };
+        slots.push_back(start);
+        start.saturating_duration_since(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_then_wait() {
+        let limiter = RateLimiter::new(RateLimit::per_second(2)).unwrap();
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        assert_eq!(limiter.reserve_at(now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve_at(now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve_at(now), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_window_frees_up_over_time() {
+        let limiter = RateLimiter::new(RateLimit::per_second(1)).unwrap();
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve_at(now), Duration::ZERO);
+        let later = now + Duration::from_millis(1500);
+        assert_eq!(limiter.reserve_at(later), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_zero_requests_rejected() {
+        let result = RateLimiter::new(RateLimit::per_second(0));
+        assert!(matches!(result, Err(Error::ConfigError { .. })));
+    }
+}
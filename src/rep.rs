@@ -1,11 +1,61 @@
 //! Response types for the Jobsuche API
 
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use tracing::debug;
+use url::Url;
+
+use crate::core::Refnr;
+use crate::pagination::{DEFAULT_PAGE_SIZE, MAX_PAGES};
+use crate::SearchOptions;
+
+/// Result of a conditional request sent with `If-None-Match`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conditional<T> {
+    /// The resource changed (or no ETag was sent)
+    Modified {
+        /// The freshly fetched value
+        value: T,
+        /// ETag of the response, to send with the next conditional request
+        etag: Option<String>,
+    },
+    /// The resource is unchanged since the ETag was issued (HTTP 304)
+    NotModified,
+}
+
+impl<T> Conditional<T> {
+    /// Whether a new value was returned
+    pub fn is_modified(&self) -> bool {
+        matches!(self, Conditional::Modified { .. })
+    }
+
+    /// ETag of a modified response
+    pub fn etag(&self) -> Option<&str> {
+        match self {
+            Conditional::Modified { etag, .. } => etag.as_deref(),
+            Conditional::NotModified => None,
+        }
+    }
+
+    /// The new value, or `None` if not modified
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            Conditional::Modified { value, .. } => Some(value),
+            Conditional::NotModified => None,
+        }
+    }
+}
 
 /// Job search response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields may be added in minor releases; build instances with
+/// [`JobSearchResponse::new`] instead of a struct literal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct JobSearchResponse {
     pub stellenangebote: Vec<JobListing>,
     #[serde(default)]
@@ -14,20 +64,195 @@ pub struct JobSearchResponse {
     pub page: Option<u64>,
     #[serde(default)]
     pub size: Option<u64>,
-    /// Facets for filtering (raw HashMap - structure varies)
+    /// Result counts per filter value, for narrowing down the search
     #[serde(default)]
-    pub facetten: Option<serde_json::Value>,
+    pub facetten: Option<Facetten>,
+}
+
+impl JobSearchResponse {
+    /// A response with the given listings and no paging information or facets
+    pub fn new(stellenangebote: Vec<JobListing>) -> Self {
+        JobSearchResponse {
+            stellenangebote,
+            ..Default::default()
+        }
+    }
+
+    /// Set `max_ergebnisse`, the total number of matching jobs
+    pub fn with_max_ergebnisse(mut self, max_ergebnisse: u64) -> Self {
+        self.max_ergebnisse = Some(max_ergebnisse);
+        self
+    }
+
+    /// Set `page` and `size`
+    pub fn with_page(mut self, page: u64, size: u64) -> Self {
+        self.page = Some(page);
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the facet counts
+    pub fn with_facetten(mut self, facetten: Facetten) -> Self {
+        self.facetten = Some(facetten);
+        self
+    }
+
+    /// Number of pages for all matching jobs, at most the API limit of 100
+    ///
+    /// `None` if `max_ergebnisse` or `size` is missing.
+    pub fn total_pages(&self) -> Option<u64> {
+        let size = self.size.filter(|&size| size > 0)?;
+        Some(self.max_ergebnisse?.div_ceil(size).min(MAX_PAGES))
+    }
+
+    /// Whether a page after this one can be fetched, see [`next_options`](Self::next_options)
+    pub fn has_next_page(&self) -> bool {
+        self.next_page(None, None).is_some()
+    }
+
+    /// `current` with `page` advanced to the next page, or `None` after the last page
+    ///
+    /// `current` should be the options this response was fetched with. The API allows at
+    /// most 100 pages. Without `max_ergebnisse`, a page holding fewer than `size` listings
+    /// is taken as the last one; missing `page` and `size` fall back to those in `current`
+    /// and then to the API defaults (page 1, 50 listings).
+    ///
+    /// ```no_run
+    /// # use jobsuche::{Jobsuche, SearchOptions};
+    /// # let client = Jobsuche::default_client().unwrap();
+    /// let mut options = Some(SearchOptions::builder().was("Koch").build());
+    /// while let Some(current) = options {
+    ///     let page = client.search().list(current.clone()).unwrap();
+    ///     println!("{} jobs", page.stellenangebote.len());
+    ///     options = page.next_options(&current);
+    /// }
+    /// ```
+    pub fn next_options(&self, current: &SearchOptions) -> Option<SearchOptions> {
+        let next = self.next_page(current.page(), current.size())?;
+        Some(current.as_builder().page(next).build())
+    }
+
+    fn next_page(&self, page_hint: Option<u64>, size_hint: Option<u64>) -> Option<u64> {
+        let page = self.page.or(page_hint).unwrap_or(1);
+        let size = self
+            .size
+            .or(size_hint)
+            .filter(|&size| size > 0)
+            .unwrap_or(DEFAULT_PAGE_SIZE);
+        let more = match self.max_ergebnisse {
+            Some(total) => page.saturating_mul(size) < total,
+            None => self.stellenangebote.len() as u64 >= size,
+        };
+        (more && !self.stellenangebote.is_empty() && page < MAX_PAGES).then(|| page + 1)
+    }
+
+    /// Combine two responses, e.g. from searches over several locations or several pages
+    ///
+    /// - `stellenangebote`: the listings of `self`, then those of `other`; a listing whose
+    ///   `refnr` was already seen is dropped
+    /// - `max_ergebnisse`: the larger of the two, since summing would count jobs found by
+    ///   both searches twice; `None` only if both are `None`
+    /// - `page` and `size`: `None`, as the result is no longer a single page
+    /// - `facetten`: kept as is if both are equal (pages of the same search), otherwise
+    ///   counts are summed per group and value; `None` if either response has no facets
+    pub fn merge(mut self, other: JobSearchResponse) -> JobSearchResponse {
+        let mut seen: HashSet<Refnr> = HashSet::new();
+        self.stellenangebote.extend(other.stellenangebote);
+        self.stellenangebote
+            .retain(|listing| seen.insert(listing.refnr.clone()));
+
+        JobSearchResponse {
+            stellenangebote: self.stellenangebote,
+            max_ergebnisse: self.max_ergebnisse.max(other.max_ergebnisse),
+            page: None,
+            size: None,
+            facetten: match (self.facetten, other.facetten) {
+                (Some(a), Some(b)) if a == b => Some(a),
+                (Some(a), Some(b)) => Some(a.merge(b)),
+                _ => None,
+            },
+        }
+    }
+
+    /// [`merge`](Self::merge) all responses in order
+    ///
+    /// A single response is returned unchanged, no responses give an empty one.
+    pub fn merge_all(responses: impl IntoIterator<Item = JobSearchResponse>) -> JobSearchResponse {
+        let mut responses = responses.into_iter();
+        let first = responses.next().unwrap_or_default();
+        responses.fold(first, JobSearchResponse::merge)
+    }
+
+    /// Counts of a facet group by its API name, `None` if the response has no such group
+    pub fn facet_counts(&self, name: &str) -> Option<&HashMap<String, u64>> {
+        self.facetten.as_ref()?.get(name).map(|group| &group.counts)
+    }
+
+    /// Counts of the `arbeitszeit` facet per working time model
+    ///
+    /// Values that are not an [`Arbeitszeit`] are dropped.
+    ///
+    /// ```
+    /// # use jobsuche::{Arbeitszeit, JobSearchResponse};
+    /// # let response: JobSearchResponse = serde_json::from_str(
+    /// #     r#"{"stellenangebote": [], "facetten": {"arbeitszeit": {"counts": {"vz": 12, "tz": 5}}}}"#,
+    /// # ).unwrap();
+    /// for (arbeitszeit, count) in response.arbeitszeit_facet().unwrap_or_default() {
+    ///     println!("{}: {}", arbeitszeit, count);
+    /// }
+    /// ```
+    pub fn arbeitszeit_facet(&self) -> Option<BTreeMap<Arbeitszeit, u64>> {
+        self.typed_facet("arbeitszeit")
+    }
+
+    /// Counts of the `befristung` facet per contract type
+    ///
+    /// Values that are not a [`Befristung`] are dropped.
+    pub fn befristung_facet(&self) -> Option<BTreeMap<Befristung, u64>> {
+        self.typed_facet("befristung")
+    }
+
+    /// Counts of the `angebotsart` facet per offer type
+    ///
+    /// Codes this crate doesn't know are kept as [`Angebotsart::Unknown`].
+    pub fn angebotsart_facet(&self) -> Option<BTreeMap<Angebotsart, u64>> {
+        let counts = self.facet_counts("angebotsart")?;
+        let typed = counts
+            .iter()
+            .map(|(code, &count)| (Angebotsart::from_code(code), count))
+            .collect();
+        Some(typed)
+    }
+
+    fn typed_facet<T: FromStr + Ord>(&self, name: &str) -> Option<BTreeMap<T, u64>> {
+        let counts = self.facet_counts(name)?;
+        let typed = counts
+            .iter()
+            .filter_map(|(value, &count)| match value.parse() {
+                Ok(typed) => Some((typed, count)),
+                Err(_) => {
+                    debug!("Ignoring unknown {} facet value {:?}", name, value);
+                    None
+                }
+            })
+            .collect();
+        Some(typed)
+    }
 }
 
 /// Individual job listing in search results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields may be added in minor releases; build instances with [`JobListing::new`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct JobListing {
     /// Hash ID for the job (may be missing, use refnr instead)
     #[serde(default)]
     pub hash_id: Option<String>,
     /// Reference number (use this for job details)
-    pub refnr: String,
+    pub refnr: Refnr,
     /// Job title/profession (may be missing for some listings)
     #[serde(default)]
     pub beruf: Option<String>,
@@ -56,9 +281,135 @@ pub struct JobListing {
     pub kundennummer_hash: Option<String>,
 }
 
+impl JobListing {
+    /// A listing with the given reference number and location and no other data
+    ///
+    /// Set further fields with the `with_*` methods or by assigning to them.
+    ///
+    /// ```
+    /// use jobsuche::{JobListing, WorkLocation};
+    ///
+    /// let job = JobListing::new("10001-1001601666-S", WorkLocation::default().with_ort("Berlin"))
+    ///     .with_beruf("Koch/Köchin")
+    ///     .with_arbeitgeber("Restaurant am Spreeufer GmbH");
+    /// assert_eq!(job.arbeitsort.ort(), Some("Berlin"));
+    /// ```
+    pub fn new(refnr: impl Into<Refnr>, arbeitsort: WorkLocation) -> Self {
+        JobListing {
+            hash_id: None,
+            refnr: refnr.into(),
+            beruf: None,
+            titel: None,
+            arbeitgeber: None,
+            aktuelle_veroeffentlichungsdatum: None,
+            eintrittsdatum: None,
+            arbeitsort,
+            modifikations_timestamp: None,
+            externe_url: None,
+            kundennummer_hash: None,
+        }
+    }
+
+    /// A listing with the fields most code looks at, e.g. for tests
+    ///
+    /// ```
+    /// use jobsuche::JobListing;
+    ///
+    /// let job = JobListing::new_minimal("10001-1001601666-S", "Koch/Köchin", "Hotel Adlon", "Berlin")
+    ///     .with_titel("Koch (m/w/d)")
+    ///     .with_koordinaten(jobsuche::Coordinates { lat: 52.516, lon: 13.380 });
+    /// assert_eq!(job.arbeitsort.ort(), Some("Berlin"));
+    /// assert_eq!(job.arbeitgeber.as_deref(), Some("Hotel Adlon"));
+    /// ```
+    pub fn new_minimal(
+        refnr: impl Into<Refnr>,
+        beruf: impl Into<String>,
+        arbeitgeber: impl Into<String>,
+        ort: impl Into<String>,
+    ) -> Self {
+        JobListing::new(refnr, WorkLocation::default().with_ort(ort))
+            .with_beruf(beruf)
+            .with_arbeitgeber(arbeitgeber)
+    }
+
+    /// Set the profession
+    pub fn with_beruf(mut self, beruf: impl Into<String>) -> Self {
+        self.beruf = Some(beruf.into());
+        self
+    }
+
+    /// Set the listing title
+    pub fn with_titel(mut self, titel: impl Into<String>) -> Self {
+        self.titel = Some(titel.into());
+        self
+    }
+
+    /// Set the employer name
+    pub fn with_arbeitgeber(mut self, arbeitgeber: impl Into<String>) -> Self {
+        self.arbeitgeber = Some(arbeitgeber.into());
+        self
+    }
+
+    /// Set the publication date (`YYYY-MM-DD`)
+    pub fn with_veroeffentlichungsdatum(mut self, datum: impl Into<String>) -> Self {
+        self.aktuelle_veroeffentlichungsdatum = Some(datum.into());
+        self
+    }
+
+    /// Set the start date (`YYYY-MM-DD`)
+    pub fn with_eintrittsdatum(mut self, datum: impl Into<String>) -> Self {
+        self.eintrittsdatum = Some(datum.into());
+        self
+    }
+
+    /// Set the modification timestamp
+    pub fn with_modifikations_timestamp(mut self, timestamp: impl Into<String>) -> Self {
+        self.modifikations_timestamp = Some(timestamp.into());
+        self
+    }
+
+    /// Set the coordinates of the work location
+    pub fn with_koordinaten(mut self, koordinaten: Coordinates) -> Self {
+        self.arbeitsort.koordinaten = Some(koordinaten);
+        self
+    }
+
+    /// Set the external URL
+    pub fn with_externe_url(mut self, url: impl Into<String>) -> Self {
+        self.externe_url = Some(url.into());
+        self
+    }
+
+    /// Whether the listing is hosted on an external job board (`externe_url` is set)
+    pub fn is_external(&self) -> bool {
+        self.externe_url
+            .as_deref()
+            .is_some_and(|url| !url.trim().is_empty())
+    }
+
+    /// `externe_url` as a parsed URL
+    ///
+    /// `None` if the field is missing, relative, malformed or not an `http(s)` URL.
+    pub fn external_url(&self) -> Option<Url> {
+        parse_web_url("externeUrl", &self.externe_url)
+    }
+
+    /// Detail page of the job on arbeitsagentur.de, see [`Refnr::web_url`]
+    ///
+    /// The link stops working once the job expires.
+    pub fn web_url(&self) -> Url {
+        self.refnr.web_url()
+    }
+}
+
 /// Work location information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields may be added in minor releases; build instances from
+/// [`WorkLocation::default`] and the `with_*` methods.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
+#[non_exhaustive]
 pub struct WorkLocation {
     #[serde(default)]
     pub plz: Option<String>,
@@ -70,40 +421,381 @@ pub struct WorkLocation {
     pub region: Option<String>,
     #[serde(default)]
     pub land: Option<String>,
-    #[serde(default)]
+    /// Coordinates; dropped rather than failing the listing if they can't be read
+    #[serde(default, deserialize_with = "lenient_coordinates")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Coordinates>"))]
     pub koordinaten: Option<Coordinates>,
     /// Distance from search location in km
     #[serde(default)]
     pub entfernung: Option<String>,
 }
 
+impl WorkLocation {
+    /// Set the postal code
+    pub fn with_plz(mut self, plz: impl Into<String>) -> Self {
+        self.plz = Some(plz.into());
+        self
+    }
+
+    /// Set the city
+    pub fn with_ort(mut self, ort: impl Into<String>) -> Self {
+        self.ort = Some(ort.into());
+        self
+    }
+
+    /// Set the region (federal state)
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the country
+    pub fn with_land(mut self, land: impl Into<String>) -> Self {
+        self.land = Some(land.into());
+        self
+    }
+
+    /// Set the street and house number
+    pub fn with_strasse(mut self, strasse: impl Into<String>) -> Self {
+        self.strasse = Some(strasse.into());
+        self
+    }
+
+    /// Set the distance from the search location (`entfernung`, in km)
+    pub fn with_entfernung(mut self, entfernung: impl Into<String>) -> Self {
+        self.entfernung = Some(entfernung.into());
+        self
+    }
+
+    /// Set the coordinates
+    pub fn with_koordinaten(mut self, koordinaten: Coordinates) -> Self {
+        self.koordinaten = Some(koordinaten);
+        self
+    }
+
+    /// City and postal code, e.g. `Berlin (10115)`; empty if both are missing
+    pub fn short(&self) -> String {
+        short_address(non_blank(&self.plz), non_blank(&self.ort))
+    }
+
+    /// `plz` as a validated [`Plz`]; `None` if missing or not five digits
+    pub fn plz_typed(&self) -> Option<Plz> {
+        parse_plz(&self.plz)
+    }
+
+    /// City of the location, like [`JobLocation::ort`]
+    pub fn ort(&self) -> Option<&str> {
+        self.ort.as_deref()
+    }
+
+    /// Postal code of the location, like [`JobLocation::plz`]
+    pub fn plz(&self) -> Option<&str> {
+        self.plz.as_deref()
+    }
+
+    /// Coordinates of the location, like [`JobLocation::coordinates`]
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        self.koordinaten
+    }
+
+    /// Distance in km from `origin`
+    ///
+    /// Uses `entfernung` if the API sent one, otherwise the great-circle distance from
+    /// `koordinaten` (see [`Coordinates::distance_km`]). `entfernung` is measured from the
+    /// `wo` location of the search, so pass the coordinates of that location as `origin`
+    /// to get consistent values across listings. `None` if neither is available.
+    pub fn distance_from(&self, origin: &Coordinates) -> Option<f64> {
+        self.distance_km().or_else(|| {
+            self.koordinaten
+                .as_ref()
+                .map(|koordinaten| koordinaten.distance_km(origin))
+        })
+    }
+
+    /// `entfernung` as a number, e.g. for sorting by distance
+    ///
+    /// Accepts `.` and `,` as decimal separator and a trailing unit such as `km`; `None`
+    /// if the field is missing or not a number.
+    ///
+    /// ```
+    /// # let location: jobsuche::WorkLocation =
+    /// #     serde_json::from_str(r#"{"entfernung": "12,3 km"}"#).unwrap();
+    /// assert_eq!(location.distance_km(), Some(12.3));
+    /// ```
+    pub fn distance_km(&self) -> Option<f64> {
+        let value = self
+            .entfernung
+            .as_deref()?
+            .trim()
+            .trim_end_matches(char::is_alphabetic)
+            .trim_end()
+            .replace(',', ".");
+        value.parse().ok().filter(|km: &f64| km.is_finite())
+    }
+}
+
 /// Geographic coordinates
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `lat` and `lon` are read from numbers as well as strings, with `.` or `,` as decimal
+/// separator (`"52,52"`); they are always written as numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RawCoordinates")]
 pub struct Coordinates {
     pub lat: f64,
     pub lon: f64,
 }
 
+/// Accepted shape of [`Coordinates`]: each value a number or a numeric string
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(rename = "Coordinates"))]
+struct RawCoordinates {
+    lat: CoordinateValue,
+    lon: CoordinateValue,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+enum CoordinateValue {
+    Number(f64),
+    Text(String),
+}
+
+impl CoordinateValue {
+    fn to_f64(&self) -> Option<f64> {
+        let value = match self {
+            Self::Number(value) => *value,
+            Self::Text(text) => text.trim().replace(',', ".").parse().ok()?,
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+impl TryFrom<RawCoordinates> for Coordinates {
+    type Error = String;
+
+    fn try_from(raw: RawCoordinates) -> Result<Self, Self::Error> {
+        match (raw.lat.to_f64(), raw.lon.to_f64()) {
+            (Some(lat), Some(lon)) => Ok(Coordinates { lat, lon }),
+            _ => Err("lat and lon must be numbers".to_string()),
+        }
+    }
+}
+
+/// `koordinaten` that can't be read as [`Coordinates`] become `None` instead of an error
+fn lenient_coordinates<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Coordinates>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| {
+        Coordinates::deserialize(&value)
+            .inspect_err(|err| debug!("Ignoring invalid koordinaten {}: {}", value, err))
+            .ok()
+    }))
+}
+
+/// Mean Earth radius in km (IUGG)
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+impl Coordinates {
+    /// Great-circle distance to `other` in km, using the haversine formula
+    ///
+    /// Treats the Earth as a sphere, which is off by at most about 0.5% compared to the
+    /// ellipsoid (a few km between German cities). This is straight-line distance, not
+    /// travel distance.
+    ///
+    /// ```
+    /// use jobsuche::Coordinates;
+    ///
+    /// let berlin = Coordinates { lat: 52.5200, lon: 13.4050 };
+    /// let hamburg = Coordinates { lat: 53.5511, lon: 9.9937 };
+    /// assert!((berlin.distance_km(&hamburg) - 255.0).abs() < 3.0);
+    /// ```
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.lon - self.lon).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
 /// Search facets for filtering
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Untyped form of [`Facetten`], kept for compatibility.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Facet {
     #[serde(flatten)]
     pub data: HashMap<String, FacetData>,
 }
 
+/// Facet counts of a search response
+///
+/// Each group maps filter values to the number of matching jobs. Groups that can be used
+/// as a filter are keyed by the same codes the [`SearchOptions`]
+/// builder sends, e.g. `vz` for [`Arbeitszeit::Vollzeit`]. Groups this crate does not know
+/// yet end up in `other`.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{Arbeitszeit, Jobsuche, SearchOptions};
+///
+/// let client = Jobsuche::default_client().unwrap();
+/// let page = client.search().list(SearchOptions::builder().was("Koch").build()).unwrap();
+/// if let Some(facetten) = page.facetten {
+///     println!("{} full-time jobs", facetten.arbeitszeit_count(Arbeitszeit::Vollzeit));
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Facetten {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbeitszeit: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub befristung: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angebotsart: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zeitarbeit: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branche: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beruf: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbeitgeber: Option<FacetData>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbeitsort: Option<FacetData>,
+    /// Keyed by the number of days since publication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub veroeffentlichtseit: Option<FacetData>,
+    /// Jobs from partner job boards, keyed by `true` and `false`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub externestellenboersen: Option<FacetData>,
+    /// Facet groups not covered by the fields above
+    #[serde(flatten)]
+    pub other: HashMap<String, FacetData>,
+}
+
+impl Facetten {
+    /// Look up a facet group by its API name, including groups in `other`
+    pub fn get(&self, name: &str) -> Option<&FacetData> {
+        let known = match name {
+            "arbeitszeit" => &self.arbeitszeit,
+            "befristung" => &self.befristung,
+            "angebotsart" => &self.angebotsart,
+            "zeitarbeit" => &self.zeitarbeit,
+            "branche" => &self.branche,
+            "beruf" => &self.beruf,
+            "arbeitgeber" => &self.arbeitgeber,
+            "arbeitsort" => &self.arbeitsort,
+            "veroeffentlichtseit" => &self.veroeffentlichtseit,
+            "externestellenboersen" => &self.externestellenboersen,
+            _ => return self.other.get(name),
+        };
+        known.as_ref()
+    }
+
+    /// Number of jobs with the given working time model, 0 if not reported
+    pub fn arbeitszeit_count(&self, arbeitszeit: Arbeitszeit) -> u64 {
+        Self::count(&self.arbeitszeit, arbeitszeit.as_str())
+    }
+
+    /// Number of jobs with the given contract type, 0 if not reported
+    pub fn befristung_count(&self, befristung: Befristung) -> u64 {
+        Self::count(&self.befristung, befristung.as_str())
+    }
+
+    /// Number of jobs with the given offer type, 0 if not reported
+    pub fn angebotsart_count(&self, angebotsart: Angebotsart) -> u64 {
+        Self::count(&self.angebotsart, angebotsart.as_str())
+    }
+
+    fn count(group: &Option<FacetData>, value: &str) -> u64 {
+        group.as_ref().map_or(0, |group| group.count(value))
+    }
+
+    /// Sum the counts of both, see [`JobSearchResponse::merge`]
+    fn merge(self, other: Facetten) -> Facetten {
+        let mut other_groups = other.other;
+        let mut merged_other = HashMap::new();
+        for (name, group) in self.other {
+            let merged = FacetData::merge(Some(group), other_groups.remove(&name));
+            merged_other.extend(merged.map(|group| (name, group)));
+        }
+        merged_other.extend(other_groups);
+
+        Facetten {
+            arbeitszeit: FacetData::merge(self.arbeitszeit, other.arbeitszeit),
+            befristung: FacetData::merge(self.befristung, other.befristung),
+            angebotsart: FacetData::merge(self.angebotsart, other.angebotsart),
+            zeitarbeit: FacetData::merge(self.zeitarbeit, other.zeitarbeit),
+            branche: FacetData::merge(self.branche, other.branche),
+            beruf: FacetData::merge(self.beruf, other.beruf),
+            arbeitgeber: FacetData::merge(self.arbeitgeber, other.arbeitgeber),
+            arbeitsort: FacetData::merge(self.arbeitsort, other.arbeitsort),
+            veroeffentlichtseit: FacetData::merge(
+                self.veroeffentlichtseit,
+                other.veroeffentlichtseit,
+            ),
+            externestellenboersen: FacetData::merge(
+                self.externestellenboersen,
+                other.externestellenboersen,
+            ),
+            other: merged_other,
+        }
+    }
+}
+
 /// Facet data with counts
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FacetData {
+    #[serde(default)]
     pub counts: HashMap<String, u64>,
+    #[serde(default)]
     pub max_count: u64,
 }
 
+impl FacetData {
+    /// Number of jobs with the given value, 0 if not reported
+    pub fn count(&self, value: &str) -> u64 {
+        self.counts.get(value).copied().unwrap_or_default()
+    }
+
+    /// Counts summed per value; a group missing on one side counts as empty
+    fn merge(a: Option<FacetData>, b: Option<FacetData>) -> Option<FacetData> {
+        let (mut merged, other) = match (a, b) {
+            (Some(a), Some(b)) => (a, b),
+            (a, b) => return a.or(b),
+        };
+        for (value, count) in other.counts {
+            let total = merged.counts.entry(value).or_default();
+            *total = total.saturating_add(count);
+        }
+        merged.max_count = merged
+            .counts
+            .values()
+            .copied()
+            .chain([merged.max_count, other.max_count])
+            .max()
+            .unwrap_or_default();
+        Some(merged)
+    }
+}
+
 /// Detailed job information
 ///
 /// This structure maps to the job details endpoint response.
 /// Field names are mapped via serde rename attributes to match the API's format.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Fields may be added in minor releases; build instances with [`JobDetails::new`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct JobDetails {
     #[serde(default, rename = "referenznummer")]
     pub refnr: Option<String>,
@@ -123,6 +815,16 @@ pub struct JobDetails {
     pub arbeitsorte: Vec<JobLocation>,
     #[serde(default, rename = "arbeitszeitVollzeit")]
     pub arbeitszeit_vollzeit: Option<bool>,
+    #[serde(default, rename = "arbeitszeitTeilzeitVormittag")]
+    pub arbeitszeit_teilzeit_vormittag: Option<bool>,
+    #[serde(default, rename = "arbeitszeitTeilzeitNachmittag")]
+    pub arbeitszeit_teilzeit_nachmittag: Option<bool>,
+    #[serde(default, rename = "arbeitszeitTeilzeitAbend")]
+    pub arbeitszeit_teilzeit_abend: Option<bool>,
+    #[serde(default, rename = "arbeitszeitTeilzeitFlexibel")]
+    pub arbeitszeit_teilzeit_flexibel: Option<bool>,
+    #[serde(default, rename = "arbeitszeitSchichtNachtWochenende")]
+    pub arbeitszeit_schicht_nacht_wochenende: Option<bool>,
     #[serde(default, rename = "verguetungsangabe")]
     pub verguetung: Option<String>,
     #[serde(default)]
@@ -151,113 +853,1016 @@ pub struct JobDetails {
     pub allianzpartner: Option<String>,
     #[serde(default, rename = "allianzpartnerUrl")]
     pub allianzpartner_url: Option<String>,
+    /// External URL (for postings hosted on another job board)
+    #[serde(default, rename = "externeURL", alias = "externeUrl")]
+    pub externe_url: Option<String>,
     #[serde(default)]
     pub chiffrenummer: Option<String>,
-}
-
-/// Job location information (from job details endpoint)
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JobLocation {
-    pub adresse: Option<LocationAddress>,
-    #[serde(default)]
-    pub breite: Option<f64>,
+    /// Required school or vocational degree
+    #[serde(default, rename = "geforderterBildungsabschluss")]
+    pub geforderter_bildungsabschluss: Option<String>,
+    /// Kind of apprenticeship, e.g. dual or school-based (training offers only)
     #[serde(default)]
-    pub laenge: Option<f64>,
-}
-
-/// Address information within a job location
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LocationAddress {
+    pub ausbildungsart: Option<String>,
+    /// Course of study (dual study offers only)
     #[serde(default)]
-    pub plz: Option<String>,
+    pub studiengang: Option<String>,
+    /// Form of study, e.g. full-time or part-time (dual study offers only)
     #[serde(default)]
-    pub ort: Option<String>,
+    pub studienform: Option<String>,
+    /// Company size bucket, e.g. `zwischen 51 und 500`, see [`JobDetails::betriebsgroesse_typed`]
     #[serde(default)]
-    pub region: Option<String>,
+    pub betriebsgroesse: Option<String>,
+    /// Employer's postal address, see [`JobDetails::primary_location`]
+    #[serde(default, rename = "arbeitgeberAdresse")]
+    pub arbeitgeber_adresse: Option<Address>,
+    /// Required skills, see [`JobDetails::skills_by_category`]
     #[serde(default)]
-    pub land: Option<String>,
+    pub fertigkeiten: Vec<Skill>,
 }
 
-/// Date range with optional 'von' and 'bis' dates
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DateRange {
-    #[serde(default)]
-    pub von: Option<String>,
-    #[serde(default)]
-    pub bis: Option<String>,
-}
+impl JobDetails {
+    /// Details with the given reference number and no other data
+    ///
+    /// Set further fields with the `with_*` methods or by assigning to them.
+    ///
+    /// ```
+    /// use jobsuche::{JobDetails, JobLocation, LocationAddress};
+    ///
+    /// let details = JobDetails::new("10001-1001601666-S")
+    ///     .with_titel("Koch (m/w/d)")
+    ///     .with_arbeitgeber("Hotel Adlon")
+    ///     .with_arbeitsort(JobLocation::new(LocationAddress {
+    ///         ort: Some("Berlin".to_string()),
+    ///         ..LocationAddress::default()
+    ///     }));
+    /// assert_eq!(details.arbeitsorte[0].ort(), Some("Berlin"));
+    /// ```
+    pub fn new(refnr: impl Into<String>) -> Self {
+        JobDetails {
+            refnr: Some(refnr.into()),
+            ..Default::default()
+        }
+    }
 
-/// Address information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Address {
-    pub land: String,
-    pub region: String,
-    pub plz: Option<String>,
-    pub ort: String,
-    #[serde(default)]
-    pub strasse: Option<String>,
-    #[serde(default)]
-    pub strasse_hausnummer: Option<String>,
-}
+    /// Set the listing title
+    pub fn with_titel(mut self, titel: impl Into<String>) -> Self {
+        self.titel = Some(titel.into());
+        self
+    }
 
-/// Skill/competency requirement
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Skill {
-    pub hierarchie_name: String,
-    #[serde(default)]
-    pub auspraegungen: Option<HashMap<String, Vec<String>>>,
-}
+    /// Set the employer name
+    pub fn with_arbeitgeber(mut self, arbeitgeber: impl Into<String>) -> Self {
+        self.arbeitgeber = Some(arbeitgeber.into());
+        self
+    }
 
-/// Mobility requirements
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Mobility {
-    #[serde(default)]
-    pub reisebereitschaft: Option<String>,
-}
+    /// Set the offer type code, e.g. `ARBEIT`
+    pub fn with_stellenangebots_art(mut self, art: impl Into<String>) -> Self {
+        self.stellenangebots_art = Some(art.into());
+        self
+    }
 
-/// Leadership competencies
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct LeadershipSkills {
-    #[serde(default)]
-    pub hat_vollmacht: Option<bool>,
-    #[serde(default)]
-    pub hat_budgetverantwortung: Option<bool>,
-}
+    /// Set the job description
+    pub fn with_stellenbeschreibung(mut self, beschreibung: impl Into<String>) -> Self {
+        self.stellenbeschreibung = Some(beschreibung.into());
+        self
+    }
 
-// Enums for type-safe parameters
+    /// Add a work location
+    pub fn with_arbeitsort(mut self, location: JobLocation) -> Self {
+        self.arbeitsorte.push(location);
+        self
+    }
 
-/// Employment type
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Angebotsart {
-    /// Regular employment (ARBEIT)
-    Arbeit = 1,
-    /// Self-employment (SELBSTAENDIGKEIT)
-    Selbstaendigkeit = 2,
-    /// Apprenticeship/Dual study (AUSBILDUNG/Duales Studium)
-    Ausbildung = 4,
-    /// Internship/Trainee (Praktikum/Trainee)
-    PraktikumTrainee = 34,
-}
+    /// Set the contract duration, e.g. `unbefristet`
+    pub fn with_vertragsdauer(mut self, vertragsdauer: impl Into<String>) -> Self {
+        self.vertragsdauer = Some(vertragsdauer.into());
+        self
+    }
 
-impl Angebotsart {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Arbeit => "1",
-            Self::Selbstaendigkeit => "2",
-            Self::Ausbildung => "4",
-            Self::PraktikumTrainee => "34",
-        }
+    /// Set the salary information
+    pub fn with_verguetung(mut self, verguetung: impl Into<String>) -> Self {
+        self.verguetung = Some(verguetung.into());
+        self
     }
-}
 
-/// Contract type (befristung)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
-#[repr(u8)]
+    /// Set the external URL
+    pub fn with_externe_url(mut self, url: impl Into<String>) -> Self {
+        self.externe_url = Some(url.into());
+        self
+    }
+
+    /// Working time models offered, derived from the `arbeitszeit*` flags
+    ///
+    /// Any of the part-time flags yields [`Arbeitszeit::Teilzeit`], and
+    /// `ist_geringfuegige_beschaeftigung` yields [`Arbeitszeit::Minijob`]. The details
+    /// endpoint has no flag for [`Arbeitszeit::HeimTelearbeit`], so it is never returned.
+    pub fn arbeitszeit_modelle(&self) -> Vec<Arbeitszeit> {
+        let teilzeit = [
+            self.arbeitszeit_teilzeit_vormittag,
+            self.arbeitszeit_teilzeit_nachmittag,
+            self.arbeitszeit_teilzeit_abend,
+            self.arbeitszeit_teilzeit_flexibel,
+        ]
+        .contains(&Some(true));
+
+        [
+            (
+                self.arbeitszeit_vollzeit == Some(true),
+                Arbeitszeit::Vollzeit,
+            ),
+            (teilzeit, Arbeitszeit::Teilzeit),
+            (
+                self.arbeitszeit_schicht_nacht_wochenende == Some(true),
+                Arbeitszeit::SchichtNachtarbeitWochenende,
+            ),
+            (
+                self.ist_geringfuegige_beschaeftigung == Some(true),
+                Arbeitszeit::Minijob,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(offered, model)| offered.then_some(model))
+        .collect()
+    }
+
+    /// Contract type, parsed from `vertragsdauer`
+    ///
+    /// Matches `befristet` and `unbefristet` in any case, so fetched details can be
+    /// filtered with the same [`Befristung`] used for searching. `None` if the field is
+    /// missing or holds anything else; the raw value stays in `vertragsdauer`.
+    pub fn befristung(&self) -> Option<Befristung> {
+        let value = self.vertragsdauer.as_deref()?;
+        value
+            .parse()
+            .inspect_err(|_| debug!("Unknown vertragsdauer {:?}", value))
+            .ok()
+    }
+
+    /// The location to show for the job: the first of `arbeitsorte`, or the employer's
+    /// address if the job lists no locations
+    ///
+    /// Returns an owned [`WorkLocation`] since both sources are converted.
+    pub fn primary_location(&self) -> Option<WorkLocation> {
+        self.arbeitsorte
+            .first()
+            .map(WorkLocation::from)
+            .or_else(|| self.arbeitgeber_adresse.clone().map(WorkLocation::from))
+    }
+
+    /// Number of work locations
+    pub fn location_count(&self) -> usize {
+        self.arbeitsorte.len()
+    }
+
+    /// Whether the job lists more than one work location
+    pub fn is_multi_location(&self) -> bool {
+        self.location_count() > 1
+    }
+
+    /// Skill values of all `fertigkeiten`, grouped by their `auspraegungen` category
+    ///
+    /// Values are listed once per category, in order of appearance. Skills without any
+    /// `auspraegungen` are listed by hierarchy name under
+    /// [`Skill::UNSPECIFIED_CATEGORY`].
+    pub fn skills_by_category(&self) -> BTreeMap<String, Vec<String>> {
+        let mut categories: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for skill in &self.fertigkeiten {
+            let mut values = skill.values().peekable();
+            if values.peek().is_none() {
+                let bucket = categories
+                    .entry(Skill::UNSPECIFIED_CATEGORY.to_string())
+                    .or_default();
+                if !bucket.contains(&skill.hierarchie_name) {
+                    bucket.push(skill.hierarchie_name.clone());
+                }
+            }
+            for (category, value) in values {
+                let bucket = categories.entry(category.to_string()).or_default();
+                if !bucket.iter().any(|known| known == value) {
+                    bucket.push(value.to_string());
+                }
+            }
+        }
+        categories
+    }
+
+    /// Whether [`skills_by_category`](Self::skills_by_category) lists `value` under
+    /// `category`, both ignoring case
+    pub fn has_skill_in_category(&self, category: &str, value: &str) -> bool {
+        let (category, value) = (category.to_lowercase(), value.to_lowercase());
+        self.skills_by_category()
+            .iter()
+            .filter(|(name, _)| name.to_lowercase() == category)
+            .flat_map(|(_, values)| values)
+            .any(|known| known.to_lowercase() == value)
+    }
+
+    /// Hierarchy names and values of all `fertigkeiten`, each skill's name followed by its
+    /// [`values`](Skill::values)
+    ///
+    /// Values shared by several skills are yielded once per skill.
+    pub fn all_skills(&self) -> impl Iterator<Item = &str> {
+        self.fertigkeiten.iter().flat_map(|skill| {
+            std::iter::once(skill.hierarchie_name.as_str())
+                .chain(skill.values().map(|(_, value)| value))
+        })
+    }
+
+    /// Whether any of the `fertigkeiten` [requires](Skill::requires) `skill`, ignoring case
+    pub fn requires(&self, skill: &str) -> bool {
+        self.fertigkeiten.iter().any(|known| known.requires(skill))
+    }
+
+    /// [`betriebsgroesse`](Self::betriebsgroesse) as a [`Betriebsgroesse`]
+    pub fn betriebsgroesse_typed(&self) -> Option<Betriebsgroesse> {
+        self.betriebsgroesse.as_deref().map(Betriebsgroesse::from)
+    }
+
+    /// Detail page of the job on arbeitsagentur.de, see [`Refnr::web_url`]
+    ///
+    /// `None` if `refnr` is missing or blank. The link stops working once the job expires.
+    pub fn web_url(&self) -> Option<Url> {
+        let refnr = self.refnr.as_deref()?.trim();
+        (!refnr.is_empty()).then(|| Refnr::new(refnr).web_url())
+    }
+
+    /// `externe_url` as a parsed URL, see [`JobListing::external_url`]
+    pub fn external_url(&self) -> Option<Url> {
+        parse_web_url("externeURL", &self.externe_url)
+    }
+
+    /// `allianzpartner_url` as a parsed URL
+    ///
+    /// `None` if the field is missing, relative, malformed or not an `http(s)` URL.
+    pub fn alliance_partner_url(&self) -> Option<Url> {
+        parse_web_url("allianzpartnerUrl", &self.allianzpartner_url)
+    }
+}
+
+/// Parse an absolute `http` or `https` URL sent by the API
+fn parse_web_url(field: &str, value: &Option<String>) -> Option<Url> {
+    let value = value.as_deref()?.trim();
+    if value.is_empty() {
+        return None;
+    }
+    match Url::parse(value) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Some(url),
+        Ok(_) | Err(_) => {
+            debug!("Ignoring invalid {} {:?}", field, value);
+            None
+        }
+    }
+}
+
+/// Job location information (from job details endpoint)
+///
+/// The details endpoint nests the address under `adresse`; locations in the flat shape of
+/// search results ([`WorkLocation`]) are accepted as well and normalized into `adresse`.
+/// Use [`ort`](Self::ort), [`plz`](Self::plz) and [`coordinates`](Self::coordinates) for the
+/// same view as on [`WorkLocation`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "RawJobLocation")]
+pub struct JobLocation {
+    pub adresse: Option<LocationAddress>,
+    #[serde(default)]
+    pub breite: Option<f64>,
+    #[serde(default)]
+    pub laenge: Option<f64>,
+}
+
+impl JobLocation {
+    /// A location at the given address, without coordinates
+    pub fn new(adresse: LocationAddress) -> Self {
+        JobLocation {
+            adresse: Some(adresse),
+            breite: None,
+            laenge: None,
+        }
+    }
+
+    /// Set `breite` and `laenge`
+    pub fn with_koordinaten(mut self, koordinaten: Coordinates) -> Self {
+        self.breite = Some(koordinaten.lat);
+        self.laenge = Some(koordinaten.lon);
+        self
+    }
+
+    /// City of the location
+    pub fn ort(&self) -> Option<&str> {
+        self.adresse.as_ref()?.ort.as_deref()
+    }
+
+    /// Postal code of the location
+    pub fn plz(&self) -> Option<&str> {
+        self.adresse.as_ref()?.plz.as_deref()
+    }
+
+    /// Coordinates from `breite` and `laenge`, if both are set
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        Some(Coordinates {
+            lat: self.breite?,
+            lon: self.laenge?,
+        })
+    }
+}
+
+/// Street is `strasse` and `hausnummer` joined with a space
+impl From<&JobLocation> for WorkLocation {
+    fn from(location: &JobLocation) -> Self {
+        let address = location.adresse.clone().unwrap_or_default();
+        let strasse = match (address.strasse, address.hausnummer) {
+            (Some(strasse), Some(hausnummer)) => Some(format!("{} {}", strasse, hausnummer)),
+            (strasse, _) => strasse,
+        };
+        WorkLocation {
+            plz: address.plz,
+            ort: address.ort,
+            strasse,
+            region: address.region,
+            land: address.land,
+            koordinaten: location.coordinates(),
+            entfernung: None,
+        }
+    }
+}
+
+/// Accepted shapes of a job-details location: nested `adresse` or flat like search results
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(rename = "JobLocation"))]
+struct RawJobLocation {
+    #[serde(default)]
+    adresse: Option<LocationAddress>,
+    #[serde(default)]
+    breite: Option<f64>,
+    #[serde(default)]
+    laenge: Option<f64>,
+    #[serde(default, deserialize_with = "lenient_coordinates")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Coordinates>"))]
+    koordinaten: Option<Coordinates>,
+    #[serde(flatten)]
+    flat: LocationAddress,
+}
+
+impl From<RawJobLocation> for JobLocation {
+    fn from(raw: RawJobLocation) -> Self {
+        let flat = raw.flat;
+        let has_flat_address = [
+            &flat.plz,
+            &flat.ort,
+            &flat.region,
+            &flat.land,
+            &flat.strasse,
+        ]
+        .iter()
+        .any(|field| field.is_some());
+        JobLocation {
+            adresse: raw.adresse.or(has_flat_address.then_some(flat)),
+            breite: raw.breite.or(raw.koordinaten.map(|c| c.lat)),
+            laenge: raw.laenge.or(raw.koordinaten.map(|c| c.lon)),
+        }
+    }
+}
+
+/// Address information within a job location
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LocationAddress {
+    #[serde(default)]
+    pub plz: Option<String>,
+    #[serde(default)]
+    pub ort: Option<String>,
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub land: Option<String>,
+    #[serde(default)]
+    pub strasse: Option<String>,
+    #[serde(default)]
+    pub hausnummer: Option<String>,
+}
+
+/// Single-line address, e.g. `Invalidenstraße 117, 10115 Berlin, Berlin, Deutschland`
+///
+/// Missing parts are left out together with their separators.
+impl fmt::Display for LocationAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let street = match (non_blank(&self.strasse), non_blank(&self.hausnummer)) {
+            (Some(strasse), Some(hausnummer)) => Some(format!("{} {}", strasse, hausnummer)),
+            (strasse, _) => strasse.map(String::from),
+        };
+        write_address(
+            f,
+            street.as_deref(),
+            non_blank(&self.plz),
+            non_blank(&self.ort),
+            non_blank(&self.region),
+            non_blank(&self.land),
+        )
+    }
+}
+
+/// Single-line address, e.g. `Musterstraße 1, 10115 Berlin, Berlin, Deutschland`
+///
+/// Missing parts are left out together with their separators; use
+/// [`short`](WorkLocation::short) for just the city.
+impl fmt::Display for WorkLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_address(
+            f,
+            non_blank(&self.strasse),
+            non_blank(&self.plz),
+            non_blank(&self.ort),
+            non_blank(&self.region),
+            non_blank(&self.land),
+        )
+    }
+}
+
+/// `Some` with the trimmed value, `None` if missing or blank
+fn non_blank(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Like [`non_blank`] for required string fields
+fn non_blank_str(value: &str) -> Option<&str> {
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
+
+/// `Straße, PLZ Ort, Region, Land` without the missing parts
+fn write_address(
+    f: &mut fmt::Formatter<'_>,
+    street: Option<&str>,
+    plz: Option<&str>,
+    ort: Option<&str>,
+    region: Option<&str>,
+    land: Option<&str>,
+) -> fmt::Result {
+    let city = match (plz, ort) {
+        (Some(plz), Some(ort)) => Some(format!("{} {}", plz, ort)),
+        (plz, ort) => plz.or(ort).map(String::from),
+    };
+    let parts: Vec<&str> = [street, city.as_deref(), region, land]
+        .into_iter()
+        .flatten()
+        .collect();
+    f.write_str(&parts.join(", "))
+}
+
+/// `Ort (PLZ)`, or whichever of the two is present
+fn short_address(plz: Option<&str>, ort: Option<&str>) -> String {
+    match (plz, ort) {
+        (Some(plz), Some(ort)) => format!("{} ({})", ort, plz),
+        (plz, ort) => plz.or(ort).unwrap_or_default().to_string(),
+    }
+}
+
+/// Date range with optional 'von' and 'bis' dates
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DateRange {
+    #[serde(default)]
+    pub von: Option<String>,
+    #[serde(default)]
+    pub bis: Option<String>,
+}
+
+/// Address information
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    #[serde(default)]
+    pub land: String,
+    #[serde(default)]
+    pub region: String,
+    pub plz: Option<String>,
+    #[serde(default)]
+    pub ort: String,
+    #[serde(default)]
+    pub strasse: Option<String>,
+    #[serde(default)]
+    pub strasse_hausnummer: Option<String>,
+}
+
+impl Address {
+    /// City and postal code, e.g. `Berlin (10115)`
+    pub fn short(&self) -> String {
+        short_address(non_blank(&self.plz), non_blank_str(&self.ort))
+    }
+
+    /// `plz` as a validated [`Plz`]; `None` if missing or not five digits
+    pub fn plz_typed(&self) -> Option<Plz> {
+        parse_plz(&self.plz)
+    }
+}
+
+/// German postal code: exactly five digits
+///
+/// Parsing ignores surrounding whitespace but does not pad short codes, since a missing
+/// leading zero can't be told apart from a missing last digit.
+///
+/// ```
+/// use jobsuche::Plz;
+///
+/// let plz: Plz = " 10115 ".parse().unwrap();
+/// assert_eq!(plz.as_str(), "10115");
+/// assert_eq!(plz.region_hint(), "Berlin, Brandenburg, Mecklenburg-Vorpommern");
+/// assert!("1011".parse::<Plz>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Plz(String);
+
+impl Plz {
+    /// The five digits
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Broad region of the postal zone (first digit), for rough geographic grouping
+    ///
+    /// Zones follow postal routing, not state borders, so border areas can fall into a
+    /// neighbouring zone's region.
+    pub fn region_hint(&self) -> &'static str {
+        match self.0.as_bytes()[0] {
+            b'0' => "Sachsen, Sachsen-Anhalt, Thüringen",
+            b'1' => "Berlin, Brandenburg, Mecklenburg-Vorpommern",
+            b'2' => "Hamburg, Schleswig-Holstein, Bremen, Niedersachsen (Nord)",
+            b'3' => "Niedersachsen, Sachsen-Anhalt (Nord), Nordhessen, Ostwestfalen",
+            b'4' => "Nordrhein-Westfalen (Ruhrgebiet, Münsterland, Niederrhein)",
+            b'5' => "Nordrhein-Westfalen (Rheinland), Rheinland-Pfalz (Nord)",
+            b'6' => "Hessen, Saarland, Rheinland-Pfalz (Süd), Nordbaden",
+            b'7' => "Baden-Württemberg",
+            b'8' => "Bayern (Süd), Baden-Württemberg (Südost)",
+            _ => "Bayern (Nord), Thüringen (Süd)",
+        }
+    }
+}
+
+impl FromStr for Plz {
+    type Err = ParsePlzError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let digits = value.trim();
+        if digits.len() == 5 && digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            Ok(Plz(digits.to_string()))
+        } else {
+            Err(ParsePlzError {
+                value: value.to_string(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for Plz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Plz {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A string is not a five-digit [`Plz`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid postal code {value:?}, expected five digits")]
+pub struct ParsePlzError {
+    /// The rejected input
+    pub value: String,
+}
+
+fn parse_plz(value: &Option<String>) -> Option<Plz> {
+    let value = value.as_deref()?;
+    value
+        .parse()
+        .inspect_err(|_| debug!("Ignoring invalid plz {:?}", value))
+        .ok()
+}
+
+/// Street is `strasse_hausnummer` if set, `strasse` otherwise; empty strings become `None`
+impl From<Address> for WorkLocation {
+    fn from(address: Address) -> Self {
+        let text = |value: String| Some(value).filter(|value| !value.trim().is_empty());
+        WorkLocation {
+            plz: address.plz.and_then(text),
+            ort: text(address.ort),
+            strasse: address
+                .strasse_hausnummer
+                .and_then(text)
+                .or_else(|| address.strasse.and_then(text)),
+            region: text(address.region),
+            land: text(address.land),
+            koordinaten: None,
+            entfernung: None,
+        }
+    }
+}
+
+/// Single-line address, e.g. `Musterstraße 1, 10115 Berlin, Berlin, Deutschland`
+///
+/// Uses `strasse_hausnummer` if set, `strasse` otherwise. Missing or empty parts are left
+/// out together with their separators.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_address(
+            f,
+            non_blank(&self.strasse_hausnummer).or(non_blank(&self.strasse)),
+            non_blank(&self.plz),
+            non_blank_str(&self.ort),
+            non_blank_str(&self.region),
+            non_blank_str(&self.land),
+        )
+    }
+}
+
+/// Skill/competency requirement
+///
+/// The keys of `auspraegungen` are categories such as `Kenntnisse` or
+/// `Expertenkenntnisse`, each listing skill values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Skill {
+    pub hierarchie_name: String,
+    #[serde(default)]
+    pub auspraegungen: Option<HashMap<String, Vec<String>>>,
+}
+
+impl Skill {
+    /// Category of skills without `auspraegungen` in [`JobDetails::skills_by_category`]
+    pub const UNSPECIFIED_CATEGORY: &'static str = "(unspecified)";
+
+    /// All `(category, value)` pairs of `auspraegungen`, ordered by category
+    ///
+    /// Empty if the skill has no `auspraegungen`.
+    pub fn values(&self) -> impl Iterator<Item = (&str, &str)> {
+        let mut categories: Vec<_> = self.auspraegungen.iter().flatten().collect();
+        categories.sort_by_key(|(category, _)| category.as_str());
+        categories.into_iter().flat_map(|(category, values)| {
+            values
+                .iter()
+                .map(move |value| (category.as_str(), value.as_str()))
+        })
+    }
+
+    /// Whether the hierarchy name or one of the values equals `skill`, ignoring case
+    pub fn requires(&self, skill: &str) -> bool {
+        let skill = skill.to_lowercase();
+        std::iter::once(self.hierarchie_name.as_str())
+            .chain(self.values().map(|(_, value)| value))
+            .any(|name| name.to_lowercase() == skill)
+    }
+}
+
+/// Mobility requirements
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Mobility {
+    #[serde(default)]
+    pub reisebereitschaft: Option<String>,
+}
+
+impl Mobility {
+    /// [`reisebereitschaft`](Self::reisebereitschaft) as a [`Reisebereitschaft`]
+    pub fn reisebereitschaft_typed(&self) -> Option<Reisebereitschaft> {
+        self.reisebereitschaft
+            .as_deref()
+            .map(Reisebereitschaft::from)
+    }
+}
+
+/// Willingness to travel required for a job, ordered from least to most travel
+///
+/// Values the crate doesn't know are kept as [`Unknown`](Self::Unknown) and sort after all
+/// known ones.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Reisebereitschaft {
+    /// No travel (nicht erforderlich)
+    NichtErforderlich,
+    /// Occasional travel (gelegentlich)
+    Gelegentlich,
+    /// Frequent travel (häufig)
+    Haeufig,
+    /// Any other value, as sent by the API
+    Unknown(String),
+}
+
+impl Reisebereitschaft {
+    /// The German text as used by the API, e.g. `nicht erforderlich`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::NichtErforderlich => "nicht erforderlich",
+            Self::Gelegentlich => "gelegentlich",
+            Self::Haeufig => "häufig",
+            Self::Unknown(value) => value,
+        }
+    }
+}
+
+/// Matches ignoring case and surrounding whitespace; `_` may replace spaces and `ae` the `ä`
+impl From<&str> for Reisebereitschaft {
+    fn from(value: &str) -> Self {
+        let normalized = value
+            .trim()
+            .to_lowercase()
+            .replace('_', " ")
+            .replace('ä', "ae");
+        match normalized.as_str() {
+            "nicht erforderlich" => Self::NichtErforderlich,
+            "gelegentlich" => Self::Gelegentlich,
+            "haeufig" => Self::Haeufig,
+            _ => Self::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Reisebereitschaft {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<Reisebereitschaft> for String {
+    fn from(value: Reisebereitschaft) -> Self {
+        match value {
+            Reisebereitschaft::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Reisebereitschaft {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Company size bucket (number of employees), ordered from smallest to largest
+///
+/// Values the crate doesn't know are kept as [`Unknown`](Self::Unknown) and sort after all
+/// known ones.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Betriebsgroesse {
+    /// Up to 5 employees (bis 5)
+    Bis5,
+    /// 6 to 50 employees (zwischen 6 und 50)
+    Zwischen6Und50,
+    /// 51 to 500 employees (zwischen 51 und 500)
+    Zwischen51Und500,
+    /// 501 to 5000 employees (zwischen 501 und 5000)
+    Zwischen501Und5000,
+    /// More than 5000 employees (über 5000)
+    Ueber5000,
+    /// Any other value, as sent by the API
+    Unknown(String),
+}
+
+impl Betriebsgroesse {
+    /// The German text as used by the API, e.g. `zwischen 51 und 500`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Bis5 => "bis 5",
+            Self::Zwischen6Und50 => "zwischen 6 und 50",
+            Self::Zwischen51Und500 => "zwischen 51 und 500",
+            Self::Zwischen501Und5000 => "zwischen 501 und 5000",
+            Self::Ueber5000 => "über 5000",
+            Self::Unknown(value) => value,
+        }
+    }
+
+    /// Smallest number of employees in the bucket; `None` for [`Unknown`](Self::Unknown)
+    pub fn min_employees(&self) -> Option<u32> {
+        match self {
+            Self::Bis5 => Some(1),
+            Self::Zwischen6Und50 => Some(6),
+            Self::Zwischen51Und500 => Some(51),
+            Self::Zwischen501Und5000 => Some(501),
+            Self::Ueber5000 => Some(5001),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Largest number of employees in the bucket; `None` for [`Ueber5000`](Self::Ueber5000)
+    /// and [`Unknown`](Self::Unknown)
+    pub fn max_employees(&self) -> Option<u32> {
+        match self {
+            Self::Bis5 => Some(5),
+            Self::Zwischen6Und50 => Some(50),
+            Self::Zwischen51Und500 => Some(500),
+            Self::Zwischen501Und5000 => Some(5000),
+            Self::Ueber5000 | Self::Unknown(_) => None,
+        }
+    }
+}
+
+/// Matches ignoring case and extra whitespace; `ueber` and `mehr als` are accepted for `über`
+impl From<&str> for Betriebsgroesse {
+    fn from(value: &str) -> Self {
+        let normalized = value
+            .to_lowercase()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace("ueber", "über")
+            .replace("mehr als", "über");
+        match normalized.as_str() {
+            "bis 5" | "1 bis 5" => Self::Bis5,
+            "zwischen 6 und 50" => Self::Zwischen6Und50,
+            "zwischen 51 und 500" => Self::Zwischen51Und500,
+            "zwischen 501 und 5000" => Self::Zwischen501Und5000,
+            "über 5000" => Self::Ueber5000,
+            _ => Self::Unknown(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Betriebsgroesse {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<Betriebsgroesse> for String {
+    fn from(value: Betriebsgroesse) -> Self {
+        match value {
+            Betriebsgroesse::Unknown(value) => value,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Betriebsgroesse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Leadership competencies
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeadershipSkills {
+    #[serde(default)]
+    pub hat_vollmacht: Option<bool>,
+    #[serde(default)]
+    pub hat_budgetverantwortung: Option<bool>,
+}
+
+// Enums for type-safe parameters
+
+/// Employment type
+///
+/// New offer types may be added in minor releases. Codes this crate doesn't know yet are
+/// read as [`Angebotsart::Unknown`] (when deserializing or in
+/// [`JobSearchResponse::angebotsart_facet`]), so they never fail decoding. Parsing with
+/// [`FromStr`] stays strict and rejects them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(from = "RawAngebotsart")]
+#[non_exhaustive]
+pub enum Angebotsart {
+    /// Regular employment (ARBEIT)
+    Arbeit,
+    /// Self-employment (SELBSTAENDIGKEIT)
+    Selbstaendigkeit,
+    /// Apprenticeship/Dual study (AUSBILDUNG/Duales Studium)
+    Ausbildung,
+    /// Internship/Trainee (Praktikum/Trainee)
+    PraktikumTrainee,
+    /// An offer type not modeled yet, with the raw code from the API
+    ///
+    /// Only produced for codes that don't match a known variant; `Unknown("1")` built by
+    /// hand is not equal to [`Angebotsart::Arbeit`].
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Accepted shape of [`Angebotsart`]: a numeric code, or a code or name as string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAngebotsart {
+    Code(u64),
+    Text(String),
+}
+
+impl From<RawAngebotsart> for Angebotsart {
+    fn from(raw: RawAngebotsart) -> Self {
+        match raw {
+            RawAngebotsart::Code(code) => Self::from_code(&code.to_string()),
+            RawAngebotsart::Text(text) => Self::from_code(&text),
+        }
+    }
+}
+
+impl Angebotsart {
+    /// The API code, e.g. `"1"`; the raw code for [`Angebotsart::Unknown`]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Arbeit => "1",
+            Self::Selbstaendigkeit => "2",
+            Self::Ausbildung => "4",
+            Self::PraktikumTrainee => "34",
+            Self::Unknown(code) => code,
+        }
+    }
+
+    /// Like [`FromStr`], but codes that match no variant become [`Angebotsart::Unknown`]
+    ///
+    /// ```
+    /// use jobsuche::Angebotsart;
+    ///
+    /// assert_eq!(Angebotsart::from_code("4"), Angebotsart::Ausbildung);
+    /// assert_eq!(Angebotsart::from_code("5").as_str(), "5");
+    /// ```
+    pub fn from_code(code: &str) -> Angebotsart {
+        code.parse()
+            .unwrap_or_else(|_| Self::Unknown(code.trim().to_string()))
+    }
+
+    /// German name as used by the API, e.g. `Ausbildung/Duales Studium`
+    ///
+    /// `Unbekannt` for [`Angebotsart::Unknown`].
+    pub fn german_name(&self) -> &'static str {
+        match self {
+            Self::Unknown(_) => "Unbekannt",
+            known => display_name(Self::ALIASES, known),
+        }
+    }
+
+    /// English name, e.g. `Apprenticeship/Dual study`
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Self::Arbeit => "Employment",
+            Self::Selbstaendigkeit => "Self-employment",
+            Self::Ausbildung => "Apprenticeship/Dual study",
+            Self::PraktikumTrainee => "Internship/Trainee",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+
+    const ALIASES: &'static [(Self, &'static [&'static str])] = &[
+        (Self::Arbeit, &["1", "Arbeit"]),
+        (
+            Self::Selbstaendigkeit,
+            &["2", "Selbständigkeit", "Selbstaendigkeit"],
+        ),
+        (
+            Self::Ausbildung,
+            &["4", "Ausbildung/Duales Studium", "Ausbildung"],
+        ),
+        (
+            Self::PraktikumTrainee,
+            &[
+                "34",
+                "Praktikum/Trainee",
+                "PraktikumTrainee",
+                "PRAKTIKUM_TRAINEE",
+            ],
+        ),
+    ];
+}
+
+/// Parses the API code (`"1"`) or the name (`"Arbeit"`), ignoring case
+impl FromStr for Angebotsart {
+    type Err = ParseEnumError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_enum("Angebotsart", Self::ALIASES, value)
+    }
+}
+
+/// Prints the German name, e.g. `Ausbildung/Duales Studium`, or the raw code of an
+/// unknown offer type
+impl fmt::Display for Angebotsart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(code) => f.write_str(code),
+            known => f.write_str(known.german_name()),
+        }
+    }
+}
+
+impl TryFrom<u8> for Angebotsart {
+    type Error = ParseEnumError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        code.to_string().parse()
+    }
+}
+
+/// Contract type (befristung)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+#[non_exhaustive]
 pub enum Befristung {
     /// Fixed-term contract (befristet)
     Befristet = 1,
@@ -265,17 +1870,60 @@ pub enum Befristung {
     Unbefristet = 2,
 }
 
-impl Befristung {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Self::Befristet => "1",
-            Self::Unbefristet => "2",
-        }
+impl Befristung {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Befristet => "1",
+            Self::Unbefristet => "2",
+        }
+    }
+
+    /// German name as used by the API, e.g. `Unbefristet`
+    pub fn german_name(&self) -> &'static str {
+        display_name(Self::ALIASES, self)
+    }
+
+    /// English name, e.g. `Permanent`
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Self::Befristet => "Fixed-term",
+            Self::Unbefristet => "Permanent",
+        }
+    }
+
+    const ALIASES: &'static [(Self, &'static [&'static str])] = &[
+        (Self::Befristet, &["1", "Befristet"]),
+        (Self::Unbefristet, &["2", "Unbefristet"]),
+    ];
+}
+
+/// Parses the API code (`"2"`) or the name (`"unbefristet"`), ignoring case
+impl FromStr for Befristung {
+    type Err = ParseEnumError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_enum("Befristung", Self::ALIASES, value)
+    }
+}
+
+/// Prints the German name, e.g. `Unbefristet`
+impl fmt::Display for Befristung {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.german_name())
+    }
+}
+
+impl TryFrom<u8> for Befristung {
+    type Error = ParseEnumError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        code.to_string().parse()
     }
 }
 
 /// Working time models
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum Arbeitszeit {
     /// Full-time (VOLLZEIT)
     Vollzeit,
@@ -299,6 +1947,106 @@ impl Arbeitszeit {
             Self::Minijob => "mj",
         }
     }
+
+    /// German name as used by the API, e.g. `Heim-/Telearbeit`
+    pub fn german_name(&self) -> &'static str {
+        display_name(Self::ALIASES, self)
+    }
+
+    /// English name, e.g. `Remote work`
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Self::Vollzeit => "Full-time",
+            Self::Teilzeit => "Part-time",
+            Self::SchichtNachtarbeitWochenende => "Shift/Night/Weekend work",
+            Self::HeimTelearbeit => "Remote work",
+            Self::Minijob => "Mini job",
+        }
+    }
+
+    const ALIASES: &'static [(Self, &'static [&'static str])] = &[
+        (Self::Vollzeit, &["vz", "Vollzeit"]),
+        (Self::Teilzeit, &["tz", "Teilzeit"]),
+        (
+            Self::SchichtNachtarbeitWochenende,
+            &[
+                "snw",
+                "Schicht/Nachtarbeit/Wochenende",
+                "SchichtNachtarbeitWochenende",
+                "SCHICHT_NACHTARBEIT_WOCHENENDE",
+            ],
+        ),
+        (
+            Self::HeimTelearbeit,
+            &[
+                "ho",
+                "Heim-/Telearbeit",
+                "HeimTelearbeit",
+                "HEIM_TELEARBEIT",
+            ],
+        ),
+        (Self::Minijob, &["mj", "Minijob"]),
+    ];
+}
+
+/// Parses the API code (`"vz"`), the name (`"Vollzeit"`) or the constant used in job
+/// details (`"HEIM_TELEARBEIT"`), ignoring case
+impl FromStr for Arbeitszeit {
+    type Err = ParseEnumError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_enum("Arbeitszeit", Self::ALIASES, value)
+    }
+}
+
+/// Prints the German name, e.g. `Heim-/Telearbeit`
+impl fmt::Display for Arbeitszeit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.german_name())
+    }
+}
+
+/// A string could not be parsed into one of the filter enums
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid {kind} {value:?}, expected one of: {}", .accepted.join(", "))]
+pub struct ParseEnumError {
+    /// Name of the enum, e.g. `Arbeitszeit`
+    pub kind: &'static str,
+    /// The rejected input
+    pub value: String,
+    /// API codes and names that would have been accepted
+    pub accepted: Vec<&'static str>,
+}
+
+/// Look up `value` among the aliases of each variant, ignoring case and surrounding whitespace
+///
+/// By convention the first alias is the API code and the second the German display name.
+fn parse_enum<T: Clone>(
+    kind: &'static str,
+    aliases: &[(T, &[&'static str])],
+    value: &str,
+) -> Result<T, ParseEnumError> {
+    let wanted = value.trim().to_lowercase();
+    aliases
+        .iter()
+        .find(|(_, names)| names.iter().any(|name| name.to_lowercase() == wanted))
+        .map(|(variant, _)| variant.clone())
+        .ok_or_else(|| ParseEnumError {
+            kind,
+            value: value.to_string(),
+            accepted: aliases
+                .iter()
+                .flat_map(|(_, names)| names.iter().take(2).copied())
+                .collect(),
+        })
+}
+
+fn display_name<T: PartialEq>(aliases: &[(T, &[&'static str])], variant: &T) -> &'static str {
+    aliases
+        .iter()
+        .find(|(candidate, _)| candidate == variant)
+        .map(|(_, names)| names[1])
+        .expect("every variant has aliases")
 }
 
 #[cfg(test)]
@@ -354,6 +2102,277 @@ mod tests {
         assert_eq!(response.size, Some(10));
     }
 
+    #[test]
+    fn test_facetten_deserialization() {
+        let response: JobSearchResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/search-facets.json")).unwrap();
+        let facetten = response.facetten.unwrap();
+
+        assert_eq!(facetten.arbeitszeit_count(Arbeitszeit::Vollzeit), 1087);
+        assert_eq!(facetten.arbeitszeit_count(Arbeitszeit::HeimTelearbeit), 12);
+        assert_eq!(facetten.befristung_count(Befristung::Unbefristet), 1015);
+        assert_eq!(facetten.angebotsart_count(Angebotsart::Ausbildung), 44);
+        assert_eq!(facetten.angebotsart_count(Angebotsart::Selbstaendigkeit), 0);
+        assert_eq!(facetten.arbeitsort.as_ref().unwrap().max_count, 1211);
+        assert_eq!(
+            facetten.veroeffentlichtseit.as_ref().unwrap().count("7"),
+            388
+        );
+        assert_eq!(
+            facetten.get("externestellenboersen").unwrap().count("true"),
+            254
+        );
+
+        // Groups this crate does not model are kept
+        assert_eq!(facetten.other.len(), 2);
+        assert_eq!(facetten.get("behinderung").unwrap().count("true"), 19);
+        assert_eq!(facetten.other["eintrittsdatum"].max_count, 806);
+        assert!(facetten.get("unbekannt").is_none());
+    }
+
+    #[test]
+    fn test_typed_facets() {
+        let response: JobSearchResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/search-facets.json")).unwrap();
+
+        assert_eq!(response.facet_counts("arbeitsort").unwrap()["Potsdam"], 131);
+        assert_eq!(response.facet_counts("behinderung").unwrap()["true"], 19);
+        assert!(response.facet_counts("unbekannt").is_none());
+
+        let arbeitszeit = response.arbeitszeit_facet().unwrap();
+        assert_eq!(
+            arbeitszeit.into_iter().collect::<Vec<_>>(),
+            [
+                (Arbeitszeit::Vollzeit, 1087),
+                (Arbeitszeit::Teilzeit, 512),
+                (Arbeitszeit::SchichtNachtarbeitWochenende, 403),
+                (Arbeitszeit::HeimTelearbeit, 12),
+                (Arbeitszeit::Minijob, 96),
+            ]
+        );
+        let befristung = response.befristung_facet().unwrap();
+        assert_eq!(befristung[&Befristung::Befristet], 221);
+        assert_eq!(befristung[&Befristung::Unbefristet], 1015);
+        let angebotsart = response.angebotsart_facet().unwrap();
+        assert_eq!(
+            angebotsart.keys().cloned().collect::<Vec<_>>(),
+            [Angebotsart::Arbeit, Angebotsart::Ausbildung]
+        );
+        assert_eq!(angebotsart[&Angebotsart::Ausbildung], 44);
+    }
+
+    #[test]
+    fn test_typed_facets_drop_unknown_values() {
+        let response: JobSearchResponse = serde_json::from_str(
+            r#"{
+                "stellenangebote": [],
+                "facetten": {"arbeitszeit": {"counts": {"vz": 3, "kurzarbeit": 2}}}
+            }"#,
+        )
+        .unwrap();
+        let arbeitszeit = response.arbeitszeit_facet().unwrap();
+        assert_eq!(arbeitszeit.len(), 1);
+        assert_eq!(arbeitszeit[&Arbeitszeit::Vollzeit], 3);
+        assert_eq!(response.facet_counts("arbeitszeit").unwrap().len(), 2);
+        assert!(response.befristung_facet().is_none());
+
+        let without: JobSearchResponse =
+            serde_json::from_str(r#"{"stellenangebote": []}"#).unwrap();
+        assert!(without.facet_counts("arbeitszeit").is_none());
+        assert!(without.arbeitszeit_facet().is_none());
+    }
+
+    #[test]
+    fn test_unknown_offer_type_codes() {
+        let response: JobSearchResponse = serde_json::from_str(
+            r#"{
+                "stellenangebote": [],
+                "facetten": {"angebotsart": {"counts": {"1": 10, "4": 3, "5": 2}}}
+            }"#,
+        )
+        .unwrap();
+        // Kept in the raw counts and, as Unknown, in the typed view
+        assert_eq!(response.facet_counts("angebotsart").unwrap()["5"], 2);
+        let angebotsart = response.angebotsart_facet().unwrap();
+        assert_eq!(angebotsart.len(), 3);
+        assert_eq!(angebotsart[&Angebotsart::Ausbildung], 3);
+        let unknown = Angebotsart::Unknown("5".to_string());
+        assert_eq!(angebotsart[&unknown], 2);
+        assert_eq!(unknown.as_str(), "5");
+        assert_eq!(unknown.to_string(), "5");
+        assert_eq!(unknown.english_name(), "Unknown");
+
+        // Codes and names deserialize to known variants, anything else to Unknown
+        let decoded: Vec<Angebotsart> =
+            serde_json::from_str(r#"[1, "34", "Ausbildung", 5, "DUALES_STUDIUM"]"#).unwrap();
+        assert_eq!(
+            decoded,
+            [
+                Angebotsart::Arbeit,
+                Angebotsart::PraktikumTrainee,
+                Angebotsart::Ausbildung,
+                unknown.clone(),
+                Angebotsart::Unknown("DUALES_STUDIUM".to_string()),
+            ]
+        );
+        // Unknown codes are written back unchanged
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""5""#);
+        assert_eq!(
+            serde_json::to_string(&Angebotsart::Arbeit).unwrap(),
+            r#""Arbeit""#
+        );
+
+        let details: JobDetails =
+            serde_json::from_str(r#"{"stellenangebotsart": "DUALES_STUDIUM"}"#).unwrap();
+        assert_eq!(
+            details.stellenangebots_art.as_deref(),
+            Some("DUALES_STUDIUM")
+        );
+        assert!("5".parse::<Angebotsart>().is_err());
+    }
+
+    #[test]
+    fn test_facetten_tolerates_missing_parts() {
+        let facetten: Facetten =
+            serde_json::from_str(r#"{"arbeitsort": {}, "corona": {"counts": {"true": 3}}}"#)
+                .unwrap();
+        assert_eq!(facetten.arbeitsort, Some(FacetData::default()));
+        assert_eq!(facetten.arbeitszeit_count(Arbeitszeit::Teilzeit), 0);
+        assert_eq!(facetten.other["corona"].count("true"), 3);
+
+        let json = serde_json::to_value(&facetten).unwrap();
+        assert_eq!(json["corona"]["counts"]["true"], 3);
+        assert!(json.get("arbeitszeit").is_none());
+    }
+
+    #[test]
+    fn test_constructors() {
+        let location = WorkLocation::default()
+            .with_plz("10115")
+            .with_ort("Berlin")
+            .with_region("Berlin")
+            .with_land("Deutschland")
+            .with_koordinaten(Coordinates {
+                lat: 52.5321,
+                lon: 13.3849,
+            });
+        let listing = JobListing::new("10000-1197745438-S", location)
+            .with_beruf("Koch/Köchin")
+            .with_titel("Koch (m/w/d) in Vollzeit")
+            .with_arbeitgeber("Restaurant am Spreeufer GmbH")
+            .with_veroeffentlichungsdatum("2026-03-24");
+        let mut response = JobSearchResponse::new(vec![listing.clone()])
+            .with_max_ergebnisse(1342)
+            .with_page(1, 1);
+
+        // Same as the fixture apart from the fields not set here
+        let fixture: JobSearchResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/search-facets.json")).unwrap();
+        let mut expected = fixture.stellenangebote[0].clone();
+        expected.modifikations_timestamp = None;
+        expected.eintrittsdatum = None;
+        expected.kundennummer_hash = None;
+        assert_eq!(listing, expected);
+
+        response = response.with_facetten(fixture.facetten.clone().unwrap());
+        response.stellenangebote = vec![fixture.stellenangebote[0].clone()];
+        assert_eq!(response, fixture);
+
+        let external = JobListing::new("10001-EXT-S", WorkLocation::default())
+            .with_externe_url("https://jobs.example.de/4711");
+        assert!(external.is_external());
+        assert_eq!(external.beruf, None);
+
+        let details = JobDetails::new("10001-MINIMAL-S");
+        assert_eq!(details.refnr.as_deref(), Some("10001-MINIMAL-S"));
+        assert!(details.arbeitsorte.is_empty());
+    }
+
+    #[test]
+    fn test_minimal_constructors() {
+        let berlin = Coordinates {
+            lat: 52.516,
+            lon: 13.38,
+        };
+        let listing =
+            JobListing::new_minimal("10001-1001601666-S", "Koch/Köchin", "Hotel Adlon", "Berlin")
+                .with_eintrittsdatum("2026-04-01")
+                .with_modifikations_timestamp("2026-03-20T10:15:30.123")
+                .with_koordinaten(berlin);
+
+        let expected: JobListing = serde_json::from_value(serde_json::json!({
+            "refnr": "10001-1001601666-S",
+            "beruf": "Koch/Köchin",
+            "arbeitgeber": "Hotel Adlon",
+            "eintrittsdatum": "2026-04-01",
+            "modifikationsTimestamp": "2026-03-20T10:15:30.123",
+            "arbeitsort": {"ort": "Berlin", "koordinaten": {"lat": 52.516, "lon": 13.38}},
+        }))
+        .unwrap();
+        assert_eq!(listing, expected);
+
+        let location = WorkLocation::default()
+            .with_strasse("Unter den Linden 77")
+            .with_entfernung("3");
+        assert_eq!(location.strasse.as_deref(), Some("Unter den Linden 77"));
+        assert_eq!(location.distance_km(), Some(3.0));
+
+        let details = JobDetails::new("10001-1001601666-S")
+            .with_titel("Koch (m/w/d)")
+            .with_arbeitgeber("Hotel Adlon")
+            .with_stellenangebots_art("ARBEIT")
+            .with_stellenbeschreibung("Wir suchen Verstärkung.")
+            .with_vertragsdauer("unbefristet")
+            .with_verguetung("nach Vereinbarung")
+            .with_externe_url("https://jobs.example.de/4711")
+            .with_arbeitsort(
+                JobLocation::new(LocationAddress {
+                    ort: Some("Berlin".to_string()),
+                    plz: Some("10117".to_string()),
+                    ..LocationAddress::default()
+                })
+                .with_koordinaten(berlin),
+            )
+            .with_arbeitsort(JobLocation::new(LocationAddress {
+                ort: Some("Potsdam".to_string()),
+                ..LocationAddress::default()
+            }));
+
+        assert_eq!(details.titel.as_deref(), Some("Koch (m/w/d)"));
+        assert_eq!(details.befristung(), Some(Befristung::Unbefristet));
+        assert_eq!(details.location_count(), 2);
+        assert_eq!(details.arbeitsorte[0].coordinates(), Some(berlin));
+        assert_eq!(details.arbeitsorte[1].coordinates(), None);
+        assert_eq!(
+            details.primary_location().unwrap().short(),
+            "Berlin (10117)"
+        );
+        assert!(details.external_url().is_some());
+    }
+
+    #[test]
+    fn test_response_types_compare_by_value() {
+        let json = include_str!("../tests/fixtures/search-facets.json");
+        let first: JobSearchResponse = serde_json::from_str(json).unwrap();
+        let second: JobSearchResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.stellenangebote[0], second.stellenangebote[0]);
+
+        let mut moved = second.stellenangebote[0].clone();
+        moved.arbeitsort.ort = Some("Potsdam".to_string());
+        assert_ne!(first.stellenangebote[0], moved);
+
+        // All-optional types can be built from defaults
+        let details = JobDetails {
+            refnr: Some("10001-MINIMAL-S".to_string()),
+            ..Default::default()
+        };
+        let parsed: JobDetails =
+            serde_json::from_str(r#"{"referenznummer": "10001-MINIMAL-S"}"#).unwrap();
+        assert_eq!(details, parsed);
+        assert_eq!(WorkLocation::default().ort(), None);
+    }
+
     #[test]
     fn test_job_listing_deserialization() {
         let json = r#"{
@@ -402,47 +2421,493 @@ mod tests {
     }
 
     #[test]
-    fn test_job_details_deserialization() {
-        let json = r#"{
-            "referenznummer": "10001-TEST-S",
-            "stellenangebotsTitel": "Senior Rust Engineer",
-            "firma": "Tech GmbH",
-            "stellenangebotsBeschreibung": "Great opportunity...",
-            "arbeitszeitVollzeit": true,
-            "vertragsdauer": "unbefristet",
-            "stellenlokationen": [
-                {
-                    "adresse": {
-                        "ort": "Hamburg",
-                        "plz": "20095"
-                    }
+    fn test_plz_parsing() {
+        for (input, expected) in [
+            ("10115", Some("10115")),
+            ("01067", Some("01067")),
+            (" 80331\t", Some("80331")),
+            ("1067", None),
+            ("", None),
+            ("101155", None),
+            ("1O115", None),
+            ("abcde", None),
+            ("10 115", None),
+            ("D-10115", None),
+        ] {
+            let parsed = input.parse::<Plz>();
+            assert_eq!(
+                parsed.as_ref().ok().map(Plz::as_str),
+                expected,
+                "{:?}",
+                input
+            );
+            if let Err(err) = parsed {
+                assert_eq!(err.value, input);
+            }
+        }
+
+        let plz: Plz = "50667".parse().unwrap();
+        assert_eq!(plz.to_string(), "50667");
+        assert_eq!(
+            "1067".parse::<Plz>().unwrap_err().to_string(),
+            "Invalid postal code \"1067\", expected five digits"
+        );
+    }
+
+    #[test]
+    fn test_plz_region_hint() {
+        let region = |plz: &str| plz.parse::<Plz>().unwrap().region_hint();
+        assert_eq!(region("01067"), "Sachsen, Sachsen-Anhalt, Thüringen");
+        assert_eq!(
+            region("20095"),
+            "Hamburg, Schleswig-Holstein, Bremen, Niedersachsen (Nord)"
+        );
+        assert_eq!(region("70173"), "Baden-Württemberg");
+        assert_eq!(region("90402"), "Bayern (Nord), Thüringen (Süd)");
+    }
+
+    #[test]
+    fn test_plz_typed_accessors() {
+        assert_eq!(
+            WorkLocation::default().with_plz(" 10115").plz_typed(),
+            Some("10115".parse().unwrap())
+        );
+        assert_eq!(WorkLocation::default().with_plz("1011").plz_typed(), None);
+        assert_eq!(WorkLocation::default().plz_typed(), None);
+
+        let mut address = Address {
+            land: "Deutschland".to_string(),
+            region: "Bayern".to_string(),
+            plz: Some("80331".to_string()),
+            ort: "München".to_string(),
+            strasse: None,
+            strasse_hausnummer: None,
+        };
+        assert_eq!(address.plz_typed().unwrap().as_str(), "80331");
+        address.plz = Some("8O331".to_string());
+        assert_eq!(address.plz_typed(), None);
+    }
+
+    #[test]
+    fn test_coordinates_lenient() {
+        let expected = Coordinates {
+            lat: 52.52,
+            lon: 13.405,
+        };
+        for koordinaten in [
+            r#"{"lat": 52.52, "lon": 13.405}"#,
+            r#"{"lat": "52.52", "lon": "13.405"}"#,
+            r#"{"lat": " 52,52 ", "lon": "13,405"}"#,
+            r#"{"lat": 52.52, "lon": "13,405"}"#,
+        ] {
+            let json = format!(r#"{{"ort": "Berlin", "koordinaten": {}}}"#, koordinaten);
+            let location: WorkLocation = serde_json::from_str(&json).unwrap();
+            assert_eq!(location.koordinaten, Some(expected), "{}", koordinaten);
+        }
+
+        // Unreadable coordinates are dropped, the rest of the location is kept
+        for koordinaten in [
+            "null",
+            r#"{"lat": null, "lon": null}"#,
+            r#"{"lat": "", "lon": "13.405"}"#,
+            r#"{"lat": "nördlich", "lon": "13.405"}"#,
+            r#"{"lat": "NaN", "lon": "inf"}"#,
+            r#"{"lat": 52.52}"#,
+            r#""52.52,13.405""#,
+        ] {
+            let json = format!(r#"{{"ort": "Berlin", "koordinaten": {}}}"#, koordinaten);
+            let location: WorkLocation = serde_json::from_str(&json).unwrap();
+            assert_eq!(location.koordinaten, None, "{}", koordinaten);
+            assert_eq!(location.ort.as_deref(), Some("Berlin"));
+        }
+
+        // Written back as numbers
+        assert_eq!(
+            serde_json::to_value(expected).unwrap(),
+            serde_json::json!({"lat": 52.52, "lon": 13.405})
+        );
+    }
+
+    #[test]
+    fn test_coordinates_lenient_in_search_response() {
+        let response: JobSearchResponse = serde_json::from_str(
+            r#"{"stellenangebote": [
+                {"refnr": "10000-1-S", "arbeitsort": {"koordinaten": {"lat": "48,137", "lon": "11,575"}}},
+                {"refnr": "10000-2-S", "arbeitsort": {"koordinaten": {"lat": "unbekannt", "lon": 0}}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(response.stellenangebote.len(), 2);
+        assert_eq!(
+            response.stellenangebote[0].arbeitsort.koordinaten,
+            Some(Coordinates {
+                lat: 48.137,
+                lon: 11.575
+            })
+        );
+        assert_eq!(response.stellenangebote[1].arbeitsort.koordinaten, None);
+
+        // Details locations read coordinates the same way
+        let location: JobLocation = serde_json::from_str(
+            r#"{"ort": "Berlin", "koordinaten": {"lat": "52,52", "lon": "13,405"}}"#,
+        )
+        .unwrap();
+        assert_eq!(location.breite, Some(52.52));
+        assert_eq!(location.laenge, Some(13.405));
+    }
+
+    #[test]
+    fn test_job_details_deserialization() {
+        let json = r#"{
+            "referenznummer": "10001-TEST-S",
+            "stellenangebotsTitel": "Senior Rust Engineer",
+            "firma": "Tech GmbH",
+            "stellenangebotsBeschreibung": "Great opportunity...",
+            "arbeitszeitVollzeit": true,
+            "vertragsdauer": "unbefristet",
+            "stellenlokationen": [
+                {
+                    "adresse": {
+                        "ort": "Hamburg",
+                        "plz": "20095"
+                    }
+                }
+            ]
+        }"#;
+
+        let details: JobDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(details.refnr, Some("10001-TEST-S".to_string()));
+        assert_eq!(details.titel, Some("Senior Rust Engineer".to_string()));
+        assert_eq!(details.arbeitgeber, Some("Tech GmbH".to_string()));
+        assert_eq!(
+            details.stellenbeschreibung,
+            Some("Great opportunity...".to_string())
+        );
+        assert_eq!(details.arbeitszeit_vollzeit, Some(true));
+        assert_eq!(details.arbeitsorte.len(), 1);
+    }
+
+    #[test]
+    fn test_job_details_arbeitszeit_modelle() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{
+                "arbeitszeitVollzeit": true,
+                "arbeitszeitTeilzeitVormittag": false,
+                "arbeitszeitTeilzeitNachmittag": false,
+                "arbeitszeitTeilzeitAbend": true,
+                "arbeitszeitTeilzeitFlexibel": false,
+                "arbeitszeitSchichtNachtWochenende": true,
+                "istGeringfuegigeBeschaeftigung": false
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(details.arbeitszeit_teilzeit_abend, Some(true));
+        assert_eq!(
+            details.arbeitszeit_modelle(),
+            vec![
+                Arbeitszeit::Vollzeit,
+                Arbeitszeit::Teilzeit,
+                Arbeitszeit::SchichtNachtarbeitWochenende
+            ]
+        );
+
+        let details: JobDetails = serde_json::from_str(
+            r#"{"arbeitszeitTeilzeitFlexibel": true, "istGeringfuegigeBeschaeftigung": true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            details.arbeitszeit_modelle(),
+            vec![Arbeitszeit::Teilzeit, Arbeitszeit::Minijob]
+        );
+
+        let details: JobDetails = serde_json::from_str("{}").unwrap();
+        assert!(details.arbeitszeit_modelle().is_empty());
+    }
+
+    #[test]
+    fn test_job_details_befristung() {
+        let befristung = |vertragsdauer: Option<&str>| {
+            serde_json::from_value::<JobDetails>(
+                serde_json::json!({ "vertragsdauer": vertragsdauer }),
+            )
+            .unwrap()
+            .befristung()
+        };
+
+        assert_eq!(
+            befristung(Some("unbefristet")),
+            Some(Befristung::Unbefristet)
+        );
+        assert_eq!(
+            befristung(Some("UNBEFRISTET")),
+            Some(Befristung::Unbefristet)
+        );
+        assert_eq!(befristung(Some("befristet")), Some(Befristung::Befristet));
+        assert_eq!(befristung(Some("Befristet")), Some(Befristung::Befristet));
+        assert_eq!(befristung(None), None);
+        assert_eq!(befristung(Some("")), None);
+        assert_eq!(befristung(Some("12 Monate")), None);
+    }
+
+    #[test]
+    fn test_job_details_locations_nested_and_flat() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{
+                "referenznummer": "10000-1197745438-S",
+                "stellenlokationen": [
+                    {
+                        "adresse": {
+                            "plz": "10115",
+                            "ort": "Berlin",
+                            "region": "Berlin",
+                            "land": "Deutschland"
+                        },
+                        "breite": 52.5321,
+                        "laenge": 13.3849
+                    },
+                    {
+                        "plz": "14467",
+                        "ort": "Potsdam",
+                        "koordinaten": {"lat": 52.3906, "lon": 13.0645}
+                    },
+                    {}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let [nested, flat, empty] = &details.arbeitsorte[..] else {
+            panic!("expected three locations");
+        };
+        assert_eq!(nested.ort(), Some("Berlin"));
+        assert_eq!(nested.plz(), Some("10115"));
+        assert_eq!(
+            nested.coordinates(),
+            Some(Coordinates {
+                lat: 52.5321,
+                lon: 13.3849
+            })
+        );
+        assert_eq!(flat.ort(), Some("Potsdam"));
+        assert_eq!(flat.plz(), Some("14467"));
+        assert_eq!(flat.coordinates().unwrap().lon, 13.0645);
+        assert!(empty.adresse.is_none());
+        assert_eq!(empty.ort(), None);
+        assert_eq!(empty.coordinates(), None);
+
+        // Serializes in the nested shape and reads back the same
+        let json = serde_json::to_value(flat).unwrap();
+        assert_eq!(json["adresse"]["ort"], "Potsdam");
+        let back: JobLocation = serde_json::from_value(json).unwrap();
+        assert_eq!(back.ort(), Some("Potsdam"));
+        assert_eq!(back.coordinates(), flat.coordinates());
+    }
+
+    #[test]
+    fn test_search_location_accessors() {
+        let response: JobSearchResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/search-facets.json")).unwrap();
+        let location = &response.stellenangebote[0].arbeitsort;
+        assert_eq!(location.ort(), Some("Berlin"));
+        assert_eq!(location.plz(), Some("10115"));
+        assert_eq!(
+            location.coordinates(),
+            Some(Coordinates {
+                lat: 52.5321,
+                lon: 13.3849
+            })
+        );
+    }
+
+    #[test]
+    fn test_job_details_optional_fields() {
+        let json = r#"{
+            "referenznummer": "10001-MINIMAL-S"
+        }"#;
+
+        let details: JobDetails = serde_json::from_str(json).unwrap();
+        assert_eq!(details.refnr, Some("10001-MINIMAL-S".to_string()));
+        assert_eq!(details.titel, None);
+        assert_eq!(details.arbeitgeber, None);
+        assert_eq!(details.arbeitszeit_vollzeit, None);
+    }
+
+    #[test]
+    fn test_job_details_full_fixture() {
+        let details: JobDetails =
+            serde_json::from_str(include_str!("../tests/fixtures/job-details-full.json")).unwrap();
+
+        assert_eq!(details.refnr.as_deref(), Some("10001-1001601666-S"));
+        assert_eq!(details.ist_arbeitnehmer_ueberlassung, Some(false));
+        assert_eq!(
+            details.geforderter_bildungsabschluss.as_deref(),
+            Some("Abitur / Hochschulreife")
+        );
+        assert_eq!(details.ausbildungsart.as_deref(), Some("Duales Studium"));
+        assert_eq!(details.studiengang.as_deref(), Some("Informatik"));
+        assert_eq!(
+            details.studienform.as_deref(),
+            Some("dual (ausbildungsintegrierend)")
+        );
+        assert_eq!(
+            details.betriebsgroesse_typed(),
+            Some(Betriebsgroesse::Zwischen51Und500)
+        );
+        assert_eq!(
+            details.external_url().unwrap().as_str(),
+            "https://jobs.example.de/duales-studium-informatik"
+        );
+
+        let adresse = details.arbeitsorte[0].adresse.as_ref().unwrap();
+        assert_eq!(adresse.strasse.as_deref(), Some("Invalidenstraße"));
+        assert_eq!(adresse.hausnummer.as_deref(), Some("117"));
+        assert_eq!(details.arbeitsorte[0].ort(), Some("Berlin"));
+
+        // Serializes back under the API's field names
+        let json = serde_json::to_value(&details).unwrap();
+        assert_eq!(
+            json["externeURL"],
+            "https://jobs.example.de/duales-studium-informatik"
+        );
+        assert_eq!(
+            json["geforderterBildungsabschluss"],
+            "Abitur / Hochschulreife"
+        );
+    }
+
+    #[test]
+    fn test_job_details_minimal_fixture() {
+        let details: JobDetails =
+            serde_json::from_str(include_str!("../tests/fixtures/job-details-minimal.json"))
+                .unwrap();
+
+        assert_eq!(details.refnr.as_deref(), Some("10001-1001601667-S"));
+        assert!(details.arbeitsorte.is_empty());
+        assert_eq!(details.externe_url, None);
+        assert_eq!(details.external_url(), None);
+        assert_eq!(details.ausbildungsart, None);
+        assert_eq!(details.studiengang, None);
+        assert_eq!(details.ist_arbeitnehmer_ueberlassung, None);
+    }
+
+    #[test]
+    fn test_job_details_externe_url_alias() {
+        let details: JobDetails =
+            serde_json::from_str(r#"{"externeUrl": "https://jobs.example.de/4711"}"#).unwrap();
+        assert_eq!(
+            details.externe_url.as_deref(),
+            Some("https://jobs.example.de/4711")
+        );
+    }
+
+    fn job_location(ort: &str, plz: &str) -> JobLocation {
+        JobLocation {
+            adresse: Some(LocationAddress {
+                ort: Some(ort.to_string()),
+                plz: Some(plz.to_string()),
+                ..LocationAddress::default()
+            }),
+            ..JobLocation::default()
+        }
+    }
+
+    fn employer_address() -> Address {
+        Address {
+            land: "Deutschland".to_string(),
+            region: "".to_string(),
+            plz: Some("20095".to_string()),
+            ort: "Hamburg".to_string(),
+            strasse: Some("Mönckebergstraße".to_string()),
+            strasse_hausnummer: Some("Mönckebergstraße 7".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_primary_location_none() {
+        let details = JobDetails::default();
+        assert_eq!(details.primary_location(), None);
+        assert_eq!(details.location_count(), 0);
+        assert!(!details.is_multi_location());
+    }
+
+    #[test]
+    fn test_primary_location_single() {
+        let details = JobDetails {
+            arbeitsorte: vec![JobLocation {
+                breite: Some(52.5321),
+                laenge: Some(13.3849),
+                ..job_location("Berlin", "10115")
+            }],
+            arbeitgeber_adresse: Some(employer_address()),
+            ..JobDetails::default()
+        };
+
+        let location = details.primary_location().unwrap();
+        assert_eq!(location.short(), "Berlin (10115)");
+        assert_eq!(
+            location.koordinaten,
+            Some(Coordinates {
+                lat: 52.5321,
+                lon: 13.3849
+            })
+        );
+        assert_eq!(details.location_count(), 1);
+        assert!(!details.is_multi_location());
+    }
+
+    #[test]
+    fn test_primary_location_multiple() {
+        let details = JobDetails {
+            arbeitsorte: vec![job_location("Köln", "50667"), job_location("Bonn", "53111")],
+            ..JobDetails::default()
+        };
+
+        assert_eq!(details.primary_location().unwrap().short(), "Köln (50667)");
+        assert_eq!(details.location_count(), 2);
+        assert!(details.is_multi_location());
+    }
+
+    #[test]
+    fn test_primary_location_falls_back_to_employer_address() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{
+                "stellenlokationen": [],
+                "arbeitgeberAdresse": {
+                    "land": "Deutschland",
+                    "plz": "20095",
+                    "ort": "Hamburg",
+                    "strasse": "Mönckebergstraße",
+                    "strasseHausnummer": "Mönckebergstraße 7"
                 }
-            ]
-        }"#;
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(details.arbeitgeber_adresse, Some(employer_address()));
 
-        let details: JobDetails = serde_json::from_str(json).unwrap();
-        assert_eq!(details.refnr, Some("10001-TEST-S".to_string()));
-        assert_eq!(details.titel, Some("Senior Rust Engineer".to_string()));
-        assert_eq!(details.arbeitgeber, Some("Tech GmbH".to_string()));
+        let location = details.primary_location().unwrap();
         assert_eq!(
-            details.stellenbeschreibung,
-            Some("Great opportunity...".to_string())
+            location,
+            WorkLocation::default()
+                .with_plz("20095")
+                .with_ort("Hamburg")
+                .with_land("Deutschland")
+                .with_strasse("Mönckebergstraße 7")
         );
-        assert_eq!(details.arbeitszeit_vollzeit, Some(true));
-        assert_eq!(details.arbeitsorte.len(), 1);
+        assert_eq!(
+            location.to_string(),
+            "Mönckebergstraße 7, 20095 Hamburg, Deutschland"
+        );
+        assert_eq!(details.location_count(), 0);
     }
 
     #[test]
-    fn test_job_details_optional_fields() {
-        let json = r#"{
-            "referenznummer": "10001-MINIMAL-S"
-        }"#;
-
-        let details: JobDetails = serde_json::from_str(json).unwrap();
-        assert_eq!(details.refnr, Some("10001-MINIMAL-S".to_string()));
-        assert_eq!(details.titel, None);
-        assert_eq!(details.arbeitgeber, None);
-        assert_eq!(details.arbeitszeit_vollzeit, None);
+    fn test_work_location_from_address() {
+        let mut address = employer_address();
+        address.strasse_hausnummer = Some(" ".to_string());
+        let location = WorkLocation::from(address);
+        assert_eq!(location.strasse.as_deref(), Some("Mönckebergstraße"));
+        assert_eq!(location.region, None);
+        assert_eq!(location.koordinaten, None);
     }
 
     #[test]
@@ -462,6 +2927,108 @@ mod tests {
         assert_eq!(address.ort, "München");
     }
 
+    fn full_work_location() -> WorkLocation {
+        WorkLocation::default()
+            .with_strasse("Musterstraße 1")
+            .with_plz("10115")
+            .with_ort("Berlin")
+            .with_region("Berlin")
+            .with_land("Deutschland")
+    }
+
+    #[test]
+    fn test_work_location_display_full() {
+        let location = full_work_location();
+        assert_eq!(
+            location.to_string(),
+            "Musterstraße 1, 10115 Berlin, Berlin, Deutschland"
+        );
+        assert_eq!(location.short(), "Berlin (10115)");
+    }
+
+    #[test]
+    fn test_work_location_display_partial() {
+        let location = WorkLocation::default()
+            .with_ort("Köln")
+            .with_land("Deutschland");
+        assert_eq!(location.to_string(), "Köln, Deutschland");
+        assert_eq!(location.short(), "Köln");
+
+        let location = WorkLocation::default()
+            .with_plz("80331")
+            .with_region("Bayern");
+        assert_eq!(location.to_string(), "80331, Bayern");
+        assert_eq!(location.short(), "80331");
+
+        // Blank values count as missing
+        let location = WorkLocation {
+            strasse: Some("  ".to_string()),
+            ..WorkLocation::default().with_ort(" Hamburg ").with_plz("")
+        };
+        assert_eq!(location.to_string(), "Hamburg");
+        assert_eq!(location.short(), "Hamburg");
+    }
+
+    #[test]
+    fn test_work_location_display_empty() {
+        let location = WorkLocation::default();
+        assert_eq!(location.to_string(), "");
+        assert_eq!(location.short(), "");
+    }
+
+    #[test]
+    fn test_address_display() {
+        let mut address = Address {
+            land: "Deutschland".to_string(),
+            region: "Bayern".to_string(),
+            plz: Some("80331".to_string()),
+            ort: "München".to_string(),
+            strasse: Some("Hauptstraße".to_string()),
+            strasse_hausnummer: Some("Hauptstraße 123".to_string()),
+        };
+        assert_eq!(
+            address.to_string(),
+            "Hauptstraße 123, 80331 München, Bayern, Deutschland"
+        );
+        assert_eq!(address.short(), "München (80331)");
+
+        address.strasse_hausnummer = None;
+        address.plz = None;
+        address.region = String::new();
+        assert_eq!(address.to_string(), "Hauptstraße, München, Deutschland");
+        assert_eq!(address.short(), "München");
+
+        let empty = Address {
+            land: String::new(),
+            region: String::new(),
+            plz: None,
+            ort: String::new(),
+            strasse: None,
+            strasse_hausnummer: None,
+        };
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(empty.short(), "");
+    }
+
+    #[test]
+    fn test_location_address_display() {
+        let address = LocationAddress {
+            strasse: Some("Invalidenstraße".to_string()),
+            hausnummer: Some("117".to_string()),
+            plz: Some("10115".to_string()),
+            ort: Some("Berlin".to_string()),
+            ..LocationAddress::default()
+        };
+        assert_eq!(address.to_string(), "Invalidenstraße 117, 10115 Berlin");
+
+        let address = LocationAddress {
+            hausnummer: Some("117".to_string()),
+            land: Some("Deutschland".to_string()),
+            ..LocationAddress::default()
+        };
+        assert_eq!(address.to_string(), "Deutschland");
+    }
+
     #[test]
     fn test_skill_deserialization() {
         let json = r#"{
@@ -481,6 +3048,107 @@ mod tests {
         assert!(auspraegungen.contains_key("tools"));
     }
 
+    #[test]
+    fn test_skill_values() {
+        let skill: Skill = serde_json::from_str(
+            r#"{
+                "hierarchieName": "Technical Skills",
+                "auspraegungen": {
+                    "tools": ["Git", "Docker"],
+                    "programming": ["Rust", "Python"]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            skill.values().collect::<Vec<_>>(),
+            [
+                ("programming", "Rust"),
+                ("programming", "Python"),
+                ("tools", "Git"),
+                ("tools", "Docker"),
+            ]
+        );
+        assert!(skill.requires("docker"));
+        assert!(skill.requires("TECHNICAL SKILLS"));
+        assert!(!skill.requires("Dock"));
+        assert!(!skill.requires("tools"));
+
+        let bare: Skill = serde_json::from_str(r#"{"hierarchieName": "Führerschein"}"#).unwrap();
+        assert_eq!(bare.values().count(), 0);
+        assert!(bare.requires("führerschein"));
+        assert!(!bare.requires("Rust"));
+    }
+
+    #[test]
+    fn test_skills_by_category() {
+        let details: JobDetails =
+            serde_json::from_str(include_str!("../tests/fixtures/job-details-full.json")).unwrap();
+        assert_eq!(details.fertigkeiten.len(), 4);
+
+        let categories = details.skills_by_category();
+        let expected: BTreeMap<String, Vec<String>> = [
+            ("(unspecified)", vec!["Teamfähigkeit", "Englisch"]),
+            ("Erweiterte Kenntnisse", vec!["Rust"]),
+            ("Expertenkenntnisse", vec!["Git"]),
+            ("Kenntnisse", vec!["Java", "SQL", "Linux"]),
+        ]
+        .into_iter()
+        .map(|(category, values)| {
+            (
+                category.to_string(),
+                values.into_iter().map(String::from).collect(),
+            )
+        })
+        .collect();
+        assert_eq!(categories, expected);
+
+        assert!(details.has_skill_in_category("kenntnisse", "sql"));
+        assert!(details.has_skill_in_category("Expertenkenntnisse", "GIT"));
+        assert!(details.has_skill_in_category(Skill::UNSPECIFIED_CATEGORY, "englisch"));
+        assert!(!details.has_skill_in_category("Kenntnisse", "Rust"));
+        assert!(!details.has_skill_in_category("Kenntnis", "Java"));
+    }
+
+    #[test]
+    fn test_skills_by_category_empty() {
+        let details = JobDetails::default();
+        assert!(details.skills_by_category().is_empty());
+        assert!(!details.has_skill_in_category("Kenntnisse", "Rust"));
+        assert_eq!(details.all_skills().count(), 0);
+        assert!(!details.requires("Rust"));
+    }
+
+    #[test]
+    fn test_all_skills_and_requires() {
+        let details: JobDetails =
+            serde_json::from_str(include_str!("../tests/fixtures/job-details-full.json")).unwrap();
+
+        assert_eq!(
+            details.all_skills().collect::<Vec<_>>(),
+            [
+                "Softwareentwicklung",
+                "Rust",
+                "Java",
+                "SQL",
+                "Betriebssysteme",
+                "Git",
+                "Linux",
+                "SQL",
+                "Teamfähigkeit",
+                "Englisch",
+            ]
+        );
+        assert!(details.requires("sql"));
+        assert!(details.requires("GIT"));
+        assert!(details.requires("teamfähigkeit"));
+        assert!(details.requires("betriebssysteme"));
+        // Categories are not skills
+        assert!(!details.requires("Kenntnisse"));
+        assert!(!details.requires("Docker"));
+    }
+
     #[test]
     fn test_mobility_deserialization() {
         let json = r#"{
@@ -491,6 +3159,331 @@ mod tests {
         assert_eq!(mobility.reisebereitschaft, Some("gelegentlich".to_string()));
     }
 
+    fn page_of(listings: usize) -> JobSearchResponse {
+        JobSearchResponse::new(
+            (0..listings)
+                .map(|i| JobListing::new(format!("10000-{}-S", i), WorkLocation::default()))
+                .collect(),
+        )
+    }
+
+    fn facetten(arbeitszeit: &[(&str, u64)], other: Option<(&str, u64)>) -> Facetten {
+        let group = |counts: &[(&str, u64)]| FacetData {
+            counts: counts
+                .iter()
+                .map(|(value, count)| (value.to_string(), *count))
+                .collect(),
+            max_count: counts.iter().map(|(_, count)| *count).max().unwrap_or(0),
+        };
+        Facetten {
+            arbeitszeit: Some(group(arbeitszeit)),
+            other: other
+                .map(|(value, count)| ("tarifvertrag".to_string(), group(&[(value, count)])))
+                .into_iter()
+                .collect(),
+            ..Facetten::default()
+        }
+    }
+
+    fn refnrs(response: &JobSearchResponse) -> Vec<&str> {
+        response
+            .stellenangebote
+            .iter()
+            .map(|listing| listing.refnr.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_overlapping_refnrs() {
+        let berlin = page_of(3).with_max_ergebnisse(3).with_page(1, 25);
+        let mut potsdam = page_of(4).with_max_ergebnisse(4);
+        potsdam.stellenangebote.remove(0);
+
+        let merged = berlin.merge(potsdam);
+        assert_eq!(
+            refnrs(&merged),
+            ["10000-0-S", "10000-1-S", "10000-2-S", "10000-3-S"]
+        );
+        assert_eq!(merged.page, None);
+        assert_eq!(merged.size, None);
+
+        // Duplicates within one response are dropped as well
+        let doubled = JobSearchResponse::new(vec![
+            JobListing::new("10000-0-S", WorkLocation::default().with_ort("Berlin")),
+            JobListing::new("10000-0-S", WorkLocation::default().with_ort("Potsdam")),
+        ]);
+        let merged = doubled.merge(JobSearchResponse::default());
+        assert_eq!(merged.stellenangebote.len(), 1);
+        assert_eq!(
+            merged.stellenangebote[0].arbeitsort.ort.as_deref(),
+            Some("Berlin")
+        );
+    }
+
+    #[test]
+    fn test_merge_max_ergebnisse() {
+        let merged = page_of(1)
+            .with_max_ergebnisse(120)
+            .merge(page_of(1).with_max_ergebnisse(80));
+        assert_eq!(merged.max_ergebnisse, Some(120));
+
+        let merged = page_of(1).merge(page_of(1).with_max_ergebnisse(80));
+        assert_eq!(merged.max_ergebnisse, Some(80));
+
+        assert_eq!(page_of(1).merge(page_of(1)).max_ergebnisse, None);
+    }
+
+    #[test]
+    fn test_merge_facets() {
+        let a = facetten(&[("vz", 10), ("tz", 2)], Some(("ja", 4)));
+        let b = facetten(&[("vz", 5), ("snw", 3)], Some(("ja", 1)));
+
+        // Pages of the same search report the same facets
+        let merged = page_of(1)
+            .with_facetten(a.clone())
+            .merge(page_of(1).with_facetten(a.clone()));
+        assert_eq!(merged.facetten, Some(a.clone()));
+
+        let merged = page_of(1)
+            .with_facetten(a.clone())
+            .merge(page_of(1).with_facetten(b));
+        let facetten = merged.facetten.unwrap();
+        let arbeitszeit = facetten.arbeitszeit.as_ref().unwrap();
+        assert_eq!(arbeitszeit.count("vz"), 15);
+        assert_eq!(arbeitszeit.count("tz"), 2);
+        assert_eq!(arbeitszeit.count("snw"), 3);
+        assert_eq!(arbeitszeit.max_count, 15);
+        assert_eq!(facetten.get("tarifvertrag").unwrap().count("ja"), 5);
+        assert_eq!(facetten.befristung, None);
+
+        // Facets of only one side would be misleading for the combined results
+        let merged = page_of(1).with_facetten(a).merge(page_of(1));
+        assert_eq!(merged.facetten, None);
+    }
+
+    #[test]
+    fn test_merge_all() {
+        assert_eq!(
+            JobSearchResponse::merge_all(Vec::new()),
+            JobSearchResponse::default()
+        );
+
+        let merged = JobSearchResponse::merge_all([
+            page_of(2).with_max_ergebnisse(5).with_page(1, 2),
+            page_of(4).with_max_ergebnisse(5).with_page(2, 2),
+            page_of(5).with_max_ergebnisse(5).with_page(3, 2),
+        ]);
+        assert_eq!(merged.stellenangebote.len(), 5);
+        assert_eq!(merged.max_ergebnisse, Some(5));
+
+        // A single response is returned unchanged
+        let single = page_of(2).with_max_ergebnisse(2).with_page(1, 25);
+        assert_eq!(JobSearchResponse::merge_all([single.clone()]), single);
+    }
+
+    #[test]
+    fn test_pagination_last_page_exactly_full() {
+        let options = SearchOptions::builder().was("Koch").size(25).build();
+
+        let first = page_of(25).with_max_ergebnisse(50).with_page(1, 25);
+        assert_eq!(first.total_pages(), Some(2));
+        assert!(first.has_next_page());
+        let next = first.next_options(&options).unwrap();
+        assert_eq!(next.page(), Some(2));
+        assert_eq!(next.size(), Some(25));
+        assert!(next.serialize().unwrap().contains("was=Koch"));
+
+        let last = page_of(25).with_max_ergebnisse(50).with_page(2, 25);
+        assert!(!last.has_next_page());
+        assert!(last.next_options(&next).is_none());
+    }
+
+    #[test]
+    fn test_pagination_last_page_partial() {
+        let last = page_of(10).with_max_ergebnisse(60).with_page(3, 25);
+        assert_eq!(last.total_pages(), Some(3));
+        assert!(!last.has_next_page());
+
+        // Without max_ergebnisse a partial page ends the results, a full one doesn't
+        let options = SearchOptions::builder().page(3).size(25).build();
+        let mut partial = page_of(10);
+        partial.size = Some(25);
+        assert_eq!(partial.total_pages(), None);
+        assert!(partial.next_options(&options).is_none());
+        assert_eq!(
+            page_of(25)
+                .next_options(&options)
+                .and_then(|next| next.page()),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_pagination_beyond_cap() {
+        let response = page_of(100).with_max_ergebnisse(50_000).with_page(99, 100);
+        assert_eq!(response.total_pages(), Some(100));
+        assert!(response.has_next_page());
+
+        let response = response.with_page(100, 100);
+        assert!(!response.has_next_page());
+        assert!(response.next_options(&SearchOptions::default()).is_none());
+    }
+
+    #[test]
+    fn test_pagination_missing_fields() {
+        let empty = JobSearchResponse::default();
+        assert_eq!(empty.total_pages(), None);
+        assert!(!empty.has_next_page());
+        assert!(empty.next_options(&SearchOptions::default()).is_none());
+
+        // Defaults to page 1 with 50 listings
+        let response = page_of(50).with_max_ergebnisse(120);
+        assert_eq!(response.total_pages(), None);
+        let next = response.next_options(&SearchOptions::default()).unwrap();
+        assert_eq!(next.page(), Some(2));
+        assert_eq!(next.size(), None);
+
+        // No results at all
+        let none = JobSearchResponse::default()
+            .with_max_ergebnisse(0)
+            .with_page(1, 50);
+        assert_eq!(none.total_pages(), Some(0));
+        assert!(!none.has_next_page());
+    }
+
+    #[test]
+    fn test_betriebsgroesse_buckets() {
+        let cases = [
+            ("bis 5", Betriebsgroesse::Bis5, Some(1), Some(5)),
+            ("1 bis 5", Betriebsgroesse::Bis5, Some(1), Some(5)),
+            (
+                "zwischen 6 und 50",
+                Betriebsgroesse::Zwischen6Und50,
+                Some(6),
+                Some(50),
+            ),
+            (
+                "zwischen 51 und 500",
+                Betriebsgroesse::Zwischen51Und500,
+                Some(51),
+                Some(500),
+            ),
+            (
+                "Zwischen  51 und 500 ",
+                Betriebsgroesse::Zwischen51Und500,
+                Some(51),
+                Some(500),
+            ),
+            (
+                "zwischen 501 und 5000",
+                Betriebsgroesse::Zwischen501Und5000,
+                Some(501),
+                Some(5000),
+            ),
+            ("über 5000", Betriebsgroesse::Ueber5000, Some(5001), None),
+            ("ueber 5000", Betriebsgroesse::Ueber5000, Some(5001), None),
+            (
+                "mehr als 5000",
+                Betriebsgroesse::Ueber5000,
+                Some(5001),
+                None,
+            ),
+            (
+                "keine Angabe",
+                Betriebsgroesse::Unknown("keine Angabe".to_string()),
+                None,
+                None,
+            ),
+        ];
+        for (value, expected, min, max) in cases {
+            let details = JobDetails {
+                betriebsgroesse: Some(value.to_string()),
+                ..JobDetails::default()
+            };
+            let size = details.betriebsgroesse_typed().unwrap();
+            assert_eq!(size, expected, "{:?}", value);
+            assert_eq!(size.min_employees(), min, "{:?}", value);
+            assert_eq!(size.max_employees(), max, "{:?}", value);
+        }
+
+        assert_eq!(JobDetails::default().betriebsgroesse_typed(), None);
+    }
+
+    #[test]
+    fn test_betriebsgroesse_serde() {
+        let details: JobDetails =
+            serde_json::from_str(r#"{"betriebsgroesse": "zwischen 6 und 50"}"#).unwrap();
+        assert_eq!(
+            details.betriebsgroesse.as_deref(),
+            Some("zwischen 6 und 50")
+        );
+
+        let size: Betriebsgroesse = serde_json::from_str(r#""über 5000""#).unwrap();
+        assert_eq!(size, Betriebsgroesse::Ueber5000);
+        assert_eq!(serde_json::to_string(&size).unwrap(), r#""über 5000""#);
+        assert!(Betriebsgroesse::Bis5 < Betriebsgroesse::Zwischen501Und5000);
+    }
+
+    #[test]
+    fn test_reisebereitschaft_typed() {
+        let cases = [
+            ("nicht erforderlich", Reisebereitschaft::NichtErforderlich),
+            ("gelegentlich", Reisebereitschaft::Gelegentlich),
+            ("häufig", Reisebereitschaft::Haeufig),
+            ("HAEUFIG", Reisebereitschaft::Haeufig),
+            ("NICHT_ERFORDERLICH", Reisebereitschaft::NichtErforderlich),
+            ("ständig", Reisebereitschaft::Unknown("ständig".to_string())),
+        ];
+        for (value, expected) in cases {
+            let json = format!(r#"{{"reisebereitschaft": "{}"}}"#, value);
+            let mobility: Mobility = serde_json::from_str(&json).unwrap();
+            assert_eq!(mobility.reisebereitschaft_typed(), Some(expected.clone()));
+
+            let direct: Reisebereitschaft =
+                serde_json::from_value(serde_json::json!(value)).unwrap();
+            assert_eq!(direct, expected);
+        }
+
+        assert_eq!(Mobility::default().reisebereitschaft_typed(), None);
+    }
+
+    #[test]
+    fn test_reisebereitschaft_serialization() {
+        assert_eq!(
+            serde_json::to_value(Reisebereitschaft::Haeufig).unwrap(),
+            serde_json::json!("häufig")
+        );
+        assert_eq!(
+            serde_json::to_value(Reisebereitschaft::Unknown("ständig".to_string())).unwrap(),
+            serde_json::json!("ständig")
+        );
+        assert_eq!(
+            Reisebereitschaft::NichtErforderlich.to_string(),
+            "nicht erforderlich"
+        );
+    }
+
+    #[test]
+    fn test_reisebereitschaft_ordering() {
+        let mut values = vec![
+            Reisebereitschaft::Unknown("ständig".to_string()),
+            Reisebereitschaft::Haeufig,
+            Reisebereitschaft::NichtErforderlich,
+            Reisebereitschaft::Gelegentlich,
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                Reisebereitschaft::NichtErforderlich,
+                Reisebereitschaft::Gelegentlich,
+                Reisebereitschaft::Haeufig,
+                Reisebereitschaft::Unknown("ständig".to_string()),
+            ]
+        );
+        assert!(Reisebereitschaft::Gelegentlich < Reisebereitschaft::Haeufig);
+    }
+
     #[test]
     fn test_leadership_skills_deserialization() {
         let json = r#"{
@@ -503,6 +3496,155 @@ mod tests {
         assert_eq!(skills.hat_budgetverantwortung, Some(false));
     }
 
+    #[test]
+    fn test_web_url() {
+        let job = JobListing::new("10001-1001601666-S", WorkLocation::default());
+        assert_eq!(
+            job.web_url().as_str(),
+            "https://www.arbeitsagentur.de/jobsuche/jobdetail/MTAwMDEtMTAwMTYwMTY2Ni1T"
+        );
+
+        // Unpadded URL-safe alphabet
+        let details = JobDetails::new("10001-TEST1-S");
+        assert_eq!(
+            details.web_url().unwrap().as_str(),
+            "https://www.arbeitsagentur.de/jobsuche/jobdetail/MTAwMDEtVEVTVDEtUw"
+        );
+
+        assert_eq!(JobDetails::default().web_url(), None);
+        assert_eq!(JobDetails::new(" ").web_url(), None);
+    }
+
+    #[test]
+    fn test_external_url() {
+        let listing = |url: Option<&str>| -> JobListing {
+            serde_json::from_value(serde_json::json!({
+                "refnr": "10001-TEST-S",
+                "arbeitsort": {},
+                "externeUrl": url,
+            }))
+            .unwrap()
+        };
+
+        let job = listing(Some(
+            "https://www.stepstone.de/stellenangebote--Koch-Berlin-12345.html",
+        ));
+        assert!(job.is_external());
+        assert_eq!(
+            job.external_url().unwrap().host_str(),
+            Some("www.stepstone.de")
+        );
+
+        // Set but unusable: still external, but no URL to link to
+        for raw in [
+            "/stellenangebote/12345",
+            "www.example.com/job",
+            "https://",
+            "javascript:alert(1)",
+        ] {
+            let job = listing(Some(raw));
+            assert!(job.is_external(), "{}", raw);
+            assert_eq!(job.external_url(), None, "{}", raw);
+        }
+
+        for raw in [None, Some(""), Some("  ")] {
+            let job = listing(raw);
+            assert!(!job.is_external());
+            assert_eq!(job.external_url(), None);
+        }
+    }
+
+    #[test]
+    fn test_alliance_partner_url() {
+        let details: JobDetails = serde_json::from_str(
+            r#"{"allianzpartnerName": "Partner", "allianzpartnerUrl": "http://partner.example/jobs?id=1"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            details.alliance_partner_url().unwrap().as_str(),
+            "http://partner.example/jobs?id=1"
+        );
+
+        let details: JobDetails =
+            serde_json::from_str(r#"{"allianzpartnerUrl": "partner.example"}"#).unwrap();
+        assert_eq!(details.alliance_partner_url(), None);
+        assert_eq!(
+            details.allianzpartner_url.as_deref(),
+            Some("partner.example")
+        );
+    }
+
+    const BERLIN: Coordinates = Coordinates {
+        lat: 52.5200,
+        lon: 13.4050,
+    };
+    const MUENCHEN: Coordinates = Coordinates {
+        lat: 48.1351,
+        lon: 11.5820,
+    };
+
+    #[test]
+    fn test_distance_km_city_pairs() {
+        let frankfurt = Coordinates {
+            lat: 50.1109,
+            lon: 8.6821,
+        };
+        let koeln = Coordinates {
+            lat: 50.9375,
+            lon: 6.9603,
+        };
+
+        let close = |actual: f64, expected: f64| (actual - expected).abs() < 5.0;
+        assert!(close(BERLIN.distance_km(&MUENCHEN), 504.0));
+        assert!(close(frankfurt.distance_km(&koeln), 152.0));
+        assert_eq!(BERLIN.distance_km(&MUENCHEN), MUENCHEN.distance_km(&BERLIN));
+        assert_eq!(BERLIN.distance_km(&BERLIN), 0.0);
+    }
+
+    #[test]
+    fn test_distance_from() {
+        let location = |entfernung: Option<&str>, koordinaten: Option<&Coordinates>| {
+            serde_json::from_value::<WorkLocation>(serde_json::json!({
+                "entfernung": entfernung,
+                "koordinaten": koordinaten,
+            }))
+            .unwrap()
+        };
+
+        // The API's distance wins over the coordinates
+        assert_eq!(
+            location(Some("12.5"), Some(&MUENCHEN)).distance_from(&BERLIN),
+            Some(12.5)
+        );
+        let fallback = location(None, Some(&MUENCHEN)).distance_from(&BERLIN);
+        assert!((fallback.unwrap() - 504.0).abs() < 5.0);
+        let fallback = location(Some("unbekannt"), Some(&MUENCHEN)).distance_from(&BERLIN);
+        assert!(fallback.is_some());
+
+        assert_eq!(location(None, None).distance_from(&BERLIN), None);
+        assert_eq!(location(Some("n/a"), None).distance_from(&BERLIN), None);
+    }
+
+    #[test]
+    fn test_work_location_distance_km() {
+        let distance = |entfernung: Option<&str>| {
+            serde_json::from_value::<WorkLocation>(serde_json::json!({ "entfernung": entfernung }))
+                .unwrap()
+                .distance_km()
+        };
+
+        assert_eq!(distance(Some("12.3")), Some(12.3));
+        assert_eq!(distance(Some("12,3")), Some(12.3));
+        assert_eq!(distance(Some("42")), Some(42.0));
+        assert_eq!(distance(Some(" 7 km")), Some(7.0));
+        assert_eq!(distance(Some("0,5km")), Some(0.5));
+
+        for junk in ["", "  ", "km", "nah", "12-15", "NaN", "inf", "1.234,5"] {
+            assert_eq!(distance(Some(junk)), None, "{:?}", junk);
+        }
+        assert_eq!(distance(None), None);
+    }
+
     #[test]
     fn test_coordinates_deserialization() {
         let json = r#"{
@@ -515,6 +3657,123 @@ mod tests {
         assert_eq!(coords.lon, 11.5820);
     }
 
+    #[test]
+    fn test_filter_enums_round_trip() {
+        for variant in [
+            Angebotsart::Arbeit,
+            Angebotsart::Selbstaendigkeit,
+            Angebotsart::Ausbildung,
+            Angebotsart::PraktikumTrainee,
+        ] {
+            assert_eq!(variant.as_str().parse(), Ok(variant.clone()));
+            assert_eq!(variant.to_string().parse(), Ok(variant.clone()));
+            assert_eq!(format!("{:?}", variant).parse(), Ok(variant.clone()));
+            let code: u8 = variant.as_str().parse().unwrap();
+            assert_eq!(Angebotsart::try_from(code), Ok(variant.clone()));
+            let json = serde_json::to_string(&variant).unwrap();
+            assert_eq!(serde_json::from_str::<Angebotsart>(&json).unwrap(), variant);
+        }
+
+        for variant in [Befristung::Befristet, Befristung::Unbefristet] {
+            assert_eq!(variant.as_str().parse(), Ok(variant));
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+            assert_eq!(format!("{:?}", variant).parse(), Ok(variant));
+            assert_eq!(Befristung::try_from(variant as u8), Ok(variant));
+        }
+
+        for variant in [
+            Arbeitszeit::Vollzeit,
+            Arbeitszeit::Teilzeit,
+            Arbeitszeit::SchichtNachtarbeitWochenende,
+            Arbeitszeit::HeimTelearbeit,
+            Arbeitszeit::Minijob,
+        ] {
+            assert_eq!(variant.as_str().parse(), Ok(variant));
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+            assert_eq!(format!("{:?}", variant).parse(), Ok(variant));
+        }
+    }
+
+    #[test]
+    fn test_filter_enums_english_names() {
+        fn check<T: fmt::Debug>(
+            aliases: &[(T, &[&'static str])],
+            german: fn(&T) -> &'static str,
+            english: fn(&T) -> &'static str,
+        ) {
+            let mut seen = Vec::new();
+            for (variant, names) in aliases {
+                assert_eq!(german(variant), names[1]);
+                let name = english(variant);
+                assert!(!name.is_empty(), "{:?}", variant);
+                assert!(!seen.contains(&name), "duplicate {}", name);
+                seen.push(name);
+            }
+        }
+
+        check(
+            Angebotsart::ALIASES,
+            Angebotsart::german_name,
+            Angebotsart::english_name,
+        );
+        check(
+            Befristung::ALIASES,
+            Befristung::german_name,
+            Befristung::english_name,
+        );
+        check(
+            Arbeitszeit::ALIASES,
+            Arbeitszeit::german_name,
+            Arbeitszeit::english_name,
+        );
+
+        assert_eq!(Befristung::Unbefristet.english_name(), "Permanent");
+        assert_eq!(Arbeitszeit::Vollzeit.english_name(), "Full-time");
+        // Display stays German
+        assert_eq!(Arbeitszeit::Vollzeit.to_string(), "Vollzeit");
+    }
+
+    #[test]
+    fn test_filter_enums_parse_leniently() {
+        assert_eq!("VZ".parse(), Ok(Arbeitszeit::Vollzeit));
+        assert_eq!(" vollzeit ".parse(), Ok(Arbeitszeit::Vollzeit));
+        assert_eq!("HEIM_TELEARBEIT".parse(), Ok(Arbeitszeit::HeimTelearbeit));
+        assert_eq!("selbständigkeit".parse(), Ok(Angebotsart::Selbstaendigkeit));
+        assert_eq!("ausbildung".parse(), Ok(Angebotsart::Ausbildung));
+        assert_eq!("UNBEFRISTET".parse(), Ok(Befristung::Unbefristet));
+        assert_eq!(Arbeitszeit::HeimTelearbeit.to_string(), "Heim-/Telearbeit");
+    }
+
+    #[test]
+    fn test_filter_enums_reject_unknown() {
+        let err = "Gleitzeit".parse::<Arbeitszeit>().unwrap_err();
+        assert_eq!(err.kind, "Arbeitszeit");
+        assert_eq!(err.value, "Gleitzeit");
+        assert_eq!(
+            err.accepted,
+            vec![
+                "vz",
+                "Vollzeit",
+                "tz",
+                "Teilzeit",
+                "snw",
+                "Schicht/Nachtarbeit/Wochenende",
+                "ho",
+                "Heim-/Telearbeit",
+                "mj",
+                "Minijob"
+            ]
+        );
+        assert!(err
+            .to_string()
+            .starts_with(r#"Invalid Arbeitszeit "Gleitzeit", expected one of: vz, Vollzeit, tz"#));
+
+        assert!("".parse::<Befristung>().is_err());
+        assert!("3".parse::<Angebotsart>().is_err());
+        assert_eq!(Angebotsart::try_from(3u8).unwrap_err().value, "3");
+        assert!(Befristung::try_from(0u8).is_err());
+    }
+
     #[test]
     fn test_angebotsart_equality() {
         assert_eq!(Angebotsart::Arbeit, Angebotsart::Arbeit);
@@ -537,7 +3796,7 @@ mod tests {
     fn test_job_listing_serialization() {
         let listing = JobListing {
             hash_id: Some("hash123".to_string()),
-            refnr: "10001-TEST-S".to_string(),
+            refnr: "10001-TEST-S".into(),
             beruf: Some("Developer".to_string()),
             titel: Some("Senior Developer".to_string()),
             arbeitgeber: Some("Company".to_string()),
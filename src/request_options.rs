@@ -0,0 +1,116 @@
+//! Per-request overrides
+//!
+//! [`RequestOptions`] adjusts a single call without touching the client-wide
+//! [`ClientConfig`](crate::ClientConfig). It is accepted by the `_with` variants of the async
+//! client methods, e.g. [`JobsucheAsync::job_details_with`](crate::JobsucheAsync::job_details_with).
+//! `RequestOptions::default()` reproduces the behavior of the plain methods exactly.
+
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue};
+
+use crate::{Error, Result};
+
+/// Header carrying [`RequestOptions::idempotency_key`]
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Overrides for a single request
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{JobsucheAsync, RequestOptions};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = JobsucheAsync::default_client()?;
+///
+///     // Fail fast instead of retrying
+///     let options = RequestOptions {
+///         timeout: Some(Duration::from_secs(2)),
+///         skip_retry: true,
+///         ..Default::default()
+///     };
+///     let job = client.job_details_with("10001-1001601666-S", &options).await?;
+///     println!("{:?}", job.titel);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Total timeout for each attempt, replacing [`ClientConfig::timeout`](crate::ClientConfig::timeout)
+    pub timeout: Option<Duration>,
+    /// Additional headers; these replace default headers with the same name
+    pub extra_headers: HeaderMap,
+    /// Send a single attempt even if retries are enabled in the client configuration
+    pub skip_retry: bool,
+    /// Value for the [`IDEMPOTENCY_KEY_HEADER`] header, reused by every retry attempt
+    pub idempotency_key: Option<String>,
+    /// Fetch job details from the API even if they are cached; the response replaces
+    /// the cached entry, or refreshes it on a 304 to `If-None-Match`
+    #[cfg(feature = "cache")]
+    pub cache_bypass: bool,
+}
+
+impl RequestOptions {
+    /// Add the extra headers and the idempotency key to `headers`
+    pub(crate) fn apply_headers(&self, headers: &mut HeaderMap) -> Result<()> {
+        // Extending with a HeaderMap replaces existing entries and keeps multi-valued headers
+        headers.extend(self.extra_headers.clone());
+        if let Some(key) = &self.idempotency_key {
+            let value = HeaderValue::from_str(key).map_err(|e| Error::ConfigError {
+                message: format!("invalid idempotency key {:?}: {}", key, e),
+            })?;
+            headers.insert(IDEMPOTENCY_KEY_HEADER, value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::ACCEPT;
+
+    #[test]
+    fn test_default_adds_nothing() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        RequestOptions::default()
+            .apply_headers(&mut headers)
+            .unwrap();
+        assert_eq!(headers.len(), 1);
+    }
+
+    #[test]
+    fn test_extra_headers_override_defaults() {
+        let mut extra_headers = HeaderMap::new();
+        extra_headers.insert(ACCEPT, HeaderValue::from_static("text/plain"));
+        extra_headers.insert("x-tenant", HeaderValue::from_static("acme"));
+        let options = RequestOptions {
+            extra_headers,
+            idempotency_key: Some("key-1".to_string()),
+            ..Default::default()
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        options.apply_headers(&mut headers).unwrap();
+
+        assert_eq!(headers[ACCEPT], "text/plain");
+        assert_eq!(headers["x-tenant"], "acme");
+        assert_eq!(headers[IDEMPOTENCY_KEY_HEADER], "key-1");
+    }
+
+    #[test]
+    fn test_invalid_idempotency_key() {
+        let options = RequestOptions {
+            idempotency_key: Some("bad\nkey".to_string()),
+            ..Default::default()
+        };
+        let result = options.apply_headers(&mut HeaderMap::new());
+        assert!(matches!(result, Err(Error::ConfigError { .. })));
+    }
+}
@@ -0,0 +1,91 @@
+//! JSON Schemas of the response types (requires the `schemars` feature)
+//!
+//! The schemas describe what the types accept when deserializing, including aliases and
+//! the alternative location shapes, and carry the field documentation as descriptions.
+//! Other types deriving `JsonSchema` can be passed to [`schemars::schema_for!`] directly.
+//!
+//! # Example
+//!
+//! ```
+//! use jobsuche::JobDetails;
+//!
+//! let schema = serde_json::to_string_pretty(&JobDetails::schema()).unwrap();
+//! assert!(schema.contains("stellenangebotsTitel"));
+//! ```
+
+use schemars::{schema_for, Schema};
+
+use crate::rep::{JobDetails, JobListing, JobSearchResponse};
+
+impl JobSearchResponse {
+    /// JSON Schema of a search response
+    pub fn schema() -> Schema {
+        schema_for!(JobSearchResponse)
+    }
+}
+
+impl JobListing {
+    /// JSON Schema of a single search result
+    pub fn schema() -> Schema {
+        schema_for!(JobListing)
+    }
+}
+
+impl JobDetails {
+    /// JSON Schema of a job details response
+    pub fn schema() -> Schema {
+        schema_for!(JobDetails)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(schema: Schema, fixture: &str) {
+        let schema = serde_json::to_value(schema).unwrap();
+        let instance: serde_json::Value = serde_json::from_str(fixture).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        let errors: Vec<_> = validator
+            .iter_errors(&instance)
+            .map(|e| format!("{} at {}", e, e.instance_path))
+            .collect();
+        assert!(errors.is_empty(), "{:#?}", errors);
+    }
+
+    #[test]
+    fn test_fixtures_match_schemas() {
+        validate(
+            JobSearchResponse::schema(),
+            include_str!("../tests/fixtures/search-facets.json"),
+        );
+        validate(
+            JobDetails::schema(),
+            include_str!("../tests/fixtures/job-details-full.json"),
+        );
+        validate(
+            JobDetails::schema(),
+            include_str!("../tests/fixtures/job-details-minimal.json"),
+        );
+    }
+
+    #[test]
+    fn test_schema_rejects_wrong_types() {
+        let schema = serde_json::to_value(JobListing::schema()).unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+
+        assert!(validator.is_valid(&serde_json::json!({"refnr": "10001-A-S", "arbeitsort": {}})));
+        // refnr and arbeitsort are required
+        assert!(!validator.is_valid(&serde_json::json!({"arbeitsort": {}})));
+        assert!(!validator.is_valid(&serde_json::json!({"refnr": 5, "arbeitsort": {}})));
+    }
+
+    #[test]
+    fn test_schema_carries_field_docs() {
+        let schema = serde_json::to_value(JobListing::schema()).unwrap();
+        assert_eq!(
+            schema["properties"]["refnr"]["description"],
+            "Reference number (use this for job details)"
+        );
+    }
+}
@@ -1,18 +1,40 @@
 //! Job search functionality
 
+#[cfg(all(feature = "async", feature = "cache"))]
+use std::sync::Arc;
+
 use tracing::debug;
 
+#[cfg(feature = "cache")]
+use crate::cache::ResponseCache;
+use crate::hooks::Endpoint;
 use crate::pagination::JobIterator;
+#[cfg(feature = "async")]
+use crate::pagination::{PageCursor, DEFAULT_PAGE_SIZE, MAX_PAGES};
 use crate::sync::Jobsuche;
 use crate::{JobSearchResponse, Result, SearchOptions};
 
+#[cfg(feature = "async")]
+use crate::{JobDetails, JobListing, ResultExt};
+
 #[cfg(feature = "async")]
 use crate::async_client::JobsucheAsync;
+#[cfg(feature = "async")]
+use crate::request_options::RequestOptions;
 
 #[cfg(feature = "async")]
 use async_stream::stream;
 #[cfg(feature = "async")]
-use futures::stream::Stream;
+use futures::stream::{Stream, StreamExt};
+
+/// Stream returned by [`SearchAsync::stream_with_details`]
+#[cfg(feature = "async")]
+pub type DetailsStream =
+    std::pin::Pin<Box<dyn Stream<Item = Result<(JobListing, JobDetails)>> + Send>>;
+
+/// Context attached to errors of paginated searches
+#[cfg(feature = "async")]
+const PAGE_CONTEXT: &str = "fetching a page of search results";
 
 /// Search interface for finding jobs
 ///
@@ -38,12 +60,9 @@ impl Search {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials, SearchOptions, Arbeitszeit};
+    /// use jobsuche::{Jobsuche, SearchOptions, Arbeitszeit};
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::default_client().unwrap();
     ///
     /// let results = client.search().list(SearchOptions::builder()
     ///     .was("Softwareentwickler")
@@ -60,17 +79,59 @@ impl Search {
     ///     println!("- {}: {}", job.refnr, job.beruf.as_deref().unwrap_or("Unknown"));
     /// }
     /// ```
+    ///
+    /// With [`ClientConfig::cache`](crate::ClientConfig::cache) set, results for the same
+    /// options are returned from memory until the search TTL runs out.
     pub fn list(&self, options: SearchOptions) -> Result<JobSearchResponse> {
-        let mut path = self.client.core.path(&["pc", "v4", "jobs"]);
+        #[cfg(feature = "cache")]
+        if let Some(response) = self.cache().and_then(|cache| cache.search(&options)) {
+            return Ok(response);
+        }
+        let response = self.fetch(&options)?;
+        #[cfg(feature = "cache")]
+        if let Some(cache) = self.cache() {
+            cache.store_search(&options, &response);
+        }
+        Ok(response)
+    }
 
-        if let Some(query) = options.serialize() {
-            path.push('?');
-            path.push_str(&query);
+    /// Perform a job search even if the results are cached
+    ///
+    /// The fresh response replaces the cached entry. Same as [`list`](Self::list) if no
+    /// cache is configured.
+    #[cfg(feature = "cache")]
+    pub fn list_fresh(&self, options: SearchOptions) -> Result<JobSearchResponse> {
+        let response = self.fetch(&options)?;
+        if let Some(cache) = self.cache() {
+            cache.store_search(&options, &response);
         }
+        Ok(response)
+    }
+
+    /// Fetch a page for pagination, skipping the cache unless
+    /// [`CacheConfig::cache_pages`](crate::CacheConfig::cache_pages) is set
+    pub(crate) fn list_page(&self, options: SearchOptions) -> Result<JobSearchResponse> {
+        #[cfg(feature = "cache")]
+        if self.cache().is_some_and(|cache| !cache.cache_pages) {
+            return self.fetch(&options);
+        }
+        self.list(options)
+    }
+
+    fn fetch(&self, options: &SearchOptions) -> Result<JobSearchResponse> {
+        let path = self
+            .client
+            .core()
+            .path_with_query(&["pc", "v4", "jobs"], options.serialize().as_deref());
 
         debug!("Searching jobs with path: {}", path);
 
-        self.client.get(&path)
+        self.client.get(Endpoint::Jobs, &path)
+    }
+
+    #[cfg(feature = "cache")]
+    fn cache(&self) -> Option<&ResponseCache> {
+        self.client.response_cache()
     }
 
     /// Search with automatic pagination, yielding all results (collected into Vec)
@@ -91,12 +152,9 @@ impl Search {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials, SearchOptions};
+    /// use jobsuche::{Jobsuche, SearchOptions};
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::default_client().unwrap();
     ///
     /// let all_jobs = client.search().iter(SearchOptions::builder()
     ///     .was("Rust Developer")
@@ -120,12 +178,9 @@ impl Search {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials, SearchOptions};
+    /// use jobsuche::{Jobsuche, SearchOptions};
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::default_client().unwrap();
     ///
     /// let options = SearchOptions::builder()
     ///     .was("Rust Developer")
@@ -165,8 +220,9 @@ mod tests {
 /// Async search interface for finding jobs
 ///
 /// This interface provides async methods to search for jobs using the Jobsuche API.
+/// It holds a handle to the client's shared state, so creating and cloning it is cheap.
 #[cfg(feature = "async")]
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SearchAsync {
     client: JobsucheAsync,
 }
@@ -179,19 +235,21 @@ impl SearchAsync {
         }
     }
 
+    #[cfg(test)]
+    pub(crate) fn client(&self) -> &JobsucheAsync {
+        &self.client
+    }
+
     /// Perform an async job search with the given options
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials, SearchOptions, Arbeitszeit};
+    /// use jobsuche::{JobsucheAsync, SearchOptions, Arbeitszeit};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::default_client()?;
     ///
     ///     let results = client.search().list(SearchOptions::builder()
     ///         .was("Softwareentwickler")
@@ -204,17 +262,76 @@ impl SearchAsync {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// # Cancellation
+    ///
+    /// Cancel safe. Dropping the future aborts the in-flight request (including a hedged
+    /// duplicate, see [`ClientConfig::hedge_after`](crate::ClientConfig::hedge_after)) and any
+    /// pending retry sleep. No background tasks are spawned, except that a blocking cache
+    /// backend finishes its current read or write on tokio's blocking pool. The client stays
+    /// usable.
     pub async fn list(&self, options: SearchOptions) -> Result<JobSearchResponse> {
-        let mut path = self.client.core.path(&["pc", "v4", "jobs"]);
+        self.list_with(options, &RequestOptions::default()).await
+    }
+
+    /// Search for jobs with per-request overrides (async)
+    ///
+    /// Like [`list`](Self::list), but applies `request` to this call only, e.g. to fail fast
+    /// with [`RequestOptions::skip_retry`] or to send extra headers. With
+    /// [`ClientConfig::cache`](crate::ClientConfig::cache) set, results for the same options
+    /// are returned from the cache until the search TTL runs out, unless
+    /// `request.cache_bypass` is set.
+    pub async fn list_with(
+        &self,
+        options: SearchOptions,
+        request: &RequestOptions,
+    ) -> Result<JobSearchResponse> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = self.cache() {
+            if !request.cache_bypass {
+                let key = options.clone();
+                if let Some(response) = cache.run(move |cache| cache.search(&key)).await {
+                    return Ok(response);
+                }
+            }
+            let response = self.fetch(&options, request).await?;
+            let stored = response.clone();
+            cache
+                .run(move |cache| cache.store_search(&options, &stored))
+                .await;
+            return Ok(response);
+        }
+        self.fetch(&options, request).await
+    }
 
-        if let Some(query) = options.serialize() {
-            path.push('?');
-            path.push_str(&query);
+    /// Fetch a page for pagination, skipping the cache unless
+    /// [`CacheConfig::cache_pages`](crate::CacheConfig::cache_pages) is set
+    async fn list_page(&self, options: SearchOptions) -> Result<JobSearchResponse> {
+        #[cfg(feature = "cache")]
+        if self.cache().is_some_and(|cache| !cache.cache_pages) {
+            return self.fetch(&options, &RequestOptions::default()).await;
         }
+        self.list(options).await
+    }
+
+    async fn fetch(
+        &self,
+        options: &SearchOptions,
+        request: &RequestOptions,
+    ) -> Result<JobSearchResponse> {
+        let path = self
+            .client
+            .core()
+            .path_with_query(&["pc", "v4", "jobs"], options.serialize().as_deref());
 
         debug!("Searching jobs with path: {} (async)", path);
 
-        self.client.get(&path).await
+        self.client.get(Endpoint::Jobs, &path, request).await
+    }
+
+    #[cfg(feature = "cache")]
+    fn cache(&self) -> Option<&Arc<ResponseCache>> {
+        self.client.response_cache()
     }
 
     /// Search with automatic pagination, yielding all results (async)
@@ -225,14 +342,11 @@ impl SearchAsync {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials, SearchOptions};
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::default_client()?;
     ///
     ///     let all_jobs = client.search().iter(SearchOptions::builder()
     ///         .was("Rust Developer")
@@ -244,41 +358,82 @@ impl SearchAsync {
     ///     Ok(())
     /// }
     /// ```
+    ///
+    /// # Cancellation
+    ///
+    /// Cancel safe, but not resumable: dropping the future discards the pages collected so
+    /// far and aborts the current request. Use [`stream`](Self::stream) to keep partial results.
     pub async fn iter(&self, options: SearchOptions) -> Result<Vec<crate::JobListing>> {
         let mut all_jobs = Vec::new();
-        let mut page = 1u64;
-        let size = options.size().unwrap_or(50);
+        let mut cursor = PageCursor::new(options);
+
+        while let Some(page_options) = cursor.next_options() {
+            let results = self
+                .list_page(page_options)
+                .await
+                .context_op(PAGE_CONTEXT)?;
+            cursor.record(&results);
+            all_jobs.extend(results.stellenangebote);
+        }
 
-        loop {
-            let page_options = options.as_builder().page(page).size(size).build();
+        Ok(all_jobs)
+    }
 
-            let results = self.list(page_options).await?;
+    /// Return a lazy stream over whole result pages
+    ///
+    /// Unlike [`stream`](Self::stream), each item is a complete [`JobSearchResponse`], so
+    /// facets and `maxErgebnisse` stay accessible while paginating. Pagination stops after a
+    /// partial page, once `maxErgebnisse` results have been received, or after 100 pages
+    /// (API limit). An error is yielded once and ends the stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let mut pages = client.search().pages(SearchOptions::builder().was("Rust").build());
+    ///
+    ///     while let Some(page) = pages.next().await {
+    ///         let page = page?;
+    ///         println!("{} jobs on this page, facets: {:?}", page.stellenangebote.len(), page.facetten);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Cancellation
+    ///
+    /// Same as [`stream`](Self::stream): pages are fetched lazily and dropping the stream
+    /// aborts the in-flight request.
+    pub fn pages(
+        &self,
+        options: SearchOptions,
+    ) -> std::pin::Pin<Box<dyn Stream<Item = Result<JobSearchResponse>> + Send>> {
+        let client = self.client.clone();
 
-            let jobs_count = results.stellenangebote.len();
-            all_jobs.extend(results.stellenangebote);
+        Box::pin(stream! {
+            let mut cursor = PageCursor::new(options);
 
-            // Stop if we got fewer results than requested (last page)
-            if jobs_count < size as usize {
-                break;
-            }
+            while let Some(page_options) = cursor.next_options() {
+                debug!("Fetching page {:?} (async pages)", page_options.page());
 
-            // Check if we've reached the maximum results
-            if let Some(max) = results.max_ergebnisse {
-                if all_jobs.len() >= max as usize {
-                    break;
+                match client.search().list_page(page_options).await.context_op(PAGE_CONTEXT) {
+                    Ok(response) => {
+                        cursor.record(&response);
+                        yield Ok(response);
+                    }
+                    Err(e) => {
+                        cursor.finish();
+                        yield Err(e);
+                    }
                 }
             }
-
-            page += 1;
-
-            // API limit: maximum 100 pages total (Issue #14 in bundesAPI/jobsuche-api)
-            if page > 100 {
-                debug!("Reached API limit: maximum 100 pages");
-                break;
-            }
-        }
-
-        Ok(all_jobs)
+        })
     }
 
     /// Return a lazy stream over job search results
@@ -293,18 +448,21 @@ impl SearchAsync {
     /// - Can process unlimited results without loading all at once
     /// - Can stop early without fetching remaining pages
     ///
+    /// # Cancellation
+    ///
+    /// Pages are fetched lazily from within `poll_next`, never by a background task. Dropping
+    /// the stream (or a pending `next()` future) aborts the in-flight page request; no further
+    /// pages are requested, and jobs already yielded are unaffected.
+    ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials, SearchOptions};
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
     /// use futures::StreamExt;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = JobsucheAsync::new(
-    ///         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///         Credentials::default()
-    ///     ).await?;
+    ///     let client = JobsucheAsync::default_client()?;
     ///
     ///     let options = SearchOptions::builder()
     ///         .was("Rust Developer")
@@ -329,15 +487,12 @@ impl SearchAsync {
     /// Streams compose naturally with futures combinators:
     ///
     /// ```no_run
-    /// use jobsuche::{JobsucheAsync, Credentials, SearchOptions};
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
     /// use futures::StreamExt;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let client = JobsucheAsync::new(
-    /// #     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    /// #     Credentials::default()
-    /// # ).await?;
+    /// # let client = JobsucheAsync::default_client()?;
     /// # let options = SearchOptions::builder().was("Developer").build();
     /// let stream = client.search().stream(options)
     ///     .filter(|result| {
@@ -358,7 +513,7 @@ impl SearchAsync {
 
         Box::pin(stream! {
             let mut page = 1u64;
-            let size = options.size().unwrap_or(50);
+            let size = options.size().unwrap_or(DEFAULT_PAGE_SIZE);
             let mut total_yielded = 0u64;
             let mut max_results: Option<u64> = None;
 
@@ -369,7 +524,7 @@ impl SearchAsync {
                 debug!("Fetching page {} (async stream)", page);
 
                 // Fetch the page
-                match client.search().list(page_options).await {
+                match client.search().list_page(page_options).await.context_op(PAGE_CONTEXT) {
                     Ok(response) => {
                         // Store max_results from first page
                         if page == 1 {
@@ -398,9 +553,8 @@ impl SearchAsync {
 
                         page += 1;
 
-                        // API limit: maximum 100 pages total (Issue #14 in bundesAPI/jobsuche-api)
-                        if page > 100 {
-                            debug!("Reached API limit: maximum 100 pages");
+                        if page > MAX_PAGES {
+                            debug!("Reached API limit: maximum {} pages", MAX_PAGES);
                             return;
                         }
                     }
@@ -413,6 +567,64 @@ impl SearchAsync {
             }
         })
     }
+
+    /// Return a stream of job listings enriched with their details
+    ///
+    /// Composes [`stream`](Self::stream) with [`JobsucheAsync::job_details`], fetching up to
+    /// `concurrency` details at once (0 is treated as 1). Items are yielded in search order.
+    /// Jobs whose details return [`Error::NotFound`](crate::Error::NotFound) (expired between search and details
+    /// request) are skipped; any other error is yielded. Retries and rate limiting follow
+    /// the client's [`ClientConfig`](crate::ClientConfig).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{JobsucheAsync, SearchOptions};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = JobsucheAsync::default_client()?;
+    ///
+    ///     let mut jobs = client
+    ///         .search()
+    ///         .stream_with_details(SearchOptions::builder().was("Rust").build(), 4);
+    ///
+    ///     while let Some(result) = jobs.next().await {
+    ///         let (listing, details) = result?;
+    ///         println!("{}: {:?}", listing.refnr, details.titel);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stream_with_details(&self, options: SearchOptions, concurrency: usize) -> DetailsStream {
+        let client = self.client.clone();
+
+        let details = self
+            .stream(options)
+            .map(move |listing| {
+                let client = client.clone();
+                async move {
+                    let listing = listing?;
+                    match client
+                        .job_details(&listing.refnr)
+                        .await
+                        .context_refnr(&listing.refnr)
+                    {
+                        Ok(details) => Ok(Some((listing, details))),
+                        Err(e) if e.is_not_found() => {
+                            debug!("Skipping expired job {}", listing.refnr);
+                            Ok(None)
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+            })
+            .buffered(concurrency.max(1))
+            .filter_map(|result| futures::future::ready(result.transpose()));
+
+        Box::pin(details)
+    }
 }
 
 #[cfg(all(test, feature = "async"))]
@@ -426,7 +638,6 @@ mod async_tests {
             "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
             Credentials::default(),
         )
-        .await
         .unwrap();
 
         let search = client.search();
@@ -441,7 +652,6 @@ mod async_tests {
             "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
             Credentials::default(),
         )
-        .await
         .unwrap();
 
         let options = SearchOptions::builder().was("test").build();
@@ -0,0 +1,106 @@
+//! `tower::Service` adapter for the async client
+//!
+//! Enabled with the `tower` feature. This lets [`JobsucheAsync`] sit behind an existing
+//! tower stack (timeouts, load shedding, tracing) while the typed API stays available.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use jobsuche::service::{JobsucheRequest, JobsucheResponse};
+//! use jobsuche::{JobsucheAsync, SearchOptions};
+//! use tower::ServiceExt;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let service = JobsucheAsync::default_client()?.into_service();
+//!
+//!     let request = JobsucheRequest::Search(SearchOptions::builder().was("Rust").build());
+//!     if let JobsucheResponse::Search(results) = service.oneshot(request).await? {
+//!         println!("Found {} jobs", results.stellenangebote.len());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::async_client::JobsucheAsync;
+use crate::{Error, JobDetails, JobSearchResponse, Result, SearchOptions};
+
+/// A request to the Jobsuche API
+#[derive(Debug, Clone)]
+pub enum JobsucheRequest {
+    /// Job search, see [`SearchAsync::list`](crate::SearchAsync::list)
+    Search(SearchOptions),
+    /// Job details by reference number, see [`JobsucheAsync::job_details`]
+    Details(String),
+    /// Employer logo by hash ID, see [`JobsucheAsync::employer_logo`]
+    Logo(String),
+}
+
+/// The response to a [`JobsucheRequest`], with the variant matching the request
+#[derive(Debug, Clone)]
+pub enum JobsucheResponse {
+    /// Search results
+    Search(Box<JobSearchResponse>),
+    /// Job details
+    Details(Box<JobDetails>),
+    /// Raw logo image bytes
+    Logo(Vec<u8>),
+}
+
+/// `tower::Service` wrapping a [`JobsucheAsync`] client
+///
+/// The service is always ready; backpressure and rate limiting come from the client
+/// configuration or from tower layers around it.
+#[derive(Debug, Clone)]
+pub struct JobsucheService {
+    client: JobsucheAsync,
+}
+
+impl JobsucheService {
+    /// Get the underlying client
+    pub fn client(&self) -> &JobsucheAsync {
+        &self.client
+    }
+}
+
+impl JobsucheAsync {
+    /// Convert the client into a `tower::Service`
+    pub fn into_service(self) -> JobsucheService {
+        JobsucheService { client: self }
+    }
+}
+
+impl tower::Service<JobsucheRequest> for JobsucheService {
+    type Response = JobsucheResponse;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<JobsucheResponse>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: JobsucheRequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move {
+            match request {
+                JobsucheRequest::Search(options) => client
+                    .search()
+                    .list(options)
+                    .await
+                    .map(|results| JobsucheResponse::Search(Box::new(results))),
+                JobsucheRequest::Details(refnr) => client
+                    .job_details(&refnr)
+                    .await
+                    .map(|details| JobsucheResponse::Details(Box::new(details))),
+                JobsucheRequest::Logo(hash_id) => client
+                    .employer_logo(&hash_id)
+                    .await
+                    .map(JobsucheResponse::Logo),
+            }
+        })
+    }
+}
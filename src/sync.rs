@@ -1,22 +1,38 @@
 //! Synchronous client for the Jobsuche API
 
-use std::io::Read;
+use std::sync::Arc;
 use std::thread;
-use std::time::Duration;
-use tracing::{debug, warn};
+use std::time::{Duration, Instant, SystemTime};
+use tracing::{debug, warn, Span};
 
 use backon::{BackoffBuilder, ExponentialBuilder};
 use reqwest::blocking::Client;
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH,
+};
 use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 
-use crate::core::{encode_refnr, ClientCore};
+use crate::auth::{self, TokenManager};
+#[cfg(feature = "cache")]
+use crate::cache::{
+    Cache, CacheConfig, CacheHandle, DetailsLookup, ResponseCache, StaleDetails, WarmupReport,
+};
+use crate::core::{
+    encode_refnr, env_var, insert_request_id, parse_retry_after, validate_refnr, ClientCore,
+    DEFAULT_ERROR_BODY_LIMIT, DEFAULT_HOST, DEFAULT_REQUEST_ID_HEADER, HOST_ENV,
+};
+use crate::errors::{decode_json, fault_body, UnknownFieldPolicy};
+use crate::hooks::{self, Endpoint, MetricsHook, RequestHook, ResponseHook};
+#[cfg(feature = "metrics")]
+use crate::metrics::{ClientMetrics, MetricsSnapshot};
+use crate::rate_limit::{RateLimit, RateLimiter};
 use crate::search::Search;
-use crate::{ApiErrors, Credentials, Error, JobDetails, Result};
+use crate::telemetry::{self, RetryWaits, WaitReason};
+use crate::{ApiErrors, Conditional, Credentials, Error, JobDetails, RequestContext, Result};
 
 /// Configuration for the Jobsuche client
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ClientConfig {
     /// Request timeout (default: 30 seconds)
     pub timeout: Duration,
@@ -26,6 +42,110 @@ pub struct ClientConfig {
     pub max_retries: u32,
     /// Enable retry logic for transient errors (default: true)
     pub retry_enabled: bool,
+    /// Hook invoked before every API request attempt, including retries and logo downloads
+    /// (default: None)
+    ///
+    /// The `X-API-Key` header value is redacted in the [`RequestInfo`](crate::hooks::RequestInfo).
+    /// A panicking hook is logged and does not affect the request.
+    pub on_request: Option<RequestHook>,
+    /// Hook invoked after every API response, including error statuses (default: None)
+    ///
+    /// Not invoked if no response arrived (connection error, timeout); `on_metric` reports
+    /// those attempts.
+    pub on_response: Option<ResponseHook>,
+    /// Maximum number of response body bytes passed to `on_response` (default: None)
+    ///
+    /// Body capture is disabled unless this is set, because response bodies can be large.
+    pub capture_response_body: Option<usize>,
+    /// Hook receiving latency and status of every request attempt (default: None)
+    ///
+    /// Metrics are labelled with an [`Endpoint`] template, never with
+    /// the requested reference number, so they are safe to export as metric labels.
+    pub on_metric: Option<MetricsHook>,
+    /// Header used to send a generated correlation ID with every request (default: `X-Request-Id`)
+    ///
+    /// Set to `None` to disable request IDs. The ID is included in debug logs and in
+    /// [`Error::Fault`] so failures can be matched with server-side logs.
+    pub request_id_header: Option<String>,
+    /// Additional trusted root certificates in PEM format (default: none)
+    ///
+    /// Each entry may contain one or more certificates. Use this for TLS-intercepting
+    /// corporate proxies or private CAs.
+    pub root_certificates_pem: Vec<Vec<u8>>,
+    /// Disable TLS certificate validation entirely (default: false)
+    ///
+    /// **DANGER:** this makes every connection vulnerable to man-in-the-middle attacks,
+    /// including theft of your API key. Only use it for local debugging; prefer
+    /// [`root_certificates_pem`](Self::root_certificates_pem) wherever possible.
+    pub danger_accept_invalid_certs: bool,
+    /// Minimum accepted TLS version (default: None, reqwest's default)
+    pub min_tls_version: Option<reqwest::tls::Version>,
+    /// Client-side rate limit shared by all clones of a client (default: None)
+    ///
+    /// Every attempt, including retries, counts against the limit.
+    pub rate_limit: Option<RateLimit>,
+    /// Send one duplicate request if a response takes longer than this (default: None)
+    ///
+    /// Only used by the async client. The first successful response wins and the other
+    /// request is cancelled; at most one hedged request is made per attempt.
+    pub hedge_after: Option<Duration>,
+    /// Total time budget for an operation including retries and their sleeps (default: None)
+    ///
+    /// Before each retry sleep, the client checks whether sleeping would exceed the budget
+    /// and, if so, returns the last error immediately. The async client also cuts off an
+    /// attempt that is still running when the budget runs out, returning the previous
+    /// attempt's error or [`Error::DeadlineExceeded`]; sync attempts are bounded by
+    /// [`timeout`](Self::timeout) only.
+    pub retry_deadline: Option<Duration>,
+    /// Log a warning when the retry sleeps of one operation add up to more than this
+    /// (default: 30 seconds)
+    ///
+    /// The warning summarizes the `Retry-After` waits and backoff sleeps, to help tune
+    /// request pacing. Set to `None` to disable it.
+    pub retry_wait_warning: Option<Duration>,
+    /// Log a warning for every attempt slower than this (default: None)
+    ///
+    /// Measured until the response headers arrive, like
+    /// [`RequestMetric::duration`](crate::hooks::RequestMetric::duration). Applies to
+    /// retries and employer logos too; with the `metrics` feature, slow attempts are also
+    /// counted in `jobsuche_slow_requests_total`.
+    pub slow_request_threshold: Option<Duration>,
+    /// Check reference numbers before requesting job details (default: true)
+    ///
+    /// Rejects empty, malformed and already base64-encoded refnrs with
+    /// [`Error::InvalidRefnr`] instead of sending a request that can only 404. Disable this
+    /// if the API introduces a refnr format the check does not know yet.
+    pub validate_refnr: bool,
+    /// Maximum number of response body bytes kept in errors (default: 4096)
+    ///
+    /// Bodies of error responses are stored in [`Error::Fault`], and an excerpt of a body
+    /// that fails to decode is stored in [`Error::Decode`]. The API key is redacted from
+    /// both. Set to 0 to keep no body.
+    pub error_body_limit: usize,
+    /// What to do with response fields this client does not know (default: ignore)
+    ///
+    /// Unknown fields are normally dropped silently. Canary jobs against the live API can
+    /// set [`UnknownFieldPolicy::Warn`] or [`UnknownFieldPolicy::Deny`] to notice API
+    /// changes before fields go missing unnoticed.
+    pub unknown_fields: UnknownFieldPolicy,
+    /// Keep job details and search results in memory for repeated lookups (default: None)
+    ///
+    /// Shared by all clones of a client. Bypass it for a single call with
+    /// `Jobsuche::job_details_fresh`, `Search::list_fresh` or `RequestOptions::cache_bypass`,
+    /// and drop entries via `Jobsuche::cache`.
+    #[cfg(feature = "cache")]
+    pub cache: Option<CacheConfig>,
+    /// Where cached responses are stored (default: None, in memory)
+    ///
+    /// Use [`FileCache`](crate::FileCache) to keep responses between runs, or your
+    /// own [`Cache`] implementation. Setting a backend enables caching with the default
+    /// [`CacheConfig`] if [`cache`](Self::cache) is not set.
+    ///
+    /// Each client otherwise gets its own in-memory cache, so pass the same backend (e.g.
+    /// an `Arc<MemoryCache>`) to a `Jobsuche` and a `JobsucheAsync` built from one
+    /// [`ClientCore`] to let them share entries.
+    #[cfg(feature = "cache")]
+    pub cache_backend: Option<Arc<dyn Cache>>,
 }
 
 impl Default for ClientConfig {
@@ -35,7 +155,82 @@ impl Default for ClientConfig {
             connect_timeout: Duration::from_secs(10),
             max_retries: 3,
             retry_enabled: true,
+            on_request: None,
+            on_response: None,
+            capture_response_body: None,
+            on_metric: None,
+            request_id_header: Some(DEFAULT_REQUEST_ID_HEADER.to_string()),
+            root_certificates_pem: Vec::new(),
+            danger_accept_invalid_certs: false,
+            min_tls_version: None,
+            rate_limit: None,
+            hedge_after: None,
+            retry_deadline: None,
+            retry_wait_warning: Some(Duration::from_secs(30)),
+            slow_request_threshold: None,
+            validate_refnr: true,
+            error_body_limit: DEFAULT_ERROR_BODY_LIMIT,
+            unknown_fields: UnknownFieldPolicy::default(),
+            #[cfg(feature = "cache")]
+            cache: None,
+            #[cfg(feature = "cache")]
+            cache_backend: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Parse [`root_certificates_pem`](Self::root_certificates_pem) into reqwest certificates
+    pub(crate) fn root_certificates(&self) -> Result<Vec<reqwest::Certificate>> {
+        let mut certificates = Vec::new();
+        for (index, pem) in self.root_certificates_pem.iter().enumerate() {
+            let parsed =
+                reqwest::Certificate::from_pem_bundle(pem).map_err(|e| Error::ConfigError {
+                    message: format!("invalid root certificate #{}: {}", index, e),
+                })?;
+            if parsed.is_empty() {
+                return Err(Error::ConfigError {
+                    message: format!("root certificate #{} contains no PEM certificates", index),
+                });
+            }
+            certificates.extend(parsed);
         }
+        Ok(certificates)
+    }
+}
+
+impl std::fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ClientConfig");
+        debug
+            .field("timeout", &self.timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_enabled", &self.retry_enabled)
+            .field("on_request", &self.on_request.is_some())
+            .field("on_response", &self.on_response.is_some())
+            .field("capture_response_body", &self.capture_response_body)
+            .field("on_metric", &self.on_metric.is_some())
+            .field("request_id_header", &self.request_id_header)
+            .field("root_certificates_pem", &self.root_certificates_pem.len())
+            .field(
+                "danger_accept_invalid_certs",
+                &self.danger_accept_invalid_certs,
+            )
+            .field("min_tls_version", &self.min_tls_version)
+            .field("rate_limit", &self.rate_limit)
+            .field("hedge_after", &self.hedge_after)
+            .field("retry_deadline", &self.retry_deadline)
+            .field("retry_wait_warning", &self.retry_wait_warning)
+            .field("slow_request_threshold", &self.slow_request_threshold)
+            .field("validate_refnr", &self.validate_refnr)
+            .field("error_body_limit", &self.error_body_limit)
+            .field("unknown_fields", &self.unknown_fields);
+        #[cfg(feature = "cache")]
+        debug
+            .field("cache", &self.cache)
+            .field("cache_backend", &self.cache_backend);
+        debug.finish()
     }
 }
 
@@ -44,15 +239,14 @@ impl Default for ClientConfig {
 /// This is the main entry point for interacting with the Jobsuche API
 /// using synchronous/blocking requests.
 ///
+/// Cloning is cheap: all clones share the same connection pool and configuration.
+///
 /// # Example
 ///
 /// ```no_run
-/// use jobsuche::{Jobsuche, Credentials, SearchOptions};
+/// use jobsuche::{Jobsuche, SearchOptions};
 ///
-/// let client = Jobsuche::new(
-///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-///     Credentials::default()
-/// ).unwrap();
+/// let client = Jobsuche::default_client().unwrap();
 ///
 /// // Search for jobs
 /// let results = client.search()
@@ -67,9 +261,21 @@ impl Default for ClientConfig {
 /// ```
 #[derive(Clone, Debug)]
 pub struct Jobsuche {
-    pub(crate) core: ClientCore,
+    inner: Arc<ClientInner>,
+}
+
+/// State shared between clones of a [`Jobsuche`] client
+#[derive(Debug)]
+struct ClientInner {
+    core: ClientCore,
     client: Client,
     config: ClientConfig,
+    rate_limiter: Option<RateLimiter>,
+    token_manager: Option<TokenManager>,
+    #[cfg(feature = "cache")]
+    cache: Option<ResponseCache>,
+    #[cfg(feature = "metrics")]
+    metrics: ClientMetrics,
 }
 
 impl Jobsuche {
@@ -77,18 +283,15 @@ impl Jobsuche {
     ///
     /// # Arguments
     ///
-    /// * `host` - Base URL of the API (typically "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service")
+    /// * `host` - Base URL of the API (typically [`crate::DEFAULT_HOST`])
     /// * `credentials` - Authentication credentials (use `Credentials::default()` for the public API key)
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials};
+    /// use jobsuche::{Jobsuche, Credentials, DEFAULT_HOST};
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::new(DEFAULT_HOST, Credentials::default()).unwrap();
     /// ```
     pub fn new<H>(host: H, credentials: Credentials) -> Result<Jobsuche>
     where
@@ -97,12 +300,42 @@ impl Jobsuche {
         Self::with_config(host, credentials, ClientConfig::default())
     }
 
+    /// Creates a client for the public API ([`DEFAULT_HOST`]) using the default API key
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::Jobsuche;
+    ///
+    /// let client = Jobsuche::default_client().unwrap();
+    /// ```
+    pub fn default_client() -> Result<Jobsuche> {
+        Self::new(DEFAULT_HOST, Credentials::default())
+    }
+
+    /// Creates a client configured from the environment
+    ///
+    /// Reads the host from `JOBSUCHE_HOST` (default: [`DEFAULT_HOST`]) and the API key via
+    /// [`Credentials::from_env`]. Empty values are treated as unset.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::Jobsuche;
+    ///
+    /// let client = Jobsuche::from_env().unwrap();
+    /// ```
+    pub fn from_env() -> Result<Jobsuche> {
+        let host = env_var(HOST_ENV).unwrap_or_else(|| DEFAULT_HOST.to_string());
+        Self::new(host, Credentials::from_env())
+    }
+
     /// Creates a new instance with custom configuration
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials, ClientConfig};
+    /// use jobsuche::{Jobsuche, Credentials, ClientConfig, DEFAULT_HOST};
     /// use std::time::Duration;
     ///
     /// let config = ClientConfig {
@@ -112,7 +345,7 @@ impl Jobsuche {
     /// };
     ///
     /// let client = Jobsuche::with_config(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
+    ///     DEFAULT_HOST,
     ///     Credentials::default(),
     ///     config
     /// ).unwrap();
@@ -126,16 +359,7 @@ impl Jobsuche {
         H: Into<String>,
     {
         let core = ClientCore::new(host, credentials)?;
-        let client = Client::builder()
-            .timeout(config.timeout)
-            .connect_timeout(config.connect_timeout)
-            .build()?;
-
-        Ok(Jobsuche {
-            core,
-            client,
-            config,
-        })
+        Self::with_config_and_core(core, config)
     }
 
     /// Creates a new instance using a custom reqwest client
@@ -146,7 +370,7 @@ impl Jobsuche {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials, ClientConfig};
+    /// use jobsuche::{Jobsuche, Credentials, ClientConfig, DEFAULT_HOST};
     /// use reqwest::blocking::Client;
     /// use std::time::Duration;
     ///
@@ -156,7 +380,7 @@ impl Jobsuche {
     ///     .unwrap();
     ///
     /// let jobsuche = Jobsuche::from_client(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
+    ///     DEFAULT_HOST,
     ///     Credentials::default(),
     ///     client,
     ///     ClientConfig::default()
@@ -172,11 +396,7 @@ impl Jobsuche {
         H: Into<String>,
     {
         let core = ClientCore::new(host, credentials)?;
-        Ok(Jobsuche {
-            core,
-            client,
-            config,
-        })
+        Self::from_parts(core, client, config)
     }
 
     /// Creates a client instance directly from an existing ClientCore
@@ -188,18 +408,88 @@ impl Jobsuche {
 
     /// Creates a client instance from an existing ClientCore with custom config
     pub fn with_config_and_core(core: ClientCore, config: ClientConfig) -> Result<Jobsuche> {
-        let client = Client::builder()
-            .timeout(config.timeout)
-            .connect_timeout(config.connect_timeout)
-            .build()?;
+        // Building a blocking client spins up reqwest's internal runtime
+        let client = run_blocking(|| -> Result<Client> {
+            let mut builder = Client::builder()
+                .timeout(config.timeout)
+                .connect_timeout(config.connect_timeout)
+                .danger_accept_invalid_certs(config.danger_accept_invalid_certs);
+            for certificate in config.root_certificates()? {
+                builder = builder.add_root_certificate(certificate);
+            }
+            if let Some(version) = config.min_tls_version {
+                builder = builder.min_tls_version(version);
+            }
+            Ok(builder.build()?)
+        })?;
+
+        Self::from_parts(core, client, config)
+    }
 
+    /// Assemble a client; shared by the public constructors
+    fn from_parts(core: ClientCore, client: Client, config: ClientConfig) -> Result<Jobsuche> {
+        let rate_limiter = config.rate_limit.map(RateLimiter::new).transpose()?;
+        let token_manager = TokenManager::from_credentials(&core.credentials);
+        #[cfg(feature = "cache")]
+        let cache = ResponseCache::from_config(&config, core.host.as_str())?;
         Ok(Jobsuche {
-            core,
-            client,
-            config,
+            inner: Arc::new(ClientInner {
+                core,
+                client,
+                rate_limiter,
+                token_manager,
+                #[cfg(feature = "cache")]
+                cache,
+                #[cfg(feature = "metrics")]
+                metrics: ClientMetrics::default(),
+                config,
+            }),
         })
     }
 
+    /// Return a client for a different base URL
+    ///
+    /// The new client shares this client's HTTP connection pool and configuration and keeps
+    /// its credentials; see [`ClientCore::rebase`]. Client-side rate limiting, cached OAuth
+    /// tokens and the in-memory response cache start fresh for the new host; a shared
+    /// [`cache_backend`](crate::ClientConfig::cache_backend) keeps entries per host. This
+    /// client is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::Jobsuche;
+    ///
+    /// let production = Jobsuche::default_client().unwrap();
+    /// let staging = production.with_host("https://staging.example.com/jobsuche-service").unwrap();
+    /// ```
+    pub fn with_host<H>(&self, host: H) -> Result<Jobsuche>
+    where
+        H: Into<String>,
+    {
+        let core = self.inner.core.rebase(host)?;
+        Self::from_parts(core, self.inner.client.clone(), self.inner.config.clone())
+    }
+
+    /// Get the core (host and credentials) used by this client
+    pub fn core(&self) -> &ClientCore {
+        &self.inner.core
+    }
+
+    /// Get the configuration used by this client
+    pub fn config(&self) -> &ClientConfig {
+        &self.inner.config
+    }
+
+    /// Counters and latency statistics of the requests sent by this client and its clones
+    /// (requires the `metrics` feature)
+    ///
+    /// Independent of the recorder installed for the [`metrics`](crate::metrics) facade.
+    #[cfg(feature = "metrics")]
+    pub fn metrics(&self) -> MetricsSnapshot {
+        self.inner.metrics.snapshot()
+    }
+
     /// Return search interface
     pub fn search(&self) -> Search {
         Search::new(self)
@@ -216,25 +506,212 @@ impl Jobsuche {
     /// - Jobs may return 404 even if they appear in search results (Issue #61)
     /// - Reference numbers are base64-encoded for the API call
     ///
+    /// With [`ClientConfig::cache`](crate::ClientConfig::cache) set, details fetched within
+    /// the TTL are returned from the cache without a request. Expired details with an
+    /// `ETag` are revalidated with `If-None-Match`; see
+    /// [`CacheConfig::revalidate_for`](crate::CacheConfig::revalidate_for).
+    ///
+    /// # Errors
+    ///
+    /// `refnr` must be the decoded reference number. An already base64-encoded value (as
+    /// found in arbeitsagentur.de URLs) is rejected with [`Error::InvalidRefnr`] naming the
+    /// decoded form, rather than being used silently; see
+    /// [`ClientConfig::validate_refnr`](crate::ClientConfig::validate_refnr).
+    ///
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials};
+    /// use jobsuche::Jobsuche;
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::default_client().unwrap();
     ///
     /// let job = client.job_details("10001-1001601666-S").unwrap();
     /// if let Some(title) = &job.titel {
     ///     println!("Job title: {}", title);
     /// }
     /// ```
-    pub fn job_details(&self, refnr: &str) -> Result<JobDetails> {
+    pub fn job_details(&self, refnr: impl AsRef<str>) -> Result<JobDetails> {
+        let refnr = refnr.as_ref();
+        let _span = telemetry::details_span(refnr).entered();
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.inner.cache {
+            return self.cached_job_details(cache, refnr, false);
+        }
+        let path = self.job_details_path(refnr)?;
+        self.get(Endpoint::JobDetails, &path)
+            .map_err(|e| e.with_id(refnr))
+    }
+
+    /// Get job details from the API even if they are cached
+    ///
+    /// The response replaces the cached entry. Same as [`job_details`](Self::job_details)
+    /// if no cache is configured. Use this for periodic checks of a job that must see
+    /// changes right away.
+    ///
+    /// If the cached entry has an `ETag`, the request sends `If-None-Match`, and a
+    /// 304 Not Modified returns the cached details without downloading them again.
+    #[cfg(feature = "cache")]
+    pub fn job_details_fresh(&self, refnr: impl AsRef<str>) -> Result<JobDetails> {
+        let refnr = refnr.as_ref();
+        match &self.inner.cache {
+            Some(cache) => {
+                let _span = telemetry::details_span(refnr).entered();
+                self.cached_job_details(cache, refnr, true)
+            }
+            None => self.job_details(refnr),
+        }
+    }
+
+    /// Get job details only if they changed since `etag` was issued
+    ///
+    /// Sends `If-None-Match` when `etag` is given. A 304 response yields
+    /// [`Conditional::NotModified`] without attempting to deserialize a body; a 200 response
+    /// yields the details together with the new `ETag` (if the server sent one). Retries
+    /// follow the client configuration, so a transient 503 followed by a 304 still succeeds.
+    /// The response cache is neither read nor written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{Conditional, Jobsuche};
+    ///
+    /// let client = Jobsuche::default_client().unwrap();
+    ///
+    /// let first = client.job_details_conditional("10001-1001601666-S", None).unwrap();
+    /// let etag = first.etag().map(String::from);
+    ///
+    /// match client.job_details_conditional("10001-1001601666-S", etag.as_deref()).unwrap() {
+    ///     Conditional::NotModified => println!("Unchanged"),
+    ///     Conditional::Modified { value, .. } => println!("Updated: {:?}", value.titel),
+    /// }
+    /// ```
+    pub fn job_details_conditional(
+        &self,
+        refnr: impl AsRef<str>,
+        etag: Option<&str>,
+    ) -> Result<Conditional<JobDetails>> {
+        let refnr = refnr.as_ref();
+        let _span = telemetry::details_span(refnr).entered();
+        let path = self.job_details_path(refnr)?;
+        self.get_conditional(Endpoint::JobDetails, &path, etag)
+            .map_err(|e| e.with_id(refnr))
+    }
+
+    /// Invalidate or clear cached responses
+    ///
+    /// See [`CacheHandle`]; its methods do nothing if no cache is configured.
+    #[cfg(feature = "cache")]
+    pub fn cache(&self) -> CacheHandle<'_> {
+        CacheHandle::new(self.inner.cache.as_ref())
+    }
+
+    #[cfg(feature = "cache")]
+    pub(crate) fn response_cache(&self) -> Option<&ResponseCache> {
+        self.inner.cache.as_ref()
+    }
+
+    /// Fetch and cache the details of `refnrs` ahead of time
+    ///
+    /// Refnrs with fresh cached details, or a 404 remembered via
+    /// [`CacheConfig::cache_not_found`], are skipped. Stale details with an `ETag` are
+    /// revalidated. Requests go one after another through the client's retry configuration
+    /// and rate limiter; failures are counted in the report and do not stop the warmup.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::ConfigError`] if the client has no cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use jobsuche::{CacheConfig, ClientConfig, Credentials, Jobsuche, DEFAULT_HOST};
+    ///
+    /// let config = ClientConfig {
+    ///     cache: Some(CacheConfig::default()),
+    ///     ..Default::default()
+    /// };
+    /// let client = Jobsuche::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+    ///
+    /// let report = client
+    ///     .warm_details_cache(&["10001-1001601666-S", "10000-1199712489-S"])
+    ///     .unwrap();
+    /// println!("{} fetched, {} already cached", report.fetched, report.skipped);
+    /// ```
+    #[cfg(feature = "cache")]
+    pub fn warm_details_cache<R: AsRef<str>>(&self, refnrs: &[R]) -> Result<WarmupReport> {
+        let cache = self
+            .inner
+            .cache
+            .as_ref()
+            .ok_or_else(WarmupReport::no_cache)?;
+        let mut report = WarmupReport::default();
+        for refnr in refnrs {
+            let refnr = refnr.as_ref();
+            report.record(refnr, self.warm_details(cache, refnr));
+        }
+        Ok(report)
+    }
+
+    /// Fetch the details of `refnr` unless cached; true if a request was sent
+    #[cfg(feature = "cache")]
+    fn warm_details(&self, cache: &ResponseCache, refnr: &str) -> Result<bool> {
+        let _span = telemetry::details_span(refnr).entered();
+        let path = self.job_details_path(refnr)?;
+        let stale = match cache.lookup_details(refnr, false) {
+            DetailsLookup::Hit(_) | DetailsLookup::NotFound => return Ok(false),
+            DetailsLookup::Revalidate(stale) => Some(stale),
+            DetailsLookup::Miss => None,
+        };
+        self.fetch_details(cache, refnr, &path, stale)?;
+        Ok(true)
+    }
+
+    /// Job details via the cache; `fresh` skips fresh entries as for `job_details_fresh`
+    #[cfg(feature = "cache")]
+    fn cached_job_details(
+        &self,
+        cache: &ResponseCache,
+        refnr: &str,
+        fresh: bool,
+    ) -> Result<JobDetails> {
+        let path = self.job_details_path(refnr)?;
+        let stale = match cache.lookup_details(refnr, fresh) {
+            DetailsLookup::Hit(details) => return Ok(details),
+            DetailsLookup::NotFound => return Err(DetailsLookup::not_found(refnr, &path)),
+            DetailsLookup::Revalidate(stale) => Some(stale),
+            DetailsLookup::Miss => None,
+        };
+        self.fetch_details(cache, refnr, &path, stale)
+    }
+
+    /// Request the details of `refnr`, revalidating `stale`, and update the cache
+    #[cfg(feature = "cache")]
+    fn fetch_details(
+        &self,
+        cache: &ResponseCache,
+        refnr: &str,
+        path: &str,
+        stale: Option<StaleDetails>,
+    ) -> Result<JobDetails> {
+        let etag = stale.as_ref().and_then(|stale| stale.etag());
+        match self.get_conditional(Endpoint::JobDetails, path, etag) {
+            Ok(response) => Ok(cache.complete_details(refnr, stale, response)),
+            Err(e) => {
+                if e.is_not_found() {
+                    cache.store_not_found(refnr);
+                }
+                Err(e.with_id(refnr))
+            }
+        }
+    }
+
+    /// The validated path of the details of `refnr`
+    fn job_details_path(&self, refnr: &str) -> Result<String> {
+        if self.inner.config.validate_refnr {
+            validate_refnr(refnr)?;
+        }
         let encoded = encode_refnr(refnr);
-        let path = self.core.path(&["pc", "v4", "jobdetails", &encoded]);
-        self.get(&path)
+        Ok(self.inner.core.path(&["pc", "v4", "jobdetails", &encoded]))
     }
 
     /// Get the logo of an employer
@@ -252,12 +729,9 @@ impl Jobsuche {
     /// # Example
     ///
     /// ```no_run
-    /// use jobsuche::{Jobsuche, Credentials};
+    /// use jobsuche::Jobsuche;
     ///
-    /// let client = Jobsuche::new(
-    ///     "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
-    ///     Credentials::default()
-    /// ).unwrap();
+    /// let client = Jobsuche::default_client().unwrap();
     ///
     /// match client.employer_logo("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=") {
     ///     Ok(logo_bytes) => println!("Got logo: {} bytes", logo_bytes.len()),
@@ -265,184 +739,517 @@ impl Jobsuche {
     /// }
     /// ```
     pub fn employer_logo(&self, hash_id: &str) -> Result<Vec<u8>> {
-        let path = self.core.path(&["ed", "v1", "arbeitgeberlogo", hash_id]);
+        // Logos are not retried, so there is exactly one attempt
+        let span = telemetry::request_span(&Method::GET, Endpoint::EmployerLogo);
+        let _entered = span.enter();
+        span.record("attempts", 1);
+        let attempt = telemetry::attempt_span(1);
+        let started = Instant::now();
+        let result = run_blocking(|| attempt.in_scope(|| self.employer_logo_inner(hash_id)));
+        telemetry::record_result(&attempt, &result, started.elapsed());
+        telemetry::record_result(&span, &result, started.elapsed());
+        result
+    }
+
+    fn employer_logo_inner(&self, hash_id: &str) -> Result<Vec<u8>> {
+        let path = self
+            .inner
+            .core
+            .path(&["ed", "v1", "arbeitgeberlogo", hash_id]);
 
         let mut headers = HeaderMap::new();
-        headers.insert(
-            "X-API-Key",
-            HeaderValue::from_str(self.core.api_key()).unwrap(),
-        );
+        self.insert_auth_header(&mut headers)?;
         headers.insert(ACCEPT, HeaderValue::from_static("image/png"));
+        let request_id =
+            insert_request_id(&mut headers, self.inner.config.request_id_header.as_deref())?;
 
-        let response = self
+        self.wait_for_rate_limit();
+        let hook_attempt = hooks::Attempt {
+            method: &Method::GET,
+            endpoint: Endpoint::EmployerLogo,
+            url: &path,
+            number: 1,
+        };
+        hooks::notify_request(&self.inner.config, &hook_attempt, &headers);
+        let start = Instant::now();
+        let response = match self
+            .inner
             .client
             .request(Method::GET, &path)
             .headers(headers)
-            .send()?;
+            .send()
+        {
+            Ok(response) => response,
+            Err(e) => {
+                self.notify_metric(&Method::GET, Endpoint::EmployerLogo, None, start.elapsed());
+                return Err(e.into());
+            }
+        };
 
         let status = response.status();
+        let elapsed = start.elapsed();
+        self.notify_metric(&Method::GET, Endpoint::EmployerLogo, Some(status), elapsed);
+        telemetry::record_status(status);
         if !status.is_success() {
-            return Err(self.error_from_status(status, response));
+            hooks::notify_response(
+                &self.inner.config,
+                &hook_attempt,
+                status,
+                elapsed,
+                None,
+                response.content_length(),
+            );
+            return Err(self
+                .error_from_status(
+                    status,
+                    response,
+                    request_id,
+                    RequestContext::new(Endpoint::EmployerLogo, &path),
+                )
+                .with_id(hash_id));
         }
 
         let bytes = response.bytes()?.to_vec();
+        hooks::notify_response(
+            &self.inner.config,
+            &hook_attempt,
+            status,
+            elapsed,
+            None,
+            Some(bytes.len() as u64),
+        );
         Ok(bytes)
     }
 
     /// Internal method to perform GET requests with retry logic
-    pub(crate) fn get<T>(&self, path: &str) -> Result<T>
+    pub(crate) fn get<T>(&self, endpoint: Endpoint, path: &str) -> Result<T>
     where
-        T: DeserializeOwned,
+        T: DeserializeOwned + Send,
+    {
+        match self.get_conditional(endpoint, path, None)? {
+            Conditional::Modified { value, .. } => Ok(value),
+            // get_once only reports NotModified when an ETag was sent
+            Conditional::NotModified => unreachable!("304 without If-None-Match"),
+        }
+    }
+
+    /// Retrying GET that sends `If-None-Match` when `etag` is given
+    fn get_conditional<T>(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+    ) -> Result<Conditional<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let span = telemetry::request_span(&Method::GET, endpoint);
+        let _entered = span.enter();
+        let started = Instant::now();
+        let mut waits = RetryWaits::default();
+        let mut attempts = 0;
+        let result = self.get_retrying(endpoint, path, etag, &mut attempts, &mut waits);
+        span.record("attempts", attempts);
+        telemetry::record_result(&span, &result, started.elapsed());
+        waits.warn_if_over(self.inner.config.retry_wait_warning, endpoint);
+        result
+    }
+
+    /// Retry loop of [`get_conditional`](Self::get_conditional), counting the attempts in
+    /// `attempts` and the sleeps in `waits`
+    fn get_retrying<T>(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+        attempts: &mut u32,
+        waits: &mut RetryWaits,
+    ) -> Result<Conditional<T>>
+    where
+        T: DeserializeOwned + Send,
     {
-        if !self.config.retry_enabled {
-            return self.get_once(path);
+        if !self.inner.config.retry_enabled {
+            *attempts = 1;
+            return self.get_attempt(1, endpoint, path, etag).0;
         }
 
         // Build exponential backoff strategy
         let backoff = ExponentialBuilder::default()
-            .with_max_times(self.config.max_retries as usize)
+            .with_max_times(self.inner.config.max_retries as usize)
             .with_max_delay(Duration::from_secs(60));
 
         let mut attempt = 0;
         let mut backoff_iter = backoff.build();
+        let started = Instant::now();
 
         loop {
             attempt += 1;
+            *attempts = attempt;
             debug!(
                 "GET {} (attempt {}/{})",
                 path,
                 attempt,
-                self.config.max_retries + 1
+                self.inner.config.max_retries + 1
             );
 
-            match self.get_once(path) {
+            let (result, attempt_span) = self.get_attempt(attempt, endpoint, path, etag);
+            match result {
                 Ok(result) => return Ok(result),
                 Err(e) => {
-                    // Check if error is retryable
-                    let should_retry = matches!(
-                        e,
-                        Error::Http(_)
-                            | Error::RateLimited { .. }
-                            | Error::Fault {
-                                code: StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT,
-                                ..
-                            }
-                    );
-
-                    if !should_retry || attempt > self.config.max_retries {
+                    if !e.is_retryable() {
                         return Err(e);
                     }
+                    if attempt > self.inner.config.max_retries {
+                        return Err(e.retries_exhausted(attempt, started.elapsed()));
+                    }
 
                     // Handle rate limiting with Retry-After
-                    if let Error::RateLimited {
-                        retry_after: Some(seconds),
+                    let (delay, reason) = if let Error::RateLimited {
+                        retry_after: Some(wait),
+                        ..
                     } = e
                     {
-                        let duration = Duration::from_secs(seconds);
                         warn!(
-                            "Rate limited, waiting {} seconds as requested by server (attempt {}/{})",
-                            seconds, attempt, self.config.max_retries
+                            "Rate limited, waiting {:?} as requested by server (attempt {}/{})",
+                            wait,
+                            attempt,
+                            self.inner.config.max_retries + 1
                         );
-                        thread::sleep(duration);
+                        (wait, WaitReason::RetryAfter)
                     } else if let Some(duration) = backoff_iter.next() {
                         warn!(
                             "Request failed ({}), retrying in {:?}... (attempt {}/{})",
-                            e, duration, attempt, self.config.max_retries
+                            e,
+                            duration,
+                            attempt,
+                            self.inner.config.max_retries + 1
                         );
-                        thread::sleep(duration);
+                        (duration, WaitReason::Backoff)
                     } else {
-                        return Err(e);
+                        return Err(e.retries_exhausted(attempt, started.elapsed()));
+                    };
+
+                    if let Some(deadline) = self.inner.config.retry_deadline {
+                        if started.elapsed() + delay > deadline {
+                            warn!(
+                                "Retry deadline of {:?} would be exceeded, giving up after attempt {}",
+                                deadline, attempt
+                            );
+                            return Err(e.retries_exhausted(attempt, started.elapsed()));
+                        }
                     }
+
+                    telemetry::record_retry_after(&attempt_span, delay);
+                    waits.record(reason, delay);
+                    #[cfg(feature = "metrics")]
+                    self.inner.metrics.record_retry(endpoint, reason, delay);
+                    thread::sleep(delay);
                 }
             }
         }
     }
 
+    /// One attempt in its own `jobsuche.attempt` span, returned for recording the retry delay
+    fn get_attempt<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+    ) -> (Result<Conditional<T>>, Span)
+    where
+        T: DeserializeOwned + Send,
+    {
+        let span = telemetry::attempt_span(attempt);
+        let started = Instant::now();
+        let result = span.in_scope(|| self.get_once(attempt, endpoint, path, etag));
+        telemetry::record_result(&span, &result, started.elapsed());
+        (result, span)
+    }
+
+    /// Add the API key or, for OAuth credentials, a bearer token to `headers`
+    fn insert_auth_header(&self, headers: &mut HeaderMap) -> Result<()> {
+        let Some(tokens) = &self.inner.token_manager else {
+            headers.insert(
+                hooks::API_KEY_HEADER,
+                HeaderValue::from_str(self.inner.core.api_key()).unwrap(),
+            );
+            return Ok(());
+        };
+
+        let token = match tokens.cached_token() {
+            Some(token) => token,
+            None => self.fetch_token(tokens)?,
+        };
+        headers.insert(AUTHORIZATION, auth::bearer_header(&token)?);
+        Ok(())
+    }
+
+    /// Request a new OAuth token from the token endpoint and cache it
+    fn fetch_token(&self, tokens: &TokenManager) -> Result<String> {
+        debug!("Fetching OAuth token from {}", tokens.token_url());
+        let response = self
+            .inner
+            .client
+            .post(tokens.token_url())
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(ACCEPT, "application/json")
+            .body(tokens.request_body())
+            .send()
+            .map_err(|e| tokens.error(e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(tokens.error(format!("status {}", status)));
+        }
+        let body = response.bytes().map_err(|e| tokens.error(e))?;
+        tokens.store(&body)
+    }
+
+    /// Record a request attempt with the client's metrics and the metrics hook, and warn if
+    /// it was slow
+    fn notify_metric(
+        &self,
+        method: &Method,
+        endpoint: Endpoint,
+        status: Option<StatusCode>,
+        duration: Duration,
+    ) {
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.record(endpoint, status, duration);
+        let threshold = self.inner.config.slow_request_threshold;
+        if telemetry::warn_if_slow(threshold, endpoint, status, duration) {
+            #[cfg(feature = "metrics")]
+            self.inner.metrics.record_slow(endpoint);
+        }
+        hooks::notify_metric(&self.inner.config, method, endpoint, status, duration);
+    }
+
     /// Perform a single GET request without retry
-    fn get_once<T>(&self, path: &str) -> Result<T>
+    fn get_once<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+    ) -> Result<Conditional<T>>
+    where
+        T: DeserializeOwned + Send,
+    {
+        // The request may run on another thread, which does not inherit the current span
+        let span = Span::current();
+        run_blocking(|| span.in_scope(|| self.get_once_inner(attempt, endpoint, path, etag)))
+    }
+
+    fn get_once_inner<T>(
+        &self,
+        attempt: u32,
+        endpoint: Endpoint,
+        path: &str,
+        etag: Option<&str>,
+    ) -> Result<Conditional<T>>
     where
         T: DeserializeOwned,
     {
         let mut headers = HeaderMap::new();
-        headers.insert(
-            "X-API-Key",
-            HeaderValue::from_str(self.core.api_key()).unwrap(),
-        );
+        self.insert_auth_header(&mut headers)?;
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        if let Some(etag) = etag {
+            let value = HeaderValue::from_str(etag).map_err(|e| Error::ConfigError {
+                message: format!("invalid ETag {:?}: {}", etag, e),
+            })?;
+            headers.insert(IF_NONE_MATCH, value);
+        }
+        let request_id =
+            insert_request_id(&mut headers, self.inner.config.request_id_header.as_deref())?;
 
-        let response = self
+        self.wait_for_rate_limit();
+        let hook_attempt = hooks::Attempt {
+            method: &Method::GET,
+            endpoint,
+            url: path,
+            number: attempt,
+        };
+        hooks::notify_request(&self.inner.config, &hook_attempt, &headers);
+        let start = Instant::now();
+
+        let response = match self
+            .inner
             .client
             .request(Method::GET, path)
             .headers(headers)
-            .send()?;
+            .send()
+        {
+            Ok(response) => response,
+            Err(e) => {
+                self.notify_metric(&Method::GET, endpoint, None, start.elapsed());
+                return Err(e.into());
+            }
+        };
 
         let status = response.status();
-        debug!("Response status: {}", status);
+        let elapsed = start.elapsed();
+        self.notify_metric(&Method::GET, endpoint, Some(status), elapsed);
+        telemetry::record_status(status);
+        debug!(
+            "Response status: {} (request id: {})",
+            status,
+            request_id.as_deref().unwrap_or("none")
+        );
+
+        if status == StatusCode::NOT_MODIFIED && etag.is_some() {
+            hooks::notify_response(
+                &self.inner.config,
+                &hook_attempt,
+                status,
+                elapsed,
+                None,
+                response.content_length(),
+            );
+            return Ok(Conditional::NotModified);
+        }
 
         if !status.is_success() {
-            return Err(self.error_from_status(status, response));
+            hooks::notify_response(
+                &self.inner.config,
+                &hook_attempt,
+                status,
+                elapsed,
+                None,
+                response.content_length(),
+            );
+            return Err(self.error_from_status(
+                status,
+                response,
+                request_id,
+                RequestContext::new(endpoint, path),
+            ));
         }
 
-        let result = response.json::<T>()?;
-        Ok(result)
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+
+        // Buffer the body ourselves (as `Response::json` would) so decode errors can show it
+        let bytes = response.bytes()?;
+        hooks::notify_response(
+            &self.inner.config,
+            &hook_attempt,
+            status,
+            elapsed,
+            Some(&bytes[..]),
+            None,
+        );
+        let value = decode_json(
+            &bytes,
+            self.inner.config.error_body_limit,
+            self.inner.config.unknown_fields,
+            self.inner.core.api_key(),
+        )?;
+        Ok(Conditional::Modified { value, etag })
+    }
+
+    /// Wait for a free slot if a client-side rate limit is configured
+    fn wait_for_rate_limit(&self) {
+        if let Some(limiter) = &self.inner.rate_limiter {
+            let wait = limiter.reserve();
+            if !wait.is_zero() {
+                debug!("Client-side rate limit reached, waiting {:?}", wait);
+                thread::sleep(wait);
+            }
+        }
     }
 
     /// Convert HTTP status and response into an appropriate Error
     fn error_from_status(
         &self,
         status: StatusCode,
-        mut response: reqwest::blocking::Response,
+        response: reqwest::blocking::Response,
+        request_id: Option<String>,
+        request: RequestContext,
     ) -> Error {
+        let resource = request.endpoint.into();
+        let request = Some(Box::new(request));
         match status {
-            StatusCode::UNAUTHORIZED => Error::Unauthorized,
-            StatusCode::FORBIDDEN => Error::Forbidden,
-            StatusCode::NOT_FOUND => Error::NotFound,
-            StatusCode::METHOD_NOT_ALLOWED => Error::MethodNotAllowed,
+            StatusCode::BAD_REQUEST => {
+                let body = response.bytes().unwrap_or_default();
+                let errors = serde_json::from_slice::<ApiErrors>(&body).unwrap_or_default();
+                Error::InvalidRequest {
+                    messages: errors.messages(),
+                    request,
+                    body: fault_body(
+                        &body,
+                        self.inner.config.error_body_limit,
+                        self.inner.core.api_key(),
+                    ),
+                }
+            }
+            StatusCode::UNAUTHORIZED => Error::Unauthorized {
+                context: None,
+                request,
+            },
+            StatusCode::FORBIDDEN => Error::Forbidden { request },
+            StatusCode::NOT_FOUND => Error::NotFound {
+                resource,
+                id: None,
+                request,
+            },
+            StatusCode::METHOD_NOT_ALLOWED => Error::MethodNotAllowed { request },
             StatusCode::TOO_MANY_REQUESTS => {
-                // Parse Retry-After header if present
-                let retry_after = response
-                    .headers()
-                    .get("Retry-After")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|s| {
-                        // Try parsing as delay-seconds (numeric)
-                        if let Ok(seconds) = s.parse::<u64>() {
-                            return Some(seconds);
-                        }
-
-                        // Try parsing as HTTP-date
-                        if let Ok(date) = httpdate::parse_http_date(s) {
-                            if let Ok(duration) = date.duration_since(std::time::SystemTime::now())
-                            {
-                                return Some(duration.as_secs());
-                            }
-                        }
-
-                        warn!("Retry-After header present but unparseable: {:?}", s);
-                        None
-                    });
-
-                Error::RateLimited { retry_after }
+                let (retry_after, retry_at) =
+                    parse_retry_after(response.headers(), SystemTime::now());
+                Error::RateLimited {
+                    retry_after,
+                    retry_at,
+                    request,
+                }
             }
             _ => {
-                // Try to parse error response
-                let mut body = String::new();
-                if response.read_to_string(&mut body).is_ok() {
-                    if let Ok(api_errors) = serde_json::from_str::<ApiErrors>(&body) {
-                        return Error::Fault {
-                            code: status,
-                            errors: api_errors,
-                        };
-                    }
+                // Keep the raw body even if it is not an API error response (e.g. HTML pages)
+                let body = response.bytes().unwrap_or_default();
+                let errors = serde_json::from_slice::<ApiErrors>(&body).unwrap_or_default();
+                Error::Fault {
+                    code: status,
+                    errors,
+                    request_id,
+                    request,
+                    body: fault_body(
+                        &body,
+                        self.inner.config.error_body_limit,
+                        self.inner.core.api_key(),
+                    ),
                 }
-                // Fallback to generic HTTP error
-                Error::Http(response.error_for_status().unwrap_err())
             }
         }
     }
 }
 
+/// Run a blocking reqwest operation, moving it off the current thread inside a tokio runtime
+///
+/// reqwest's blocking client panics when used from within an async context. Instead of
+/// panicking, the operation runs on a scoped thread; the calling task is still blocked
+/// until it finishes, so async code should prefer `JobsucheAsync`.
+fn run_blocking<T, F>(f: F) -> T
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    #[cfg(feature = "async")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        debug!("Blocking client used inside a tokio runtime, running request on a separate thread");
+        return thread::scope(|scope| {
+            scope
+                .spawn(f)
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))
+        });
+    }
+    f()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -456,6 +1263,26 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[test]
+    fn test_default_client() {
+        let client = Jobsuche::default_client().unwrap();
+        assert_eq!(client.core().host.as_str(), DEFAULT_HOST);
+    }
+
+    #[test]
+    fn test_clone_shares_inner_state() {
+        let config = ClientConfig {
+            max_retries: 7,
+            ..Default::default()
+        };
+        let client = Jobsuche::with_config(DEFAULT_HOST, Credentials::default(), config).unwrap();
+        let cloned = client.clone();
+
+        assert!(Arc::ptr_eq(&client.inner, &cloned.inner));
+        assert_eq!(cloned.config().max_retries, 7);
+        assert_eq!(cloned.core().host.as_str(), DEFAULT_HOST);
+    }
+
     #[test]
     fn test_invalid_url() {
         let client = Jobsuche::new("not a url", Credentials::default());
@@ -0,0 +1,198 @@
+//! Tracing spans around API requests
+//!
+//! Every retrying GET runs in a `jobsuche.request` span (info level) with one
+//! `jobsuche.attempt` child span per attempt:
+//!
+//! | Span | Fields |
+//! |------|--------|
+//! | `jobsuche.request` | `endpoint`, `method`, `attempts`, `duration_ms`, `error` |
+//! | `jobsuche.attempt` | `attempt`, `status`, `duration_ms`, `retry_after_ms`, `error` |
+//!
+//! `endpoint` is the [`Endpoint::as_str`] name and `error` the [`ErrorKind`] name. Details
+//! lookups add a debug-level `jobsuche.job_details` parent span carrying the `refnr`, so
+//! reference numbers only show up at debug verbosity.
+//!
+//! [`RetryWaits`] adds up the retry sleeps of one request and warns if they get long, and
+//! [`warn_if_slow`] warns about single attempts slower than
+//! [`ClientConfig::slow_request_threshold`](crate::ClientConfig::slow_request_threshold).
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use tracing::field::Empty;
+use tracing::{debug_span, info_span, warn, Span};
+
+use crate::hooks::Endpoint;
+use crate::Error;
+
+/// Span of a whole request, including retries
+pub(crate) fn request_span(method: &Method, endpoint: Endpoint) -> Span {
+    info_span!(
+        "jobsuche.request",
+        endpoint = endpoint.as_str(),
+        method = %method,
+        attempts = Empty,
+        duration_ms = Empty,
+        error = Empty,
+    )
+}
+
+/// Span of one attempt, a child of the current [`request_span`]
+pub(crate) fn attempt_span(attempt: u32) -> Span {
+    info_span!(
+        "jobsuche.attempt",
+        attempt,
+        status = Empty,
+        duration_ms = Empty,
+        retry_after_ms = Empty,
+        error = Empty,
+    )
+}
+
+/// Debug-level span of a details lookup
+pub(crate) fn details_span(refnr: &str) -> Span {
+    debug_span!("jobsuche.job_details", refnr)
+}
+
+/// Record the response status on the current attempt
+pub(crate) fn record_status(status: StatusCode) {
+    Span::current().record("status", status.as_u16());
+}
+
+/// Record the outcome of an attempt or request on `span`
+pub(crate) fn record_result<T>(span: &Span, result: &Result<T, Error>, duration: Duration) {
+    span.record("duration_ms", millis(duration));
+    if let Err(e) = result {
+        span.record("error", e.kind().as_str());
+    }
+}
+
+/// Record the wait before the next attempt on an attempt span
+pub(crate) fn record_retry_after(span: &Span, delay: Duration) {
+    span.record("retry_after_ms", millis(delay));
+}
+
+/// Warn if an attempt took longer than `threshold`; true if it did
+pub(crate) fn warn_if_slow(
+    threshold: Option<Duration>,
+    endpoint: Endpoint,
+    status: Option<StatusCode>,
+    duration: Duration,
+) -> bool {
+    let Some(threshold) = threshold.filter(|threshold| duration > *threshold) else {
+        return false;
+    };
+    warn!(
+        endpoint = endpoint.as_str(),
+        duration_ms = millis(duration),
+        "Slow request to {}: {:?} until {} (threshold {:?})",
+        endpoint.template(),
+        duration,
+        status.map_or_else(
+            || "the attempt failed".to_string(),
+            |s| format!("status {}", s.as_u16())
+        ),
+        threshold
+    );
+    true
+}
+
+/// Why the retry loop sleeps before the next attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitReason {
+    /// The server asked for the wait with `Retry-After`
+    RetryAfter,
+    /// Exponential backoff after a transient error
+    Backoff,
+}
+
+#[cfg(feature = "metrics")]
+impl WaitReason {
+    /// Label value (`retry_after` or `backoff`)
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            WaitReason::RetryAfter => "retry_after",
+            WaitReason::Backoff => "backoff",
+        }
+    }
+}
+
+/// Retry sleeps of one request
+#[derive(Debug, Default)]
+pub(crate) struct RetryWaits {
+    total: Duration,
+    retry_after: u32,
+    backoff: u32,
+}
+
+impl RetryWaits {
+    pub(crate) fn record(&mut self, reason: WaitReason, delay: Duration) {
+        self.total += delay;
+        match reason {
+            WaitReason::RetryAfter => self.retry_after += 1,
+            WaitReason::Backoff => self.backoff += 1,
+        }
+    }
+
+    /// Warn if the sleeps added up to more than `threshold`
+    pub(crate) fn warn_if_over(&self, threshold: Option<Duration>, endpoint: Endpoint) {
+        if threshold.is_some_and(|threshold| self.total > threshold) {
+            warn!(
+                "Request to {} spent {:?} waiting for retries ({} Retry-After waits, {} backoff sleeps)",
+                endpoint.template(),
+                self.total,
+                self.retry_after,
+                self.backoff
+            );
+        }
+    }
+}
+
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_test::traced_test;
+
+    #[test]
+    #[traced_test]
+    fn test_warn_if_slow() {
+        let ms = Duration::from_millis;
+        assert!(!warn_if_slow(None, Endpoint::Jobs, None, ms(5000)));
+        assert!(!warn_if_slow(Some(ms(100)), Endpoint::Jobs, None, ms(100)));
+        assert!(!logs_contain("Slow request"));
+
+        assert!(warn_if_slow(
+            Some(ms(100)),
+            Endpoint::JobDetails,
+            Some(StatusCode::OK),
+            ms(250)
+        ));
+        assert!(logs_contain(
+            "Slow request to /pc/v4/jobdetails/{refnr}: 250ms until status 200 (threshold 100ms)"
+        ));
+        assert!(logs_contain("duration_ms=250"));
+
+        assert!(warn_if_slow(Some(ms(100)), Endpoint::Jobs, None, ms(101)));
+        assert!(logs_contain("101ms until the attempt failed"));
+    }
+
+    #[test]
+    #[traced_test]
+    fn test_retry_waits_warn_over_threshold() {
+        let mut waits = RetryWaits::default();
+        waits.record(WaitReason::RetryAfter, Duration::from_secs(20));
+        waits.record(WaitReason::Backoff, Duration::from_secs(2));
+
+        waits.warn_if_over(Some(Duration::from_secs(30)), Endpoint::Jobs);
+        waits.warn_if_over(None, Endpoint::Jobs);
+        assert!(!logs_contain("waiting for retries"));
+
+        waits.warn_if_over(Some(Duration::from_secs(20)), Endpoint::Jobs);
+        assert!(logs_contain("/pc/v4/jobs spent 22s waiting for retries"));
+        assert!(logs_contain("1 Retry-After waits, 1 backoff sleeps"));
+    }
+}
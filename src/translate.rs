@@ -0,0 +1,117 @@
+//! English names for German API terms
+//!
+//! The API and this crate's `Display` implementations use German. The filter enums have
+//! `english_name()` methods, and [`to_english`] covers the same values when they come in
+//! as plain strings, along with facet group names.
+//!
+//! # Example
+//!
+//! ```
+//! use jobsuche::translate::to_english;
+//!
+//! assert_eq!(to_english("unbefristet"), Some("Permanent"));
+//! assert_eq!(to_english("Vollzeit"), Some("Full-time"));
+//! assert_eq!(to_english("veroeffentlichtseit"), Some("Published within (days)"));
+//! assert_eq!(to_english("Koch/Köchin"), None);
+//! ```
+
+use crate::{Angebotsart, Arbeitszeit, Befristung};
+
+/// Facet group names and other terms that are not filter enum values
+const TERMS: &[(&str, &str)] = &[
+    ("arbeitszeit", "Working time"),
+    ("befristung", "Contract type"),
+    ("angebotsart", "Offer type"),
+    ("zeitarbeit", "Temporary agency work"),
+    ("branche", "Industry"),
+    ("beruf", "Occupation"),
+    ("arbeitgeber", "Employer"),
+    ("arbeitsort", "Work location"),
+    ("veroeffentlichtseit", "Published within (days)"),
+    ("externestellenboersen", "External job boards"),
+    ("behinderung", "Disability"),
+    ("eintrittsdatum", "Start date"),
+    ("Arbeitnehmerüberlassung", "Temporary agency work"),
+    (
+        "Vergütung nach Tarifvertrag",
+        "Pay according to collective agreement",
+    ),
+];
+
+/// English name of a German term, ignoring case
+///
+/// Accepts the names of [`Arbeitszeit`], [`Befristung`] and [`Angebotsart`] values (not
+/// their numeric or short API codes, which are ambiguous) and the facet group names.
+pub fn to_english(term: &str) -> Option<&'static str> {
+    let term = term.trim();
+
+    if let Ok(arbeitszeit) = term.parse::<Arbeitszeit>() {
+        if !arbeitszeit.as_str().eq_ignore_ascii_case(term) {
+            return Some(arbeitszeit.english_name());
+        }
+    }
+    if let Ok(befristung) = term.parse::<Befristung>() {
+        if befristung.as_str() != term {
+            return Some(befristung.english_name());
+        }
+    }
+    if let Ok(angebotsart) = term.parse::<Angebotsart>() {
+        if angebotsart.as_str() != term {
+            return Some(angebotsart.english_name());
+        }
+    }
+
+    let term = term.to_lowercase();
+    TERMS
+        .iter()
+        .find(|(german, _)| german.to_lowercase() == term)
+        .map(|&(_, english)| english)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enum_names() {
+        assert_eq!(to_english("Vollzeit"), Some("Full-time"));
+        assert_eq!(to_english(" teilzeit "), Some("Part-time"));
+        assert_eq!(to_english("HEIM_TELEARBEIT"), Some("Remote work"));
+        assert_eq!(to_english("befristet"), Some("Fixed-term"));
+        assert_eq!(to_english("Selbständigkeit"), Some("Self-employment"));
+
+        // API codes are not translated
+        assert_eq!(to_english("vz"), None);
+        assert_eq!(to_english("1"), None);
+        assert_eq!(to_english("34"), None);
+    }
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(to_english("Branche"), Some("Industry"));
+        assert_eq!(
+            to_english("arbeitnehmerüberlassung"),
+            Some("Temporary agency work")
+        );
+        assert_eq!(to_english(""), None);
+        assert_eq!(to_english("Gleitzeit"), None);
+    }
+
+    #[test]
+    fn test_terms_are_unique_and_not_enum_values() {
+        for (index, (german, english)) in TERMS.iter().enumerate() {
+            assert!(!english.is_empty(), "{}", german);
+            assert!(
+                TERMS[..index]
+                    .iter()
+                    .all(|(other, _)| other.to_lowercase() != german.to_lowercase()),
+                "duplicate term {}",
+                german
+            );
+            // An enum name would shadow the entry
+            assert!(german.parse::<Arbeitszeit>().is_err(), "{}", german);
+            assert!(german.parse::<Befristung>().is_err(), "{}", german);
+            assert!(german.parse::<Angebotsart>().is_err(), "{}", german);
+        }
+    }
+}
@@ -0,0 +1,453 @@
+//! Periodic polling for newly published jobs
+//!
+//! A watcher repeats a search at a fixed interval and hands every listing whose `refnr` it
+//! has not seen before to a callback or channel. The first poll delivers all current
+//! listings; later polls only deliver listings that appeared since.
+//!
+//! Polls go through the client, so its [`RateLimit`](crate::RateLimit), retries and hooks
+//! apply. A failed poll is logged and retried at the next interval (or after the server's
+//! `Retry-After`, if that is longer); it never ends the watcher.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use tracing::{debug, warn};
+
+use crate::sync::Jobsuche;
+use crate::{Error, JobListing, Refnr, Result, SearchOptions};
+
+#[cfg(feature = "async")]
+use crate::async_client::JobsucheAsync;
+#[cfg(feature = "async")]
+use futures::StreamExt;
+#[cfg(feature = "async")]
+use tokio::sync::watch;
+
+/// Seen reference numbers and the delivery target, shared by the sync and async watchers
+struct WatchState<F> {
+    interval: Duration,
+    seen: HashSet<Refnr>,
+    deliver: F,
+    /// Set once `deliver` reported that nobody is listening any more
+    closed: bool,
+}
+
+impl<F: FnMut(JobListing) -> bool> WatchState<F> {
+    fn new(interval: Duration, deliver: F) -> Result<Self> {
+        if interval.is_zero() {
+            return Err(Error::ConfigError {
+                message: "watcher poll interval must be greater than 0".to_string(),
+            });
+        }
+        Ok(Self {
+            interval,
+            seen: HashSet::new(),
+            deliver,
+            closed: false,
+        })
+    }
+
+    /// Deliver `listing` if it is new; returns false once delivery is closed
+    fn offer(&mut self, listing: JobListing, new: &mut usize) -> bool {
+        if self.seen.insert(listing.refnr.clone()) {
+            *new += 1;
+            self.closed = !(self.deliver)(listing);
+        }
+        !self.closed
+    }
+
+    /// Delay until the next poll after a poll finished with `result`
+    fn next_delay(&self, result: &Result<usize>) -> Duration {
+        match result {
+            Ok(new) => {
+                debug!(new, seen = self.seen.len(), "Watcher poll finished");
+                self.interval
+            }
+            Err(error) => {
+                let retry_after = match error.root() {
+                    Error::RateLimited { retry_after, .. } => *retry_after,
+                    _ => None,
+                };
+                let delay = self.interval.max(retry_after.unwrap_or_default());
+                warn!(%error, delay_ms = delay.as_millis() as u64, "Watcher poll failed");
+                delay
+            }
+        }
+    }
+}
+
+/// Stop flag the watcher thread sleeps on
+#[derive(Debug, Default)]
+struct StopSignal {
+    stopped: Mutex<bool>,
+    wake: Condvar,
+}
+
+impl StopSignal {
+    fn stop(&self) {
+        *self.stopped.lock().unwrap_or_else(|e| e.into_inner()) = true;
+        self.wake.notify_all();
+    }
+
+    fn is_stopped(&self) -> bool {
+        *self.stopped.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Sleep for `timeout` or until stopped; returns whether the watcher was stopped
+    fn wait(&self, timeout: Duration) -> bool {
+        let stopped = self.stopped.lock().unwrap_or_else(|e| e.into_inner());
+        let (stopped, _) = self
+            .wake
+            .wait_timeout_while(stopped, timeout, |stopped| !*stopped)
+            .unwrap_or_else(|e| e.into_inner());
+        *stopped
+    }
+}
+
+/// Background thread polling a search for new listings
+///
+/// Created with [`spawn`](Self::spawn) (callback) or [`channel`](Self::channel). The thread
+/// runs until [`stop`](Self::stop) is called or the watcher is dropped; both wait for an
+/// in-flight request to finish. A channel watcher also ends once its receiver is dropped.
+///
+/// Seen reference numbers are kept for the lifetime of the watcher, so a listing that
+/// disappears from the results and comes back later is not delivered again.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{Jobsuche, SearchOptions};
+/// use jobsuche::watch::JobWatcher;
+/// use std::time::Duration;
+///
+/// let client = Jobsuche::default_client().unwrap();
+/// let options = SearchOptions::builder().was("Rust").veroeffentlichtseit(1).build();
+///
+/// let interval = Duration::from_secs(600);
+/// let (watcher, jobs) = JobWatcher::channel(&client, options, interval).unwrap();
+/// for job in jobs.iter().take(10) {
+///     println!("New: {} ({})", job.titel.as_deref().unwrap_or("?"), job.refnr);
+/// }
+/// watcher.stop();
+/// ```
+#[derive(Debug)]
+pub struct JobWatcher {
+    signal: Arc<StopSignal>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl JobWatcher {
+    /// Poll `options` every `interval`, calling `on_new` for each new listing
+    ///
+    /// The first poll starts immediately. Fails with [`Error::ConfigError`] for a zero
+    /// interval, or [`Error::IO`] if the thread cannot be spawned.
+    pub fn spawn<F>(
+        client: &Jobsuche,
+        options: SearchOptions,
+        interval: Duration,
+        mut on_new: F,
+    ) -> Result<JobWatcher>
+    where
+        F: FnMut(JobListing) + Send + 'static,
+    {
+        Self::start(client, options, interval, move |listing| {
+            on_new(listing);
+            true
+        })
+    }
+
+    /// Poll `options` every `interval`, sending each new listing to the returned receiver
+    pub fn channel(
+        client: &Jobsuche,
+        options: SearchOptions,
+        interval: Duration,
+    ) -> Result<(JobWatcher, std::sync::mpsc::Receiver<JobListing>)> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let watcher = Self::start(client, options, interval, move |listing| {
+            sender.send(listing).is_ok()
+        })?;
+        Ok((watcher, receiver))
+    }
+
+    fn start<F>(
+        client: &Jobsuche,
+        options: SearchOptions,
+        interval: Duration,
+        deliver: F,
+    ) -> Result<JobWatcher>
+    where
+        F: FnMut(JobListing) -> bool + Send + 'static,
+    {
+        let mut state = WatchState::new(interval, deliver)?;
+        let signal = Arc::new(StopSignal::default());
+        let client = client.clone();
+        let thread_signal = Arc::clone(&signal);
+        let handle = thread::Builder::new()
+            .name("jobsuche-watcher".to_string())
+            .spawn(move || loop {
+                let result = Self::poll(&client, &options, &mut state, &thread_signal);
+                if state.closed {
+                    debug!("Watcher receiver dropped, stopping");
+                    return;
+                }
+                let delay = state.next_delay(&result);
+                if thread_signal.wait(delay) {
+                    return;
+                }
+            })?;
+
+        Ok(JobWatcher {
+            signal,
+            handle: Some(handle),
+        })
+    }
+
+    fn poll<F: FnMut(JobListing) -> bool>(
+        client: &Jobsuche,
+        options: &SearchOptions,
+        state: &mut WatchState<F>,
+        signal: &StopSignal,
+    ) -> Result<usize> {
+        let mut new = 0;
+        for listing in client.search().jobs(options.clone())? {
+            if !state.offer(listing?, &mut new) || signal.is_stopped() {
+                break;
+            }
+        }
+        Ok(new)
+    }
+
+    /// Whether the polling thread has ended, e.g. because the receiver was dropped
+    pub fn is_finished(&self) -> bool {
+        self.handle.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Stop polling and wait for the thread to end
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.signal.stop();
+        if let Some(handle) = self.handle.take() {
+            if handle.join().is_err() {
+                warn!("Watcher callback panicked");
+            }
+        }
+    }
+}
+
+impl Drop for JobWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Tokio task polling a search for new listings
+///
+/// The async counterpart of [`JobWatcher`]; must be created inside a Tokio runtime. The task
+/// ends when [`stop`](Self::stop) is awaited or the watcher is dropped, cancelling an
+/// in-flight poll, and a channel watcher also ends once its receiver is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use jobsuche::{JobsucheAsync, SearchOptions};
+/// use jobsuche::watch::JobWatcherAsync;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = JobsucheAsync::default_client()?;
+///     let options = SearchOptions::builder().was("Rust").build();
+///
+///     let (watcher, mut jobs) =
+///         JobWatcherAsync::channel(&client, options, Duration::from_secs(600))?;
+///     while let Some(job) = jobs.recv().await {
+///         println!("New: {}", job.refnr);
+///     }
+///     watcher.stop().await;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct JobWatcherAsync {
+    shutdown: watch::Sender<bool>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+#[cfg(feature = "async")]
+impl JobWatcherAsync {
+    /// Poll `options` every `interval`, calling `on_new` for each new listing
+    ///
+    /// The first poll starts immediately. Fails with [`Error::ConfigError`] for a zero
+    /// interval.
+    pub fn spawn<F>(
+        client: &JobsucheAsync,
+        options: SearchOptions,
+        interval: Duration,
+        mut on_new: F,
+    ) -> Result<JobWatcherAsync>
+    where
+        F: FnMut(JobListing) + Send + 'static,
+    {
+        Self::start(client, options, interval, move |listing| {
+            on_new(listing);
+            true
+        })
+    }
+
+    /// Poll `options` every `interval`, sending each new listing to the returned receiver
+    pub fn channel(
+        client: &JobsucheAsync,
+        options: SearchOptions,
+        interval: Duration,
+    ) -> Result<(
+        JobWatcherAsync,
+        tokio::sync::mpsc::UnboundedReceiver<JobListing>,
+    )> {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let watcher = Self::start(client, options, interval, move |listing| {
+            sender.send(listing).is_ok()
+        })?;
+        Ok((watcher, receiver))
+    }
+
+    fn start<F>(
+        client: &JobsucheAsync,
+        options: SearchOptions,
+        interval: Duration,
+        deliver: F,
+    ) -> Result<JobWatcherAsync>
+    where
+        F: FnMut(JobListing) -> bool + Send + 'static,
+    {
+        let mut state = WatchState::new(interval, deliver)?;
+        let (shutdown, mut stopped) = watch::channel(false);
+        let client = client.clone();
+
+        // `changed()` also resolves once the sender is dropped with the watcher
+        let handle = tokio::spawn(async move {
+            loop {
+                let result = tokio::select! {
+                    _ = stopped.changed() => return,
+                    result = Self::poll(&client, &options, &mut state) => result,
+                };
+                if state.closed {
+                    debug!("Watcher receiver dropped, stopping");
+                    return;
+                }
+                let delay = state.next_delay(&result);
+                tokio::select! {
+                    _ = stopped.changed() => return,
+                    _ = tokio::time::sleep(delay) => {}
+                }
+            }
+        });
+
+        Ok(JobWatcherAsync { shutdown, handle })
+    }
+
+    async fn poll<F: FnMut(JobListing) -> bool>(
+        client: &JobsucheAsync,
+        options: &SearchOptions,
+        state: &mut WatchState<F>,
+    ) -> Result<usize> {
+        let mut new = 0;
+        let mut listings = client.search().stream(options.clone());
+        while let Some(listing) = listings.next().await {
+            if !state.offer(listing?, &mut new) {
+                break;
+            }
+        }
+        Ok(new)
+    }
+
+    /// Whether the polling task has ended, e.g. because the receiver was dropped
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+
+    /// Stop polling and wait for the task to end
+    pub async fn stop(self) {
+        let _ = self.shutdown.send(true);
+        if self.handle.await.is_err() {
+            warn!("Watcher callback panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WorkLocation;
+
+    fn listing(refnr: &str) -> JobListing {
+        JobListing::new(refnr, WorkLocation::default())
+    }
+
+    fn state() -> WatchState<impl FnMut(JobListing) -> bool> {
+        WatchState::new(Duration::from_secs(60), |_| true).unwrap()
+    }
+
+    #[test]
+    fn test_offer_skips_seen_listings() {
+        let mut state = state();
+        let mut new = 0;
+
+        assert!(state.offer(listing("A"), &mut new));
+        assert!(state.offer(listing("B"), &mut new));
+        assert!(state.offer(listing("A"), &mut new));
+        assert_eq!(new, 2);
+    }
+
+    #[test]
+    fn test_offer_reports_closed_delivery() {
+        let mut state = WatchState::new(Duration::from_secs(60), |_| false).unwrap();
+        let mut new = 0;
+
+        assert!(!state.offer(listing("A"), &mut new));
+        assert!(state.closed);
+    }
+
+    #[test]
+    fn test_zero_interval_rejected() {
+        assert!(matches!(
+            WatchState::new(Duration::ZERO, |_| true),
+            Err(Error::ConfigError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_next_delay_honors_retry_after() {
+        let state = state();
+        let rate_limited = |secs| {
+            Err(Error::RateLimited {
+                retry_after: Some(Duration::from_secs(secs)),
+                retry_at: None,
+                request: None,
+            })
+        };
+
+        assert_eq!(state.next_delay(&Ok(3)), Duration::from_secs(60));
+        assert_eq!(state.next_delay(&rate_limited(5)), Duration::from_secs(60));
+        assert_eq!(
+            state.next_delay(&rate_limited(300)),
+            Duration::from_secs(300)
+        );
+    }
+
+    #[test]
+    fn test_stop_signal_wakes_waiter() {
+        let signal = Arc::new(StopSignal::default());
+        let waiter = Arc::clone(&signal);
+        let handle = thread::spawn(move || waiter.wait(Duration::from_secs(60)));
+
+        signal.stop();
+        assert!(handle.join().unwrap());
+        assert!(signal.is_stopped());
+    }
+}
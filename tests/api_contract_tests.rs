@@ -206,7 +206,7 @@ fn api_contract_job_details_structure() {
                     details.titel, details.arbeitgeber
                 );
             }
-            Err(jobsuche::Error::NotFound) => {
+            Err(jobsuche::Error::NotFound { .. }) => {
                 // Known issue: jobs can expire between search and detail fetch
                 println!("Job {} returned 404 (expired) — known issue", job.refnr);
             }
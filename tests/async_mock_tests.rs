@@ -40,9 +40,7 @@ async fn test_async_search_with_mock() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     let results = client
         .search()
@@ -93,9 +91,7 @@ async fn test_async_job_details_mock() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     let job = client.job_details("10001-1001601666-S").await.unwrap();
 
@@ -120,13 +116,113 @@ async fn test_async_job_details_not_found() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("nonexistent").await;
+    let result = client.job_details("10001-NONEXISTENT-S").await;
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::NotFound));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::NotFound { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_async_error_display_includes_request_url() {
+    let mut server = Server::new_async().await;
+    let encoded = jobsuche::encode_refnr("10001-EXPIRED-S");
+
+    let _m404 = server
+        .mock("GET", format!("/pc/v4/jobdetails/{}", encoded).as_str())
+        .with_status(404)
+        .create_async()
+        .await;
+    let _m500 = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/abc")
+        .with_status(500)
+        .with_body("Internal Server Error")
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-EXPIRED-S").await.unwrap_err();
+    let display = error.to_string();
+    assert!(matches!(error, jobsuche::Error::NotFound { .. }));
+    assert!(display.contains("jobdetails request to"), "{}", display);
+    assert!(display.contains(&encoded), "{}", display);
+
+    let error = client.employer_logo("abc").await.unwrap_err();
+    let request = error.request().expect("500 should carry the request");
+    assert_eq!(request.endpoint, jobsuche::Endpoint::EmployerLogo);
+    assert_eq!(
+        request.url,
+        format!("{}/ed/v1/arbeitgeberlogo/abc", server.url())
+    );
+    assert!(error.to_string().contains(&request.url));
+}
+
+#[tokio::test]
+async fn test_async_not_found_reports_resource_kind() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let error = client.job_details("10001-EXPIRED-S").await.unwrap_err();
+    assert!(matches!(
+        error,
+        jobsuche::Error::NotFound {
+            resource: jobsuche::ResourceKind::JobDetails,
+            id: Some(ref id),
+            ..
+        } if id == "10001-EXPIRED-S"
+    ));
+
+    let error = client
+        .job_details_conditional("10001-EXPIRED-S", Some("\"v1\""))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        jobsuche::Error::NotFound {
+            resource: jobsuche::ResourceKind::JobDetails,
+            id: Some(_),
+            ..
+        }
+    ));
+
+    let error = client.employer_logo("abc").await.unwrap_err();
+    assert!(matches!(
+        error,
+        jobsuche::Error::NotFound {
+            resource: jobsuche::ResourceKind::EmployerLogo,
+            id: Some(ref id),
+            ..
+        } if id == "abc"
+    ));
+
+    let error = client
+        .search()
+        .list(SearchOptions::default())
+        .await
+        .unwrap_err();
+    assert!(error.is_not_found());
+    assert!(matches!(
+        error,
+        jobsuche::Error::NotFound {
+            resource: jobsuche::ResourceKind::Search,
+            ..
+        }
+    ));
 }
 
 #[tokio::test]
@@ -154,9 +250,7 @@ async fn test_async_employer_logo_success() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     let logo = client.employer_logo("test-hash").await.unwrap();
     assert_eq!(logo.len(), png_bytes.len());
@@ -173,13 +267,14 @@ async fn test_async_employer_logo_not_found() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     let result = client.employer_logo("nonexistent").await;
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::NotFound));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::NotFound { .. }
+    ));
 }
 
 #[tokio::test]
@@ -192,13 +287,14 @@ async fn test_async_401_unauthorized() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::Unauthorized));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::Unauthorized { .. }
+    ));
 }
 
 #[tokio::test]
@@ -211,13 +307,14 @@ async fn test_async_403_forbidden() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::Forbidden));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::Forbidden { .. }
+    ));
 }
 
 #[tokio::test]
@@ -230,15 +327,13 @@ async fn test_async_405_method_not_allowed() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
-        jobsuche::Error::MethodNotAllowed
+        jobsuche::Error::MethodNotAllowed { .. }
     ));
 }
 
@@ -259,16 +354,14 @@ async fn test_async_rate_limit_429_with_retry_after() {
         ..Default::default()
     };
 
-    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config)
-        .await
-        .unwrap();
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
 
     match result.unwrap_err() {
-        jobsuche::Error::RateLimited { retry_after } => {
-            assert_eq!(retry_after, Some(120));
+        jobsuche::Error::RateLimited { retry_after, .. } => {
+            assert_eq!(retry_after, Some(Duration::from_secs(120)));
         }
         _ => panic!("Expected RateLimited error"),
     }
@@ -290,15 +383,13 @@ async fn test_async_rate_limit_429_without_retry_after() {
         ..Default::default()
     };
 
-    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config)
-        .await
-        .unwrap();
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
 
     match result.unwrap_err() {
-        jobsuche::Error::RateLimited { retry_after } => {
+        jobsuche::Error::RateLimited { retry_after, .. } => {
             assert_eq!(retry_after, None);
         }
         _ => panic!("Expected RateLimited error"),
@@ -327,9 +418,7 @@ async fn test_async_empty_results() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     let results = client
         .search()
@@ -367,17 +456,21 @@ async fn test_async_500_server_error_with_api_errors() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
 
-    match result.unwrap_err() {
-        jobsuche::Error::Fault { code, .. } => {
-            assert_eq!(code, 500);
-            // Successfully parsed as a Fault error
+    // The default client retries the 500 before giving up
+    let error = result.unwrap_err();
+    assert!(matches!(error, jobsuche::Error::RetriesExhausted { .. }));
+    match error.root() {
+        jobsuche::Error::Fault { code, errors, .. } => {
+            assert_eq!(*code, 500);
+            assert_eq!(
+                errors.first_message(),
+                Some("Internal server error occurred")
+            );
         }
         _ => panic!("Expected Fault error"),
     }
@@ -395,22 +488,134 @@ async fn test_async_500_server_error_plain_text() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test").await;
+    let result = client.job_details("10001-TEST-S").await;
     assert!(result.is_err());
 
-    match result.unwrap_err() {
-        jobsuche::Error::Fault { code, errors } => {
-            assert_eq!(code, 500);
+    match result.unwrap_err().root() {
+        jobsuche::Error::Fault { code, errors, .. } => {
+            assert_eq!(*code, 500);
             assert_eq!(errors.errors.len(), 0);
         }
         _ => panic!("Expected Fault error"),
     }
 }
 
+#[tokio::test]
+async fn test_async_html_error_page_kept_in_fault() {
+    let mut server = Server::new_async().await;
+
+    let page = "<html><body><h1>503</h1><p>Wartungsarbeiten</p></body></html>";
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "text/html")
+        .with_body(page)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.job_details("10001-TEST-S").await.unwrap_err() {
+        jobsuche::Error::Fault { code, body, .. } => {
+            assert_eq!(code, 503);
+            assert_eq!(body.as_deref(), Some(page));
+        }
+        other => panic!("expected Fault, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_400_invalid_request_messages() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobs")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "umkreis".into(),
+            "9999".into(),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"errors": [{"code": "VALIDATION_ERROR", "message": "umkreis: must be less than or equal to 200"}]}"#,
+        )
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let err = client
+        .search()
+        .list(SearchOptions::builder().umkreis(9999).build())
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status_code(), Some(jobsuche::StatusCode::BAD_REQUEST));
+    match err {
+        jobsuche::Error::InvalidRequest { messages, .. } => {
+            assert_eq!(messages, vec!["umkreis: must be less than or equal to 200"]);
+        }
+        other => panic!("expected InvalidRequest, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_missing_field_reports_path() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobs")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [{"titel": "Koch"}]}"#)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    match client.search().list(SearchOptions::default()).await {
+        Err(jobsuche::Error::Decode { path, message, .. }) => {
+            assert_eq!(path, "stellenangebote[0]");
+            assert!(message.contains("refnr"), "{}", message);
+        }
+        other => panic!("expected Decode, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_malformed_json_reports_body_snippet() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"refnr": "10001-TEST-S", "titel": <!-- maintenance -->}"#)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let error = client.job_details("10001-TEST-S").await.unwrap_err();
+    assert!(!error.is_retryable());
+    match error {
+        jobsuche::Error::Decode { snippet, .. } => {
+            assert!(snippet.contains("<!-- maintenance -->"), "{}", snippet);
+        }
+        other => panic!("expected Decode, got {:?}", other),
+    }
+}
+
 #[tokio::test]
 async fn test_async_with_config_custom_timeout() {
     let config = ClientConfig {
@@ -418,14 +623,14 @@ async fn test_async_with_config_custom_timeout() {
         connect_timeout: Duration::from_secs(2),
         max_retries: 1,
         retry_enabled: false,
+        ..Default::default()
     };
 
     let client = JobsucheAsync::with_config(
         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
         Credentials::default(),
         config,
-    )
-    .await;
+    );
 
     assert!(client.is_ok());
 }
@@ -437,14 +642,14 @@ async fn test_async_with_config_retries_enabled() {
         connect_timeout: Duration::from_secs(10),
         max_retries: 3,
         retry_enabled: true,
+        ..Default::default()
     };
 
     let client = JobsucheAsync::with_config(
         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
         Credentials::default(),
         config,
-    )
-    .await;
+    );
 
     assert!(client.is_ok());
 }
@@ -459,7 +664,7 @@ async fn test_async_with_core() {
     )
     .unwrap();
 
-    let client = JobsucheAsync::with_core(core).await;
+    let client = JobsucheAsync::with_core(core);
     assert!(client.is_ok());
 }
 
@@ -478,9 +683,10 @@ async fn test_async_with_config_and_core() {
         connect_timeout: Duration::from_secs(5),
         max_retries: 2,
         retry_enabled: true,
+        ..Default::default()
     };
 
-    let client = JobsucheAsync::with_config_and_core(core, config).await;
+    let client = JobsucheAsync::with_config_and_core(core, config);
     assert!(client.is_ok());
 }
 
@@ -490,7 +696,6 @@ async fn test_async_search_interface() {
         "https://rest.arbeitsagentur.de/jobboerse/jobsuche-service",
         Credentials::default(),
     )
-    .await
     .unwrap();
 
     // Just verify we can create a search interface
@@ -541,9 +746,7 @@ async fn test_async_pagination_mock() {
         .create_async()
         .await;
 
-    let client = JobsucheAsync::new(server.url(), Credentials::default())
-        .await
-        .unwrap();
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
 
     // Test page 0
     let results_page1 = client
@@ -608,9 +811,7 @@ async fn test_async_retry_respects_retry_after_header() {
         ..Default::default()
     };
 
-    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config)
-        .await
-        .unwrap();
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
 
     let start = std::time::Instant::now();
     let result = client.job_details("10001-RETRY-TEST-S").await;
@@ -628,3 +829,2661 @@ async fn test_async_retry_respects_retry_after_header() {
         elapsed
     );
 }
+
+#[tokio::test]
+async fn test_async_hooks_fire_with_redacted_api_key() {
+    use jobsuche::{RequestInfo, ResponseInfo};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Hook.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create_async()
+        .await;
+
+    let requests: Arc<Mutex<Vec<RequestInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let responses: Arc<Mutex<Vec<ResponseInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let request_sink = requests.clone();
+    let response_sink = responses.clone();
+
+    let config = ClientConfig {
+        on_request: Some(Arc::new(move |info: &RequestInfo| {
+            request_sink.lock().unwrap().push(info.clone());
+        })),
+        on_response: Some(Arc::new(move |info: &ResponseInfo| {
+            response_sink.lock().unwrap().push(info.clone());
+        })),
+        capture_response_body: Some(1024),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(
+        server.url(),
+        Credentials::ApiKey("super-secret".to_string()),
+        config,
+    )
+    .unwrap();
+
+    client
+        .search()
+        .list(SearchOptions::builder().was("Hook").build())
+        .await
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].url.contains("was=Hook"));
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(name, value)| name == "x-api-key" && value == "[REDACTED]"));
+    assert!(!format!("{:?}", requests[0]).contains("super-secret"));
+
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, 200);
+    assert!(responses[0]
+        .body
+        .as_deref()
+        .is_some_and(|body| body.contains("stellenangebote")));
+}
+
+#[tokio::test]
+async fn test_async_hooks_called_for_every_attempt() {
+    use jobsuche::{RequestInfo, ResponseInfo};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new_async().await;
+
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .with_body("slow down")
+        .expect(1)
+        .create_async()
+        .await;
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let _logo = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/abc123")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body([0x89, b'P', b'N', b'G'])
+        .create_async()
+        .await;
+
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let request_sink = events.clone();
+    let response_sink = events.clone();
+    let config = ClientConfig {
+        on_request: Some(Arc::new(move |info: &RequestInfo| {
+            request_sink.lock().unwrap().push(format!(
+                "request {} #{}",
+                info.endpoint.as_str(),
+                info.attempt
+            ));
+        })),
+        on_response: Some(Arc::new(move |info: &ResponseInfo| {
+            response_sink.lock().unwrap().push(format!(
+                "response {} #{} {} {:?}",
+                info.endpoint.as_str(),
+                info.attempt,
+                info.status.as_u16(),
+                info.bytes
+            ));
+        })),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    client.job_details("10001-1001601666-S").await.unwrap();
+    assert_eq!(client.employer_logo("abc123").await.unwrap().len(), 4);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "request jobdetails #1",
+            "response jobdetails #1 429 Some(9)",
+            "request jobdetails #2",
+            "response jobdetails #2 200 Some(115)",
+            "request logo #1",
+            "response logo #1 200 Some(4)",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_async_panicking_hook_does_not_fail_request() {
+    use jobsuche::RequestInfo;
+    use std::sync::Arc;
+
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        on_request: Some(Arc::new(|_: &RequestInfo| panic!("broken request hook"))),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let job = client.job_details("10001-1001601666-S").await.unwrap();
+    assert_eq!(job.refnr.as_deref(), Some("10001-1001601666-S"));
+}
+
+#[tokio::test]
+async fn test_async_request_id_attached_to_fault() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header(
+            "x-custom-request-id",
+            mockito::Matcher::Regex(r"^[0-9a-f-]{36}$".to_string()),
+        )
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["unprocessable"]}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        request_id_header: Some("X-Custom-Request-Id".to_string()),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let err = client.job_details("10001-TEST-S").await.unwrap_err();
+    match &err {
+        jobsuche::Error::Fault { request_id, .. } => {
+            let id = request_id.as_deref().expect("request id should be set");
+            assert_eq!(id.len(), 36);
+            assert!(err.to_string().contains(id));
+        }
+        other => panic!("Expected Fault, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_client_tls_config() {
+    let config = ClientConfig {
+        root_certificates_pem: vec![include_bytes!("fixtures/test-ca.pem").to_vec()],
+        min_tls_version: Some(reqwest::tls::Version::TLS_1_2),
+        ..Default::default()
+    };
+    assert!(
+        JobsucheAsync::with_config(jobsuche::DEFAULT_HOST, Credentials::default(), config).is_ok()
+    );
+
+    let config = ClientConfig {
+        root_certificates_pem: vec![b"-----BEGIN GARBAGE-----".to_vec()],
+        ..Default::default()
+    };
+    let result = JobsucheAsync::with_config(jobsuche::DEFAULT_HOST, Credentials::default(), config);
+    assert!(matches!(result, Err(jobsuche::Error::ConfigError { .. })));
+}
+
+#[tokio::test]
+async fn test_async_not_found_is_not_retried() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 3,
+        retry_enabled: true,
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let result = client.job_details("10001-EXPIRED-S").await;
+    assert!(matches!(result, Err(jobsuche::Error::NotFound { .. })));
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_service_unavailable_is_retried() {
+    let mut server = Server::new_async().await;
+
+    let _m_unavailable = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _m_success = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 3,
+        retry_enabled: true,
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let result = client
+        .search()
+        .list(SearchOptions::builder().was("Retry").build())
+        .await
+        .expect("Expected success after retrying 503");
+    assert!(result.stellenangebote.is_empty());
+}
+
+fn temp_logo_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("jobsuche-{}-{}", name, uuid::Uuid::new_v4()))
+}
+
+const PNG_HEADER: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+#[tokio::test]
+async fn test_async_employer_logo_to_file() {
+    let mut server = Server::new_async().await;
+
+    let mut body = PNG_HEADER.to_vec();
+    body.extend_from_slice(b"fake image data");
+
+    let _m = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/LOGOHASH")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(&body)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let path = temp_logo_path("logo");
+    let written = client
+        .employer_logo_to_file("LOGOHASH", &path)
+        .await
+        .unwrap();
+
+    assert_eq!(written, path.with_extension("png"));
+    assert_eq!(tokio::fs::read(&written).await.unwrap(), body);
+
+    tokio::fs::remove_file(&written).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_async_employer_logo_to_file_not_found() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/MISSING")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let path = temp_logo_path("missing");
+    let result = client.employer_logo_to_file("MISSING", &path).await;
+
+    assert!(matches!(result, Err(jobsuche::Error::NotFound { .. })));
+    assert!(!path.with_extension("png").exists());
+}
+
+#[tokio::test]
+async fn test_async_employer_logo_to_file_large_body() {
+    let mut server = Server::new_async().await;
+
+    // 8 MiB body, delivered in several chunks
+    let mut body = PNG_HEADER.to_vec();
+    body.resize(8 * 1024 * 1024, 0xAB);
+
+    let _m = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/BIGLOGO")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(&body)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let path = temp_logo_path("big-logo");
+    let written = client
+        .employer_logo_to_file("BIGLOGO", &path)
+        .await
+        .unwrap();
+
+    let metadata = tokio::fs::metadata(&written).await.unwrap();
+    assert_eq!(metadata.len(), body.len() as u64);
+    assert_eq!(tokio::fs::read(&written).await.unwrap(), body);
+
+    tokio::fs::remove_file(&written).await.unwrap();
+}
+
+fn job_details_body(refnr: &str) -> String {
+    format!(
+        r#"{{"referenznummer": "{}", "stellenangebotsTitel": "Job {}", "stellenlokationen": []}}"#,
+        refnr, refnr
+    )
+}
+
+#[tokio::test]
+async fn test_async_job_details_concurrent() {
+    use jobsuche::{encode_refnr, RequestInfo, ResponseInfo};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let mut server = Server::new_async().await;
+
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let (start, peak, end) = (in_flight.clone(), max_in_flight.clone(), in_flight.clone());
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        on_request: Some(Arc::new(move |_: &RequestInfo| {
+            let now = start.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+        })),
+        on_response: Some(Arc::new(move |_: &ResponseInfo| {
+            end.fetch_sub(1, Ordering::SeqCst);
+        })),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let refnrs: Vec<String> = (0..12).map(|i| format!("10001-CONC{:02}-S", i)).collect();
+    let served = Arc::new(AtomicUsize::new(0));
+
+    let mut mocks = Vec::new();
+    for (i, refnr) in refnrs.iter().enumerate() {
+        let url = client
+            .core()
+            .path(&["pc", "v4", "jobdetails", &encode_refnr(refnr)]);
+        let path = url.strip_prefix(&server.url()).unwrap().to_string();
+        let mock = server.mock("GET", path.as_str());
+        let mock = match i % 4 {
+            1 => mock.with_status(404),
+            2 => mock.with_status(429),
+            _ => {
+                let served = served.clone();
+                let body = job_details_body(refnr);
+                mock.with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body_from_request(move |_| {
+                        served.fetch_add(1, Ordering::SeqCst);
+                        body.clone().into_bytes()
+                    })
+            }
+        };
+        mocks.push(mock.create_async().await);
+    }
+
+    let results = client.job_details_concurrent(&refnrs, 3).await;
+
+    assert_eq!(results.len(), refnrs.len());
+    for (i, (refnr, result)) in results.iter().enumerate() {
+        assert_eq!(refnr, &refnrs[i]);
+        match i % 4 {
+            1 => assert!(matches!(result, Err(jobsuche::Error::NotFound { .. }))),
+            2 => assert!(matches!(result, Err(jobsuche::Error::RateLimited { .. }))),
+            _ => assert_eq!(
+                result.as_ref().unwrap().refnr.as_deref(),
+                Some(refnr.as_str())
+            ),
+        }
+    }
+
+    assert_eq!(served.load(Ordering::SeqCst), 6);
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 3);
+    assert_eq!(in_flight.load(Ordering::SeqCst), 0);
+}
+
+/// Constructors are synchronous and don't need a runtime, so a client can live in a static
+static LAZY_CLIENT: std::sync::LazyLock<JobsucheAsync> =
+    std::sync::LazyLock::new(|| JobsucheAsync::default_client().unwrap());
+
+#[test]
+fn test_async_client_constructed_without_runtime() {
+    assert!(tokio::runtime::Handle::try_current().is_err());
+
+    let client = JobsucheAsync::new(jobsuche::DEFAULT_HOST, Credentials::default()).unwrap();
+    assert_eq!(client.core().host.as_str(), jobsuche::DEFAULT_HOST);
+    assert_eq!(LAZY_CLIENT.core().host.as_str(), jobsuche::DEFAULT_HOST);
+}
+
+#[tokio::test]
+async fn test_async_lazy_static_client_usable_in_runtime() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create_async()
+        .await;
+
+    static MOCK_CLIENT: std::sync::OnceLock<JobsucheAsync> = std::sync::OnceLock::new();
+    let client = MOCK_CLIENT
+        .get_or_init(|| JobsucheAsync::new(server.url(), Credentials::default()).unwrap());
+
+    let result = client
+        .search()
+        .list(SearchOptions::builder().was("Static").build())
+        .await
+        .unwrap();
+    assert!(result.stellenangebote.is_empty());
+}
+
+#[tokio::test]
+async fn test_async_from_client_uses_custom_client() {
+    use jobsuche::RequestInfo;
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("x-custom-layer", "enabled")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-custom-layer", HeaderValue::from_static("enabled"));
+    let http = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let seen = Arc::new(AtomicUsize::new(0));
+    let counter = seen.clone();
+    let config = ClientConfig {
+        on_request: Some(Arc::new(move |_: &RequestInfo| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })),
+        ..Default::default()
+    };
+
+    let client =
+        JobsucheAsync::from_client(server.url(), Credentials::default(), http, config).unwrap();
+
+    for _ in 0..2 {
+        client
+            .search()
+            .list(SearchOptions::builder().was("Layer").build())
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(seen.load(Ordering::SeqCst), 2);
+    m.assert_async().await;
+}
+
+/// Six requests at 5/sec must span at least one second, across clones and retries.
+///
+/// This runs on the real clock: with `tokio::time::pause` the runtime auto-advances while
+/// waiting on the mock server's socket, which would trip the request timeout instead.
+#[tokio::test]
+async fn test_async_client_side_rate_limit() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(6)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        rate_limit: Some(jobsuche::RateLimit::per_second(5)),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let clone = client.clone();
+
+    let start = std::time::Instant::now();
+    for i in 0..6 {
+        let c = if i % 2 == 0 { &client } else { &clone };
+        c.search()
+            .list(SearchOptions::builder().was("Limit").build())
+            .await
+            .unwrap();
+    }
+
+    assert!(
+        start.elapsed() >= Duration::from_secs(1),
+        "6 requests at 5/sec finished in {:?}",
+        start.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn test_async_rate_limit_counts_retries() {
+    let mut server = Server::new_async().await;
+
+    let _m_unavailable = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _m_success = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create_async()
+        .await;
+
+    // One request per 800ms: the retry must wait for the window even though backoff is shorter
+    let config = ClientConfig {
+        rate_limit: Some(jobsuche::RateLimit {
+            max_requests: 1,
+            per: Duration::from_millis(800),
+        }),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let start = std::time::Instant::now();
+    client
+        .search()
+        .list(SearchOptions::builder().was("Limit").build())
+        .await
+        .unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(800));
+}
+
+#[test]
+fn test_rate_limit_zero_rejected() {
+    let config = ClientConfig {
+        rate_limit: Some(jobsuche::RateLimit::per_second(0)),
+        ..Default::default()
+    };
+
+    let result = JobsucheAsync::with_config(jobsuche::DEFAULT_HOST, Credentials::default(), config);
+    assert!(matches!(result, Err(jobsuche::Error::ConfigError { .. })));
+}
+
+#[tokio::test]
+async fn test_async_metrics_hook_reports_endpoint_templates() {
+    use jobsuche::{Endpoint, RequestMetric};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new_async().await;
+
+    let _m_search = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create_async()
+        .await;
+
+    let _m_details = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobdetails/.*".to_string()),
+        )
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let metrics: Arc<Mutex<Vec<RequestMetric>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = metrics.clone();
+    let config = ClientConfig {
+        retry_enabled: false,
+        on_metric: Some(Arc::new(move |metric: &RequestMetric| {
+            sink.lock().unwrap().push(metric.clone());
+        })),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    client
+        .search()
+        .list(SearchOptions::builder().was("Metrics").build())
+        .await
+        .unwrap();
+    let _ = client.job_details("10001-METRICS-S").await;
+
+    let metrics = metrics.lock().unwrap();
+    assert_eq!(metrics.len(), 2);
+
+    assert_eq!(metrics[0].endpoint, Endpoint::Jobs);
+    assert_eq!(metrics[0].endpoint.template(), "/pc/v4/jobs");
+    assert_eq!(metrics[0].status, Some(reqwest::StatusCode::OK));
+
+    assert_eq!(metrics[1].endpoint, Endpoint::JobDetails);
+    assert_eq!(metrics[1].endpoint.template(), "/pc/v4/jobdetails/{refnr}");
+    assert_eq!(metrics[1].endpoint.as_str(), "jobdetails");
+    assert_eq!(metrics[1].status, Some(reqwest::StatusCode::NOT_FOUND));
+    assert!(!format!("{:?}", metrics[1]).contains("METRICS"));
+}
+
+#[tokio::test]
+async fn test_async_hedged_request_fast_response_wins() {
+    let mut server = Server::new_async().await;
+
+    let slow = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(3));
+            w.write_all(job_details_body("10001-SLOW-S").as_bytes())
+        })
+        .expect(1)
+        .create_async()
+        .await;
+
+    let fast = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-FAST-S"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        hedge_after: Some(Duration::from_millis(100)),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let start = std::time::Instant::now();
+    let job = client.job_details("10001-HEDGE-S").await.unwrap();
+
+    assert_eq!(job.refnr.as_deref(), Some("10001-FAST-S"));
+    assert!(start.elapsed() < Duration::from_secs(2));
+    slow.assert_async().await;
+    fast.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_no_hedge_for_fast_response() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-QUICK-S"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        hedge_after: Some(Duration::from_secs(5)),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let job = client.job_details("10001-QUICK-S").await.unwrap();
+    assert_eq!(job.refnr.as_deref(), Some("10001-QUICK-S"));
+    m.assert_async().await;
+}
+
+const EMPTY_SEARCH_BODY: &str = r#"{"stellenangebote": [], "maxErgebnisse": 0}"#;
+
+#[tokio::test]
+async fn test_async_list_cancelled_mid_request() {
+    let mut server = Server::new_async().await;
+
+    let slow = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(500));
+            w.write_all(EMPTY_SEARCH_BODY.as_bytes())
+        })
+        .expect(1)
+        .create_async()
+        .await;
+
+    let fast = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_SEARCH_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let options = SearchOptions::builder().was("Cancel").build();
+
+    let search = client.search();
+    tokio::select! {
+        _ = search.list(options.clone()) => panic!("slow request should not finish first"),
+        _ = tokio::time::sleep(Duration::from_millis(50)) => {}
+    }
+
+    // The client is still usable and the cancelled request was not retried in the background
+    let result = client.search().list(options).await.unwrap();
+    assert!(result.stellenangebote.is_empty());
+    slow.assert_async().await;
+    fast.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_stream_dropped_stops_fetching() {
+    use futures::StreamExt;
+
+    let mut server = Server::new_async().await;
+
+    let page1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"stellenangebote": [
+                {"refnr": "REF1", "arbeitsort": {}},
+                {"refnr": "REF2", "arbeitsort": {}}
+            ], "maxErgebnisse": 10}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let page2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_SEARCH_BODY)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    {
+        let mut stream = client
+            .search()
+            .stream(SearchOptions::builder().was("Drop").size(2).build());
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.refnr, "REF1");
+    }
+
+    page1.assert_async().await;
+    page2.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_iter_cancelled_then_reused() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_SEARCH_BODY)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let options = SearchOptions::builder().was("Cancel").build();
+
+    // Drop the future before it is ever polled to completion
+    let cancelled =
+        tokio::time::timeout(Duration::ZERO, client.search().iter(options.clone())).await;
+    assert!(cancelled.is_err() || cancelled.unwrap().is_ok());
+
+    let jobs = client.search().iter(options).await.unwrap();
+    assert!(jobs.is_empty());
+}
+
+fn search_page_body(refnrs: &[&str], max: u64) -> String {
+    let jobs: Vec<String> = refnrs
+        .iter()
+        .map(|r| format!(r#"{{"refnr": "{}", "arbeitsort": {{"ort": "Berlin"}}}}"#, r))
+        .collect();
+    format!(
+        r#"{{"stellenangebote": [{}], "maxErgebnisse": {}, "facetten": {{"arbeitsort": {{}}}}}}"#,
+        jobs.join(","),
+        max
+    )
+}
+
+#[tokio::test]
+async fn test_async_pages_multi_page() {
+    use futures::StreamExt;
+
+    let mut server = Server::new_async().await;
+
+    let _m1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF1", "REF2"], 3))
+        .create_async()
+        .await;
+
+    let _m2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF3"], 3))
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let pages: Vec<_> = client
+        .search()
+        .pages(SearchOptions::builder().was("Pages").size(2).build())
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 2);
+    let first = pages[0].as_ref().unwrap();
+    assert_eq!(first.stellenangebote.len(), 2);
+    assert_eq!(first.max_ergebnisse, Some(3));
+    assert!(first.facetten.as_ref().unwrap().arbeitsort.is_some());
+    assert_eq!(pages[1].as_ref().unwrap().stellenangebote[0].refnr, "REF3");
+}
+
+#[tokio::test]
+async fn test_async_pages_take_one_fetches_one_page() {
+    use futures::StreamExt;
+
+    let mut server = Server::new_async().await;
+
+    let m1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF1", "REF2"], 100))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let m2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF3", "REF4"], 100))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let pages: Vec<_> = client
+        .search()
+        .pages(SearchOptions::builder().size(2).build())
+        .take(1)
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 1);
+    m1.assert_async().await;
+    m2.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_pages_error_ends_stream() {
+    use futures::StreamExt;
+
+    let mut server = Server::new_async().await;
+
+    let _m1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF1", "REF2"], 100))
+        .create_async()
+        .await;
+
+    let _m2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let pages: Vec<_> = client
+        .search()
+        .pages(SearchOptions::builder().size(2).build())
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 2);
+    assert!(pages[0].is_ok());
+    let err = pages[1].as_ref().unwrap_err();
+    assert!(matches!(err.root(), jobsuche::Error::Forbidden { .. }));
+    assert_eq!(err.kind(), jobsuche::ErrorKind::Auth);
+    assert!(err
+        .to_string()
+        .starts_with("fetching a page of search results: "));
+}
+
+#[tokio::test]
+async fn test_async_stream_with_details_skips_expired() {
+    use futures::StreamExt;
+    use jobsuche::encode_refnr;
+
+    let mut server = Server::new_async().await;
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let _m1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["10001-A-S", "10001-B-S"], 4))
+        .create_async()
+        .await;
+
+    let _m2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["10001-C-S", "10001-D-S"], 4))
+        .create_async()
+        .await;
+
+    let mut detail_mocks = Vec::new();
+    for refnr in ["10001-A-S", "10001-B-S", "10001-C-S", "10001-D-S"] {
+        let url = client
+            .core()
+            .path(&["pc", "v4", "jobdetails", &encode_refnr(refnr)]);
+        let path = url.strip_prefix(&server.url()).unwrap().to_string();
+        let mock = server.mock("GET", path.as_str());
+        // B and D have expired since the search
+        let mock = if refnr.contains('B') || refnr.contains('D') {
+            mock.with_status(404)
+        } else {
+            mock.with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(job_details_body(refnr))
+        };
+        detail_mocks.push(mock.create_async().await);
+    }
+
+    let results: Vec<_> = client
+        .search()
+        .stream_with_details(SearchOptions::builder().size(2).build(), 2)
+        .collect()
+        .await;
+
+    assert_eq!(results.len(), 2);
+    let (listing, details) = results[0].as_ref().unwrap();
+    assert_eq!(listing.refnr, "10001-A-S");
+    assert_eq!(details.refnr.as_deref(), Some("10001-A-S"));
+    let (listing, details) = results[1].as_ref().unwrap();
+    assert_eq!(listing.refnr, "10001-C-S");
+    assert_eq!(details.refnr.as_deref(), Some("10001-C-S"));
+}
+
+#[tokio::test]
+async fn test_async_tower_service_oneshot() {
+    use jobsuche::service::{JobsucheRequest, JobsucheResponse};
+    use tower::ServiceExt;
+
+    let mut server = Server::new_async().await;
+
+    let _m_search = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Tower.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["REF1"], 1))
+        .create_async()
+        .await;
+
+    let _m_details = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobdetails/.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-TOWER-S"))
+        .create_async()
+        .await;
+
+    let _m_logo = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/TOWERLOGO")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(PNG_HEADER)
+        .create_async()
+        .await;
+
+    let service = JobsucheAsync::new(server.url(), Credentials::default())
+        .unwrap()
+        .into_service();
+
+    let response = service
+        .clone()
+        .oneshot(JobsucheRequest::Search(
+            SearchOptions::builder().was("Tower").build(),
+        ))
+        .await
+        .unwrap();
+    match response {
+        JobsucheResponse::Search(results) => assert_eq!(results.stellenangebote[0].refnr, "REF1"),
+        other => panic!("Expected search response, got {:?}", other),
+    }
+
+    let response = service
+        .clone()
+        .oneshot(JobsucheRequest::Details("10001-TOWER-S".to_string()))
+        .await
+        .unwrap();
+    match response {
+        JobsucheResponse::Details(details) => {
+            assert_eq!(details.refnr.as_deref(), Some("10001-TOWER-S"))
+        }
+        other => panic!("Expected details response, got {:?}", other),
+    }
+
+    let response = service
+        .oneshot(JobsucheRequest::Logo("TOWERLOGO".to_string()))
+        .await
+        .unwrap();
+    match response {
+        JobsucheResponse::Logo(bytes) => assert_eq!(bytes, PNG_HEADER),
+        other => panic!("Expected logo response, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_employer_logo_stream_multi_chunk() {
+    use futures::StreamExt;
+
+    let mut server = Server::new_async().await;
+
+    let chunks: Vec<Vec<u8>> = (0..4u8)
+        .map(|i| {
+            let mut chunk = if i == 0 {
+                PNG_HEADER.to_vec()
+            } else {
+                Vec::new()
+            };
+            chunk.resize(64 * 1024, i);
+            chunk
+        })
+        .collect();
+    let expected: Vec<u8> = chunks.concat();
+
+    let _m = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/STREAMLOGO")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_chunked_body(move |w| {
+            for chunk in &chunks {
+                w.write_all(chunk)?;
+                w.flush()?;
+            }
+            Ok(())
+        })
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let mut stream = client.employer_logo_stream("STREAMLOGO").await.unwrap();
+    let mut received = Vec::new();
+    let mut chunk_count = 0;
+    while let Some(chunk) = stream.next().await {
+        received.extend_from_slice(&chunk.unwrap());
+        chunk_count += 1;
+    }
+
+    assert!(chunk_count >= 1);
+    assert_eq!(received, expected);
+}
+
+#[tokio::test]
+async fn test_async_employer_logo_stream_not_found() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/NOLOGO")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let result = client.employer_logo_stream("NOLOGO").await;
+    assert!(matches!(result, Err(jobsuche::Error::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_async_retry_deadline_stops_retrying() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    // Backoff sleeps 1s, then 2s: the second sleep would exceed the 1.5s budget
+    let config = ClientConfig {
+        max_retries: 5,
+        retry_deadline: Some(Duration::from_millis(1500)),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let start = std::time::Instant::now();
+    let result = client.job_details("10001-DEADLINE-S").await;
+
+    assert!(start.elapsed() < Duration::from_millis(1500));
+    match result.unwrap_err() {
+        jobsuche::Error::RetriesExhausted {
+            attempts, source, ..
+        } => {
+            assert_eq!(attempts, 2);
+            assert!(matches!(
+                *source,
+                jobsuche::Error::Fault {
+                    code: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    ..
+                }
+            ));
+        }
+        other => panic!("expected RetriesExhausted, got {:?}", other),
+    }
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_retry_deadline_cuts_off_slow_attempt() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|_| {
+            std::thread::sleep(Duration::from_secs(2));
+            job_details_body("10001-DEADLINE-SLOW").into_bytes()
+        })
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 5,
+        retry_deadline: Some(Duration::from_millis(300)),
+        ..Default::default()
+    };
+
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let start = std::time::Instant::now();
+    let result = client.job_details("10001-DEADLINE-SLOW").await;
+
+    assert!(start.elapsed() < Duration::from_secs(1));
+    match result.unwrap_err() {
+        jobsuche::Error::DeadlineExceeded { deadline } => {
+            assert_eq!(deadline, Duration::from_millis(300));
+        }
+        other => panic!("expected DeadlineExceeded, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_async_retries_exhausted_reports_attempts() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 1,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-DOWN-S").await.unwrap_err();
+    assert!(!error.is_retryable());
+    match error {
+        jobsuche::Error::RetriesExhausted {
+            attempts, elapsed, ..
+        } => {
+            assert_eq!(attempts, 2);
+            assert!(elapsed >= Duration::from_secs(1), "{:?}", elapsed);
+        }
+        other => panic!("expected RetriesExhausted, got {:?}", other),
+    }
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_job_details_conditional_captures_etag() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(job_details_body("10001-ETAG-S"))
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let result = client
+        .job_details_conditional("10001-ETAG-S", None)
+        .await
+        .unwrap();
+
+    assert!(result.is_modified());
+    assert_eq!(result.etag(), Some("\"v1\""));
+    let details = result.into_value().unwrap();
+    assert_eq!(details.refnr.as_deref(), Some("10001-ETAG-S"));
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_job_details_conditional_not_modified() {
+    let mut server = Server::new_async().await;
+
+    // No body and no content type: a deserialization attempt would fail
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let result = client
+        .job_details_conditional("10001-ETAG-S", Some("\"v1\""))
+        .await
+        .unwrap();
+
+    assert_eq!(result.etag(), None);
+    assert!(matches!(result, jobsuche::Conditional::NotModified));
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_job_details_conditional_retries_then_not_modified() {
+    let mut server = Server::new_async().await;
+
+    let unavailable = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let not_modified = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 2,
+        retry_enabled: true,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let result = client
+        .job_details_conditional("10001-ETAG-S", Some("\"v1\""))
+        .await
+        .unwrap();
+
+    assert!(matches!(result, jobsuche::Conditional::NotModified));
+    unavailable.assert_async().await;
+    not_modified.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_search_many_partial_failure_keeps_order() {
+    let mut server = Server::new_async().await;
+
+    let _rust = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Rust.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["RUST1"], 1))
+        .create_async()
+        .await;
+    let _go = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Go.*".to_string()),
+        )
+        .with_status(500)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["boom"]}"#)
+        .create_async()
+        .await;
+    let _python = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Python.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_page_body(&["PY1", "PY2"], 2))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let queries = ["Rust", "Go", "Python"]
+        .iter()
+        .map(|was| SearchOptions::builder().was(was).build())
+        .collect::<Vec<_>>();
+    let results = client.search_many(queries.clone(), 2).await;
+
+    assert_eq!(results.len(), 3);
+    for ((options, _), query) in results.iter().zip(&queries) {
+        assert_eq!(options.serialize(), query.serialize());
+    }
+
+    let refnrs = |i: usize| -> Vec<String> {
+        results[i]
+            .1
+            .as_ref()
+            .unwrap()
+            .stellenangebote
+            .iter()
+            .map(|j| j.refnr.to_string())
+            .collect()
+    };
+    assert_eq!(refnrs(0), vec!["RUST1"]);
+    assert!(matches!(
+        results[1].1,
+        Err(jobsuche::Error::Fault {
+            code: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            ..
+        })
+    ));
+    assert_eq!(refnrs(2), vec!["PY1", "PY2"]);
+}
+
+#[tokio::test]
+async fn test_async_skip_retry_sends_single_request() {
+    use jobsuche::RequestOptions;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 3,
+        retry_enabled: true,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let options = RequestOptions {
+        skip_retry: true,
+        ..Default::default()
+    };
+    let start = std::time::Instant::now();
+    let result = client.job_details_with("10001-FAST-S", &options).await;
+
+    // No backoff sleep happened
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert!(matches!(
+        result,
+        Err(jobsuche::Error::Fault {
+            code: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        })
+    ));
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_request_options_headers_propagate() {
+    use jobsuche::RequestOptions;
+    use reqwest::header::{HeaderMap, HeaderValue};
+
+    let mut server = Server::new_async().await;
+
+    let search = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .match_header("x-tenant", "acme")
+        .match_header("idempotency-key", "morning-run-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_SEARCH_BODY)
+        .create_async()
+        .await;
+    let logo = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/ed/v1/arbeitgeberlogo/".to_string()),
+        )
+        .match_header("x-tenant", "acme")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(PNG_HEADER)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    let mut extra_headers = HeaderMap::new();
+    extra_headers.insert("x-tenant", HeaderValue::from_static("acme"));
+    let options = RequestOptions {
+        extra_headers,
+        idempotency_key: Some("morning-run-1".to_string()),
+        ..Default::default()
+    };
+
+    client
+        .search()
+        .list_with(SearchOptions::builder().was("Rust").build(), &options)
+        .await
+        .unwrap();
+    let bytes = client
+        .employer_logo_with("LOGO123", &options)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, PNG_HEADER);
+    search.assert_async().await;
+    logo.assert_async().await;
+}
+
+/// Address on which nothing is listening, so connecting is refused
+fn refused_url() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn test_async_read_timeout_error() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(2));
+            w.write_all(job_details_body("10001-SLOW-S").as_bytes())
+        })
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        timeout: Duration::from_millis(300),
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let result = client.job_details("10001-SLOW-S").await;
+    assert!(matches!(
+        result,
+        Err(jobsuche::Error::Timeout {
+            phase: jobsuche::TimeoutPhase::Read,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_async_connection_refused_error() {
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(refused_url(), Credentials::default(), config).unwrap();
+
+    let result = client.job_details("10001-REFUSED-S").await;
+    assert!(matches!(result, Err(jobsuche::Error::Connect(_))));
+}
+
+#[tokio::test]
+async fn test_async_oauth_bearer_token_refreshed_after_expiry() {
+    let mut server = Server::new_async().await;
+
+    let first_token = server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "token-1", "expires_in": 1}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let second_token = server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "token-2", "expires_in": 3600}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let first_call = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("authorization", "Bearer token-1")
+        .match_header("x-api-key", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-OAUTH-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let second_call = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("authorization", "Bearer token-2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-OAUTH-S"))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let credentials = Credentials::OAuth {
+        client_id: "my-client".to_string(),
+        client_secret: "my-secret".to_string(),
+        token_url: format!("{}/oauth/token", server.url()),
+    };
+    let client = JobsucheAsync::new(server.url(), credentials).unwrap();
+
+    // token-1 expires within the refresh margin; token-2 is then cached
+    for _ in 0..3 {
+        client.job_details("10001-OAUTH-S").await.unwrap();
+    }
+
+    first_token.assert_async().await;
+    second_token.assert_async().await;
+    first_call.assert_async().await;
+    second_call.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_job_details_rejects_invalid_refnr() {
+    let mut server = Server::new_async().await;
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+
+    for refnr in ["", "MTAwMDEtMTAwMTYwMTY2Ni1T"] {
+        let result = client.job_details(refnr).await;
+        assert!(matches!(result, Err(jobsuche::Error::InvalidRefnr { .. })));
+    }
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_employer_logo_hash_path_encoding() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock(
+            "GET",
+            "/ed/v1/arbeitgeberlogo/VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=",
+        )
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(PNG_HEADER)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let bytes = client
+        .employer_logo("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=")
+        .await
+        .unwrap();
+
+    assert_eq!(bytes, PNG_HEADER);
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_async_with_host_switches_server() {
+    let mut blue = Server::new_async().await;
+    let mut green = Server::new_async().await;
+
+    let blue_mock = blue
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let green_mock = green
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(EMPTY_SEARCH_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(blue.url(), Credentials::default()).unwrap();
+    let switched = client.with_host(green.url()).unwrap();
+
+    assert!(switched.core().path(&[]).starts_with(&green.url()));
+    switched
+        .search()
+        .list(SearchOptions::builder().was("Rust").build())
+        .await
+        .unwrap();
+
+    blue_mock.assert_async().await;
+    green_mock.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_job_details_cache() {
+    use jobsuche::{CacheConfig, RequestOptions};
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(3)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let first = client.job_details("10001-1001601666-S").await.unwrap();
+    let cached = client
+        .clone()
+        .job_details("10001-1001601666-S")
+        .await
+        .unwrap();
+    assert_eq!(first, cached);
+
+    let options = RequestOptions {
+        cache_bypass: true,
+        ..Default::default()
+    };
+    client
+        .job_details_with("10001-1001601666-S", &options)
+        .await
+        .unwrap();
+
+    // The bypass refreshed the entry, so only clearing leads to another request
+    client.job_details("10001-1001601666-S").await.unwrap();
+    client.cache().clear();
+    client.job_details("10001-1001601666-S").await.unwrap();
+
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_search_cache() {
+    use jobsuche::{CacheConfig, RequestOptions};
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [{"refnr": "10001-A-S", "arbeitsort": {}}], "maxErgebnisse": 1}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let options = SearchOptions::builder().was("Rust").wo("Berlin").build();
+
+    let first = client.search().list(options.clone()).await.unwrap();
+    let reordered = SearchOptions::builder().wo("Berlin").was("Rust").build();
+    assert_eq!(client.search().list(reordered).await.unwrap(), first);
+
+    let bypass = RequestOptions {
+        cache_bypass: true,
+        ..Default::default()
+    };
+    client.search().list_with(options, &bypass).await.unwrap();
+
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_job_details_fresh() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(3)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    client.job_details("10001-1001601666-S").await.unwrap();
+    client
+        .job_details_fresh("10001-1001601666-S")
+        .await
+        .unwrap();
+    // The fresh response was stored
+    client.job_details("10001-1001601666-S").await.unwrap();
+
+    client.cache().invalidate_details("10001-1001601666-S");
+    client.job_details("10001-1001601666-S").await.unwrap();
+
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_job_details_cache_revalidates_with_etag() {
+    use jobsuche::{CacheConfig, RequestOptions};
+    use mockito::Matcher;
+
+    let mut server = Server::new_async().await;
+    let path = "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T";
+
+    // Mocks that reached their expected hits give way to the next matching one
+    let initial = server
+        .mock("GET", path)
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let unchanged = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+    let changed = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v2\"")
+        .with_body(r#"{"referenznummer": "10001-1001601666-S", "stellenangebotsTitel": "Senior Rust Developer"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let unchanged_again = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v2\"")
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    // 200 with an ETag, then served from the cache
+    for _ in 0..2 {
+        let details = client.job_details("10001-1001601666-S").await.unwrap();
+        assert_eq!(details.titel.as_deref(), Some("Job 10001-1001601666-S"));
+    }
+    initial.assert_async().await;
+
+    // Expired: If-None-Match gets a 304, which restarts the TTL
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    for _ in 0..2 {
+        let details = client.job_details("10001-1001601666-S").await.unwrap();
+        assert_eq!(details.titel.as_deref(), Some("Job 10001-1001601666-S"));
+    }
+    unchanged.assert_async().await;
+    assert_eq!(client.cache().stats().revalidations, 1);
+
+    // Expired again: a 200 replaces the entry and its ETag
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    for _ in 0..2 {
+        let details = client.job_details("10001-1001601666-S").await.unwrap();
+        assert_eq!(details.titel.as_deref(), Some("Senior Rust Developer"));
+    }
+    changed.assert_async().await;
+
+    // A cache bypass revalidates even within the TTL
+    let bypass = RequestOptions {
+        cache_bypass: true,
+        ..Default::default()
+    };
+    let details = client
+        .job_details_with("10001-1001601666-S", &bypass)
+        .await
+        .unwrap();
+    assert_eq!(details.titel.as_deref(), Some("Senior Rust Developer"));
+    unchanged_again.assert_async().await;
+
+    let stats = client.cache().stats();
+    assert_eq!(stats.revalidations, 2);
+    assert_eq!(stats.insertions, 2);
+    assert_eq!(stats.entries, 1);
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_job_details_not_found_cached() {
+    use jobsuche::{CacheConfig, RequestOptions};
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            cache_not_found: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    for _ in 0..3 {
+        let err = client.job_details("10001-1001601666-S").await.unwrap_err();
+        assert!(err.is_not_found());
+    }
+    assert_eq!(client.cache().stats().negative_hits, 2);
+
+    let bypass = RequestOptions {
+        cache_bypass: true,
+        ..Default::default()
+    };
+    let err = client
+        .job_details_with("10001-1001601666-S", &bypass)
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_job_details_cache_expires() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    client.job_details("10001-1001601666-S").await.unwrap();
+    client.job_details("10001-1001601666-S").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    client.job_details("10001-1001601666-S").await.unwrap();
+
+    let stats = client.cache().stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 2);
+    assert_eq!(stats.insertions, 2);
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_file_cache_survives_client_restart() {
+    use jobsuche::FileCache;
+    use std::sync::Arc;
+
+    let mut server = Server::new_async().await;
+
+    let details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let search = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = |dir: &std::path::Path| {
+        let config = ClientConfig {
+            cache_backend: Some(Arc::new(FileCache::new(dir))),
+            ..Default::default()
+        };
+        JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap()
+    };
+    let options = SearchOptions::builder().was("Rust").build();
+
+    let first = client(dir.path());
+    let details_first = first.job_details("10001-1001601666-S").await.unwrap();
+    let search_first = first.search().list(options.clone()).await.unwrap();
+
+    // The file I/O runs on the blocking pool; a new client reads what the first one wrote
+    let second = client(dir.path());
+    let details_second = second.job_details("10001-1001601666-S").await.unwrap();
+    let search_second = second.search().list(options).await.unwrap();
+    assert_eq!(details_first, details_second);
+    assert_eq!(search_first, search_second);
+    assert_eq!(second.cache().stats().hits, 2);
+
+    details.assert_async().await;
+    search.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_concurrent_lookups_send_one_request() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let lookups = (0..10).map(|_| {
+        let client = client.clone();
+        tokio::spawn(async move { client.job_details("10001-1001601666-S").await })
+    });
+    let results = futures::future::join_all(lookups).await;
+
+    for result in results {
+        let details = result.unwrap().unwrap();
+        assert_eq!(details.titel.as_deref(), Some("Job 10001-1001601666-S"));
+    }
+    let stats = client.cache().stats();
+    assert_eq!(stats.misses, 1);
+    assert_eq!(stats.hits, 9);
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_cache_shared_with_sync_client() {
+    use jobsuche::{ClientCore, Jobsuche, MemoryCache};
+    use std::sync::Arc;
+
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    // Both clients use the same core and backend
+    let core = ClientCore::new(server.url(), Credentials::default()).unwrap();
+    let config = ClientConfig {
+        cache_backend: Some(Arc::new(MemoryCache::new(100).unwrap())),
+        ..Default::default()
+    };
+    let async_client = JobsucheAsync::with_config_and_core(core.clone(), config.clone()).unwrap();
+
+    let from_async = async_client
+        .job_details("10001-1001601666-S")
+        .await
+        .unwrap();
+    let from_sync = tokio::task::spawn_blocking(move || {
+        Jobsuche::with_config_and_core(core, config)
+            .unwrap()
+            .job_details("10001-1001601666-S")
+            .unwrap()
+    })
+    .await
+    .unwrap();
+    assert_eq!(from_async, from_sync);
+
+    m.assert_async().await;
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+async fn test_async_warm_details_cache() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new_async().await;
+    let path = |refnr: &str| format!("/pc/v4/jobdetails/{}", jobsuche::encode_refnr(refnr));
+
+    let fresh = server
+        .mock("GET", path("10001-A-S").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-A-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let stale = server
+        .mock("GET", path("10001-B-S").as_str())
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"b1\"")
+        .with_body(job_details_body("10001-B-S"))
+        .expect(1)
+        .create_async()
+        .await;
+    let revalidated = server
+        .mock("GET", path("10001-B-S").as_str())
+        .match_header("if-none-match", "\"b1\"")
+        .with_status(304)
+        .expect(1)
+        .create_async()
+        .await;
+    let gone = server
+        .mock("GET", path("10001-C-S").as_str())
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let rejected = server
+        .mock("GET", path("10001-D-S").as_str())
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["bad request"]}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(500),
+            cache_not_found: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let refnrs = ["10001-A-S", "10001-B-S", "10001-C-S", "10001-D-S"];
+
+    // B is stale when the warmup starts, A is fresh
+    client.job_details("10001-B-S").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    client.job_details("10001-A-S").await.unwrap();
+
+    let report = client.warm_details_cache(&refnrs, 4).await.unwrap();
+    assert_eq!(report.fetched, 1);
+    assert_eq!(report.skipped, 1);
+    assert_eq!(report.not_found, 1);
+    assert_eq!(report.failed, 1);
+
+    // B was revalidated and the 404 of C is remembered; only D is requested again
+    let report = client.warm_details_cache(&refnrs, 4).await.unwrap();
+    assert_eq!(report.fetched, 0);
+    assert_eq!(report.skipped, 3);
+    assert_eq!(report.failed, 1);
+
+    fresh.assert_async().await;
+    stale.assert_async().await;
+    revalidated.assert_async().await;
+    gone.assert_async().await;
+    rejected.assert_async().await;
+}
+
+/// Counter name, labels and value from a `DebuggingRecorder` snapshot
+#[cfg(feature = "metrics")]
+type CounterEntry = (String, Vec<(String, String)>, u64);
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_async_metrics_recorded() {
+    use jobsuche::metrics::{ERRORS_TOTAL, REQUESTS_TOTAL, SUCCESSES_TOTAL};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let mut server = Server::new();
+
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .create();
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create();
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    // A local recorder only sees metrics recorded on this thread
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        runtime.block_on(async {
+            client.job_details("10001-1001601666-S").await.unwrap();
+            client.job_details("10001-1001601667-S").await.unwrap_err();
+        })
+    });
+
+    let counters: Vec<CounterEntry> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .filter_map(|(key, _, _, value)| match value {
+            DebugValue::Counter(count) => {
+                let key = key.key();
+                let labels = key
+                    .labels()
+                    .map(|l| (l.key().to_string(), l.value().to_string()))
+                    .collect();
+                Some((key.name().to_string(), labels, count))
+            }
+            _ => None,
+        })
+        .collect();
+    let count = |name: &str, class: Option<&str>| {
+        counters
+            .iter()
+            .find(|(n, labels, _)| {
+                n == name
+                    && labels.contains(&("endpoint".to_string(), "jobdetails".to_string()))
+                    && class.is_none_or(|class| {
+                        labels.contains(&("class".to_string(), class.to_string()))
+                    })
+            })
+            .map_or(0, |(_, _, count)| *count)
+    };
+
+    assert_eq!(count(REQUESTS_TOTAL, None), 2);
+    assert_eq!(count(SUCCESSES_TOTAL, None), 1);
+    assert_eq!(count(ERRORS_TOTAL, Some("4xx")), 1);
+}
+
+#[cfg(feature = "metrics")]
+#[tokio::test]
+async fn test_async_client_metrics_snapshot() {
+    let mut server = Server::new_async().await;
+
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .create_async()
+        .await;
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create_async()
+        .await;
+    let _limited = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 1,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    client.job_details("10001-1001601666-S").await.unwrap();
+    client
+        .clone()
+        .job_details("10001-1001601667-S")
+        .await
+        .unwrap_err();
+    client
+        .search()
+        .list(SearchOptions::default())
+        .await
+        .unwrap_err();
+
+    let snapshot = client.metrics();
+    assert_eq!(snapshot.requests, 4);
+    assert_eq!(snapshot.successes, 1);
+    assert_eq!(snapshot.client_errors, 3);
+    assert_eq!(snapshot.rate_limited, 2);
+    assert_eq!(snapshot.retries, 1);
+    assert_eq!(snapshot.latency.count, 4);
+    assert!(snapshot.latency.p50 <= snapshot.latency.max);
+}
+
+#[test]
+fn test_async_request_spans_for_retried_request() {
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::format::FmtSpan;
+
+    #[derive(Clone, Default)]
+    struct Logs(Arc<Mutex<Vec<u8>>>);
+    impl std::io::Write for Logs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut server = Server::new();
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(1)
+        .create();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create();
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    let logs = Logs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    // The subscriber is thread-local, so run the client on this thread
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    tracing::subscriber::with_default(subscriber, || {
+        runtime
+            .block_on(client.job_details("10001-1001601666-S"))
+            .unwrap();
+    });
+
+    let logs = String::from_utf8_lossy(&logs.0.lock().unwrap()).into_owned();
+    let closed: Vec<&str> = logs.lines().filter(|l| l.contains("close")).collect();
+    assert_eq!(closed.len(), 3, "{}", logs);
+    let first = closed
+        .iter()
+        .find(|l| l.contains("jobsuche.attempt{attempt=1"))
+        .unwrap();
+    assert!(first.contains("status=429"));
+    assert!(first.contains("retry_after_ms=0"));
+    assert!(first.contains("rate_limited"));
+    assert!(closed
+        .iter()
+        .any(|l| l.contains("jobsuche.attempt{attempt=2") && l.contains("status=200")));
+    let request = closed
+        .iter()
+        .find(|l| !l.contains("jobsuche.attempt"))
+        .unwrap();
+    assert_eq!(request.matches("attempts=").count(), 1, "{}", request);
+    assert!(request.contains("attempts=2"));
+    assert!(!logs.contains("10001-1001601666-S"));
+}
+
+#[cfg(feature = "metrics")]
+#[tokio::test]
+async fn test_async_rate_limit_telemetry() {
+    let mut server = Server::new_async().await;
+
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "1")
+        .expect(1)
+        .create_async()
+        .await;
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(job_details_body("10001-1001601666-S"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JobsucheAsync::new(server.url(), Credentials::default()).unwrap();
+    client.job_details("10001-1001601666-S").await.unwrap();
+
+    let snapshot = client.metrics();
+    assert_eq!(snapshot.rate_limited, 1);
+    assert_eq!(snapshot.retries, 1);
+    assert_eq!(snapshot.retry_after_wait, Duration::from_secs(1));
+    assert_eq!(snapshot.backoff_sleeps, 0);
+    assert_eq!(snapshot.longest_wait, Duration::from_secs(1));
+}
+
+#[test]
+fn test_async_slow_request_warning() {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Logs(Arc<Mutex<Vec<u8>>>);
+    impl std::io::Write for Logs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut server = Server::new();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|_| {
+            std::thread::sleep(Duration::from_millis(300));
+            job_details_body("10001-1001601666-S").into_bytes()
+        })
+        .create();
+    let _logo = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/abc123")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body_from_request(|_| {
+            std::thread::sleep(Duration::from_millis(300));
+            vec![0x89, b'P', b'N', b'G']
+        })
+        .create();
+
+    let config = ClientConfig {
+        slow_request_threshold: Some(Duration::from_millis(200)),
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let logs = Logs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::WARN)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    // The subscriber is thread-local, so run the client on this thread
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    tracing::subscriber::with_default(subscriber, || {
+        runtime.block_on(async {
+            client.job_details("10001-1001601666-S").await.unwrap();
+            client.employer_logo("abc123").await.unwrap();
+        })
+    });
+
+    let logs = String::from_utf8_lossy(&logs.0.lock().unwrap()).into_owned();
+    assert!(
+        logs.contains("Slow request to /pc/v4/jobdetails/{refnr}"),
+        "{}",
+        logs
+    );
+    assert!(logs.contains("Slow request to /ed/v1/arbeitgeberlogo/{hash_id}"));
+    #[cfg(feature = "metrics")]
+    assert_eq!(client.metrics().slow_requests, 2);
+}
+
+#[tokio::test]
+async fn test_async_job_watcher_delivers_only_new_listings() {
+    use jobsuche::JobWatcherAsync;
+
+    let mut server = Server::new_async().await;
+    let page = |refnrs: &[&str]| {
+        let listings: Vec<String> = refnrs
+            .iter()
+            .map(|refnr| format!(r#"{{"refnr": "{}", "arbeitsort": {{}}}}"#, refnr))
+            .collect();
+        format!(r#"{{"stellenangebote": [{}]}}"#, listings.join(","))
+    };
+    let jobs_path = || mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string());
+    let first = server
+        .mock("GET", jobs_path())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(&["10001-A-S", "10001-B-S"]))
+        .expect(1)
+        .create_async()
+        .await;
+    let failure = server
+        .mock("GET", jobs_path())
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    let _second = server
+        .mock("GET", jobs_path())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page(&["10001-B-S", "10001-C-S"]))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        max_retries: 0,
+        ..Default::default()
+    };
+    let client = JobsucheAsync::with_config(server.url(), Credentials::default(), config).unwrap();
+    let (watcher, mut jobs) = JobWatcherAsync::channel(
+        &client,
+        SearchOptions::builder().was("Rust").build(),
+        Duration::from_millis(50),
+    )
+    .unwrap();
+
+    let mut delivered = Vec::new();
+    for _ in 0..3 {
+        let job = tokio::time::timeout(Duration::from_secs(5), jobs.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        delivered.push(job.refnr.to_string());
+    }
+    assert_eq!(delivered, ["10001-A-S", "10001-B-S", "10001-C-S"]);
+
+    // Later polls see the same listings again
+    assert!(
+        tokio::time::timeout(Duration::from_millis(200), jobs.recv())
+            .await
+            .is_err()
+    );
+    assert!(!watcher.is_finished());
+
+    watcher.stop().await;
+    assert!(jobs.recv().await.is_none());
+    first.assert_async().await;
+    failure.assert_async().await;
+}
@@ -0,0 +1,72 @@
+//! Tests for environment-based configuration
+//!
+//! Environment variables are process-global, so every test holds `ENV_LOCK` while it
+//! modifies them.
+
+use jobsuche::{Credentials, Jobsuche, API_KEY_ENV, HOST_ENV};
+use std::sync::Mutex;
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+fn api_key(credentials: &Credentials) -> &str {
+    match credentials {
+        Credentials::ApiKey(key) => key,
+        Credentials::OAuth { .. } => panic!("expected API key credentials"),
+    }
+}
+
+#[test]
+fn test_credentials_from_env() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::set_var(API_KEY_ENV, "deployment-key");
+    assert_eq!(api_key(&Credentials::from_env()), "deployment-key");
+
+    std::env::remove_var(API_KEY_ENV);
+    assert_eq!(api_key(&Credentials::from_env()), "jobboerse-jobsuche");
+
+    std::env::set_var(API_KEY_ENV, "");
+    assert_eq!(api_key(&Credentials::from_env()), "jobboerse-jobsuche");
+
+    std::env::remove_var(API_KEY_ENV);
+}
+
+#[test]
+fn test_client_from_env() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::set_var(HOST_ENV, "https://jobsuche.example.com/api");
+    std::env::set_var(API_KEY_ENV, "deployment-key");
+    let client = Jobsuche::from_env().unwrap();
+    assert_eq!(
+        client.core().host.as_str(),
+        "https://jobsuche.example.com/api"
+    );
+    assert_eq!(client.core().api_key(), "deployment-key");
+
+    std::env::remove_var(HOST_ENV);
+    std::env::remove_var(API_KEY_ENV);
+    let client = Jobsuche::from_env().unwrap();
+    assert_eq!(client.core().host.as_str(), jobsuche::DEFAULT_HOST);
+    assert_eq!(client.core().api_key(), "jobboerse-jobsuche");
+
+    std::env::set_var(HOST_ENV, "");
+    std::env::set_var(API_KEY_ENV, "");
+    let client = Jobsuche::from_env().unwrap();
+    assert_eq!(client.core().host.as_str(), jobsuche::DEFAULT_HOST);
+    assert_eq!(client.core().api_key(), "jobboerse-jobsuche");
+
+    std::env::remove_var(HOST_ENV);
+    std::env::remove_var(API_KEY_ENV);
+}
+
+#[test]
+fn test_client_from_env_invalid_host() {
+    let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    std::env::set_var(HOST_ENV, "not a url");
+    let result = Jobsuche::from_env();
+    std::env::remove_var(HOST_ENV);
+
+    assert!(matches!(result, Err(jobsuche::Error::ParseError(_))));
+}
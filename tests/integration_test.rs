@@ -100,7 +100,7 @@ fn test_real_api_job_details() {
                     println!("Employer: {}", employer);
                 }
             }
-            Err(jobsuche::Error::NotFound) => {
+            Err(jobsuche::Error::NotFound { .. }) => {
                 println!("Job expired (404) - this is expected and OK (Issue #61)");
             }
             Err(e) => {
@@ -235,7 +235,7 @@ fn test_real_api_employer_logo() {
                     println!("Successfully got logo: {} bytes", logo_bytes.len());
                     assert!(!logo_bytes.is_empty(), "Logo should have data");
                 }
-                Err(jobsuche::Error::NotFound) => {
+                Err(jobsuche::Error::NotFound { .. }) => {
                     println!("Logo not available (404) - this is expected");
                     // This is expected for most employers (Issue #62)
                 }
@@ -7,6 +7,11 @@ use jobsuche::{Arbeitszeit, ClientConfig, Credentials, Jobsuche, SearchOptions};
 use mockito::Server;
 use std::time::Duration;
 
+/// Job details response body, using the API's field names
+fn details_body(refnr: &str, titel: Option<&str>) -> String {
+    serde_json::json!({"referenznummer": refnr, "stellenangebotsTitel": titel}).to_string()
+}
+
 #[test]
 fn test_search_with_mock() {
     let mut server = Server::new();
@@ -141,11 +146,98 @@ fn test_404_error_handling() {
 
     let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("testref"); // Will be base64 encoded
+    let result = client.job_details("10001-TESTREF-S"); // Will be base64 encoded
 
     // We expect a NotFound error
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::NotFound));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::NotFound { .. }
+    ));
+}
+
+#[test]
+fn test_error_display_includes_request_url() {
+    let mut server = Server::new();
+    let encoded = jobsuche::encode_refnr("10001-EXPIRED-S");
+
+    let _m404 = server
+        .mock("GET", format!("/pc/v4/jobdetails/{}", encoded).as_str())
+        .with_status(404)
+        .create();
+    let _m500 = server
+        .mock("GET", "/pc/v4/jobs")
+        .match_query(mockito::Matcher::Any)
+        .with_status(500)
+        .with_body(r#"{"errors": ["boom"]}"#)
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(
+        server.url(),
+        Credentials::ApiKey("secret-key".to_string()),
+        config,
+    )
+    .unwrap();
+
+    let error = client.job_details("10001-EXPIRED-S").unwrap_err();
+    let request = error.request().expect("404 should carry the request");
+    assert_eq!(request.endpoint, jobsuche::Endpoint::JobDetails);
+    let display = error.to_string();
+    assert!(display.contains(&encoded), "{}", display);
+    assert!(display.contains(&server.url()), "{}", display);
+    assert!(!display.contains("secret-key"), "{}", display);
+
+    let error = client
+        .search()
+        .list(SearchOptions::builder().was("Koch").build())
+        .unwrap_err();
+    let display = error.to_string();
+    assert!(matches!(error, jobsuche::Error::Fault { .. }));
+    assert!(display.contains("jobs request to"), "{}", display);
+    assert!(display.contains("was=Koch"), "{}", display);
+}
+
+#[test]
+fn test_not_found_reports_resource_kind() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(404)
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+
+    match client.job_details("10001-EXPIRED-S").unwrap_err() {
+        jobsuche::Error::NotFound { resource, id, .. } => {
+            assert_eq!(resource, jobsuche::ResourceKind::JobDetails);
+            assert_eq!(id.as_deref(), Some("10001-EXPIRED-S"));
+        }
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    match client.employer_logo("abc").unwrap_err() {
+        jobsuche::Error::NotFound { resource, id, .. } => {
+            assert_eq!(resource, jobsuche::ResourceKind::EmployerLogo);
+            assert_eq!(id.as_deref(), Some("abc"));
+        }
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    let error = client.search().list(SearchOptions::default()).unwrap_err();
+    assert!(error.is_not_found());
+    assert!(matches!(
+        error,
+        jobsuche::Error::NotFound {
+            resource: jobsuche::ResourceKind::Search,
+            id: None,
+            ..
+        }
+    ));
 }
 
 #[test]
@@ -159,7 +251,10 @@ fn test_401_unauthorized() {
     let result = client.search().list(SearchOptions::default());
 
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::Unauthorized));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::Unauthorized { .. }
+    ));
 }
 
 #[test]
@@ -339,6 +434,7 @@ fn test_timeout_configuration() {
         connect_timeout: Duration::from_secs(2),
         max_retries: 2,
         retry_enabled: true,
+        ..Default::default()
     };
 
     let server = Server::new();
@@ -388,8 +484,59 @@ fn test_rate_limit_429_detection() {
     // Should detect rate limit error with Retry-After
     assert!(result.is_err());
     match result.unwrap_err() {
-        jobsuche::Error::RateLimited { retry_after } => {
-            assert_eq!(retry_after, Some(60), "Should parse Retry-After header");
+        jobsuche::Error::RateLimited {
+            retry_after,
+            retry_at,
+            ..
+        } => {
+            assert_eq!(
+                retry_after,
+                Some(Duration::from_secs(60)),
+                "Should parse Retry-After header"
+            );
+            let retry_at = retry_at.expect("numeric Retry-After should set retry_at");
+            let remaining = retry_at
+                .duration_since(std::time::SystemTime::now())
+                .unwrap();
+            assert!(remaining > Duration::from_secs(50));
+            assert!(remaining <= Duration::from_secs(60));
+        }
+        other => panic!("Expected RateLimited error, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_rate_limit_429_with_http_date() {
+    let mut server = Server::new();
+
+    let at = std::time::SystemTime::now() + Duration::from_secs(300);
+    let _m = server
+        .mock("GET", "/pc/v4/jobs")
+        .with_status(429)
+        .with_header("Retry-After", &httpdate::fmt_http_date(at))
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.search().list(SearchOptions::default()).unwrap_err() {
+        jobsuche::Error::RateLimited {
+            retry_after,
+            retry_at,
+            ..
+        } => {
+            // HTTP-dates have a resolution of one second
+            let retry_at = retry_at.expect("HTTP-date should set retry_at");
+            assert_eq!(
+                httpdate::fmt_http_date(retry_at),
+                httpdate::fmt_http_date(at)
+            );
+            let retry_after = retry_after.expect("future date should set retry_after");
+            assert!(retry_after > Duration::from_secs(290));
+            assert!(retry_after <= Duration::from_secs(300));
         }
         other => panic!("Expected RateLimited error, got: {:?}", other),
     }
@@ -415,8 +562,13 @@ fn test_rate_limit_429_without_retry_after() {
     // Should detect rate limit error without Retry-After
     assert!(result.is_err());
     match result.unwrap_err() {
-        jobsuche::Error::RateLimited { retry_after } => {
+        jobsuche::Error::RateLimited {
+            retry_after,
+            retry_at,
+            ..
+        } => {
             assert_eq!(retry_after, None, "Should have no Retry-After header");
+            assert_eq!(retry_at, None);
         }
         other => panic!("Expected RateLimited error, got: {:?}", other),
     }
@@ -427,14 +579,20 @@ fn test_rate_limit_error_display() {
     use jobsuche::Error;
 
     let error = Error::RateLimited {
-        retry_after: Some(60),
+        retry_after: Some(Duration::from_secs(60)),
+        retry_at: None,
+        request: None,
     };
 
     let display = format!("{}", error);
     assert!(display.contains("Rate limited"));
-    assert!(display.contains("60"));
+    assert!(display.contains("retry in 1m 0s"));
 
-    let error_no_retry = Error::RateLimited { retry_after: None };
+    let error_no_retry = Error::RateLimited {
+        retry_after: None,
+        retry_at: None,
+        request: None,
+    };
     let display_no_retry = format!("{}", error_no_retry);
     assert!(display_no_retry.contains("Rate limited"));
 }
@@ -488,6 +646,7 @@ fn test_with_config_and_core() {
         connect_timeout: Duration::from_secs(5),
         max_retries: 2,
         retry_enabled: true,
+        ..Default::default()
     };
 
     let client = Jobsuche::with_config_and_core(core, config);
@@ -505,9 +664,12 @@ fn test_403_forbidden() {
 
     let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::Forbidden));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::Forbidden { .. }
+    ));
 }
 
 #[test]
@@ -521,11 +683,11 @@ fn test_405_method_not_allowed() {
 
     let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
     assert!(matches!(
         result.unwrap_err(),
-        jobsuche::Error::MethodNotAllowed
+        jobsuche::Error::MethodNotAllowed { .. }
     ));
 }
 
@@ -557,14 +719,23 @@ fn test_500_server_error_with_api_errors() {
 
     let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
 
-    // Should get either Fault or Http error
-    assert!(matches!(
-        result.unwrap_err(),
-        jobsuche::Error::Fault { .. } | jobsuche::Error::Http(_)
-    ));
+    // The object form of the error payload is parsed and shown in the message
+    let error = result.unwrap_err();
+    assert!(
+        error.to_string().contains("]: Internal server error"),
+        "{}",
+        error
+    );
+    match error {
+        jobsuche::Error::Fault { errors, .. } => {
+            assert_eq!(errors.errors[0].code.as_deref(), Some("INTERNAL_ERROR"));
+            assert_eq!(errors.error_messages, vec!["Internal server error"]);
+        }
+        other => panic!("expected Fault, got {:?}", other),
+    }
 }
 
 #[test]
@@ -585,11 +756,224 @@ fn test_500_server_error_plain_text() {
 
     let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
 
-    // Should get HTTP error for unparseable response
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::Http(_)));
+    // Unparseable responses still produce a Fault that keeps the raw body
+    match result.unwrap_err() {
+        jobsuche::Error::Fault { code, body, .. } => {
+            assert_eq!(code, 500);
+            assert_eq!(body.as_deref(), Some("Internal Server Error"));
+        }
+        other => panic!("expected Fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_400_invalid_request_messages() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobs")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "umkreis".into(),
+            "9999".into(),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "errors": [
+                    {"code": "VALIDATION_ERROR", "message": "umkreis: must be less than or equal to 200"},
+                    {"code": "VALIDATION_ERROR", "message": "size: must be less than or equal to 100"}
+                ]
+            }"#,
+        )
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let err = client
+        .search()
+        .list(SearchOptions::builder().umkreis(9999).build())
+        .unwrap_err();
+
+    assert_eq!(err.status_code(), Some(jobsuche::StatusCode::BAD_REQUEST));
+    assert_eq!(err.kind(), jobsuche::ErrorKind::Client);
+    assert!(!err.is_retryable());
+    assert!(err
+        .to_string()
+        .contains("umkreis: must be less than or equal to 200; size: must be"));
+    match err {
+        jobsuche::Error::InvalidRequest {
+            messages, request, ..
+        } => {
+            assert_eq!(
+                messages,
+                vec![
+                    "umkreis: must be less than or equal to 200",
+                    "size: must be less than or equal to 100"
+                ]
+            );
+            assert!(request.unwrap().url.contains("umkreis=9999"));
+        }
+        other => panic!("expected InvalidRequest, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_400_without_api_errors_keeps_body() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(400)
+        .with_header("content-type", "text/plain")
+        .with_body("Bad Request")
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.job_details("10001-TEST-S").unwrap_err() {
+        jobsuche::Error::InvalidRequest { messages, body, .. } => {
+            assert!(messages.is_empty());
+            assert_eq!(body.as_deref(), Some("Bad Request"));
+        }
+        other => panic!("expected InvalidRequest, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_html_error_page_kept_in_fault() {
+    let mut server = Server::new();
+
+    let page = "<html><body><h1>502 Bad Gateway</h1><p>upstream down</p></body></html>";
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(502)
+        .with_header("content-type", "text/html")
+        .with_body(page)
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-TEST-S").unwrap_err();
+    assert!(error.to_string().contains("upstream down"), "{}", error);
+    match error {
+        jobsuche::Error::Fault { code, body, .. } => {
+            assert_eq!(code, 502);
+            assert_eq!(body.as_deref(), Some(page));
+        }
+        other => panic!("expected Fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_fault_body_truncated_to_limit() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(500)
+        .with_body("x".repeat(10_000))
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        error_body_limit: 100,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.job_details("10001-TEST-S").unwrap_err() {
+        jobsuche::Error::Fault {
+            body: Some(body), ..
+        } => {
+            assert_eq!(&*body, format!("{}…", "x".repeat(100)));
+        }
+        other => panic!("expected Fault with body, got {:?}", other),
+    }
+
+    // A limit of 0 keeps no body at all
+    let config = ClientConfig {
+        retry_enabled: false,
+        error_body_limit: 0,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    assert!(matches!(
+        client.job_details("10001-TEST-S"),
+        Err(jobsuche::Error::Fault { body: None, .. })
+    ));
+}
+
+#[test]
+fn test_missing_field_reports_path() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobs")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"stellenangebote": [
+                {"refnr": "10001-A-S", "titel": "Koch", "arbeitsort": {"ort": "Berlin"}},
+                {"referenznummer": "10001-B-S", "titel": "Kellner", "arbeitsort": {"ort": "Berlin"}}
+            ]}"#,
+        )
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+
+    let error = client.search().list(SearchOptions::default()).unwrap_err();
+    assert!(
+        error.to_string().contains("`stellenangebote[1]`"),
+        "{}",
+        error
+    );
+    match error {
+        jobsuche::Error::Decode { path, message, .. } => {
+            assert_eq!(path, "stellenangebote[1]");
+            assert!(message.contains("missing field `refnr`"), "{}", message);
+        }
+        other => panic!("expected Decode, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_malformed_json_reports_body_snippet() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"refnr": "10001-TEST-S", "titel": <!-- maintenance -->}"#)
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+
+    match client.job_details("10001-TEST-S").unwrap_err() {
+        jobsuche::Error::Decode {
+            message, snippet, ..
+        } => {
+            assert!(message.contains("column"), "{}", message);
+            assert!(snippet.contains("<!-- maintenance -->"), "{}", snippet);
+        }
+        other => panic!("expected Decode, got {:?}", other),
+    }
 }
 
 #[test]
@@ -636,7 +1020,10 @@ fn test_employer_logo_not_found() {
 
     let result = client.employer_logo("nonexistent");
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), jobsuche::Error::NotFound));
+    assert!(matches!(
+        result.unwrap_err(),
+        jobsuche::Error::NotFound { .. }
+    ));
 }
 
 #[test]
@@ -1029,6 +1416,61 @@ fn test_pagination_empty_page_mid_stream_stops() {
     assert_eq!(all_jobs.len(), 2);
 }
 
+/// Errors from a later page carry the pagination context but keep
+/// their kind and root variant.
+#[test]
+fn test_pagination_error_has_context() {
+    let mut server = Server::new();
+
+    let _m1 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=1.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "stellenangebote": [
+                    {"refnr": "1", "beruf": "Job 1", "arbeitsort": {"ort": "Berlin"}},
+                    {"refnr": "2", "beruf": "Job 2", "arbeitsort": {"ort": "Berlin"}}
+                ],
+                "maxErgebnisse": 4,
+                "page": 1,
+                "size": 2
+            }"#,
+        )
+        .create();
+
+    let _m2 = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*page=2.*".to_string()),
+        )
+        .with_status(403)
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let err = client
+        .search()
+        .jobs(SearchOptions::builder().size(2).build())
+        .unwrap()
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .unwrap_err();
+
+    assert!(matches!(err.root(), jobsuche::Error::Forbidden { .. }));
+    assert_eq!(err.kind(), jobsuche::ErrorKind::Auth);
+    assert_eq!(err.status_code(), Some(jobsuche::StatusCode::FORBIDDEN));
+    assert!(err
+        .to_string()
+        .starts_with("fetching a page of search results: "));
+}
+
 #[test]
 fn test_503_service_unavailable_no_retry() {
     let mut server = Server::new();
@@ -1045,7 +1487,7 @@ fn test_503_service_unavailable_no_retry() {
 
     let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
 
     // Should get either Fault or Http error
@@ -1071,6 +1513,2122 @@ fn test_504_gateway_timeout() {
 
     let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
 
-    let result = client.job_details("test");
+    let result = client.job_details("10001-TEST-S");
     assert!(result.is_err());
 }
+
+// --- Request/response hook tests ---
+
+#[test]
+fn test_hooks_fire_with_redacted_api_key() {
+    use jobsuche::{RequestInfo, ResponseInfo};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new();
+
+    let _m = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*was=Hook.*".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create();
+
+    let requests: Arc<Mutex<Vec<RequestInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let responses: Arc<Mutex<Vec<ResponseInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let request_sink = requests.clone();
+    let response_sink = responses.clone();
+
+    let config = ClientConfig {
+        on_request: Some(Arc::new(move |info: &RequestInfo| {
+            request_sink.lock().unwrap().push(info.clone());
+        })),
+        on_response: Some(Arc::new(move |info: &ResponseInfo| {
+            response_sink.lock().unwrap().push(info.clone());
+        })),
+        capture_response_body: Some(16),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(
+        server.url(),
+        Credentials::ApiKey("super-secret".to_string()),
+        config,
+    )
+    .unwrap();
+
+    client
+        .search()
+        .list(SearchOptions::builder().was("Hook").build())
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].url.contains("was=Hook"));
+    assert!(requests[0]
+        .headers
+        .iter()
+        .any(|(name, value)| name == "x-api-key" && value == "[REDACTED]"));
+    assert!(!format!("{:?}", requests[0]).contains("super-secret"));
+
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, 200);
+    assert_eq!(responses[0].body.as_deref(), Some(r#"{"stellenangebot"#));
+}
+
+#[test]
+fn test_response_hook_without_body_capture() {
+    use jobsuche::ResponseInfo;
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(404)
+        .create();
+
+    let responses: Arc<Mutex<Vec<ResponseInfo>>> = Arc::new(Mutex::new(Vec::new()));
+    let sink = responses.clone();
+
+    let config = ClientConfig {
+        on_response: Some(Arc::new(move |info: &ResponseInfo| {
+            sink.lock().unwrap().push(info.clone());
+        })),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let _ = client.job_details("10001-HOOK-S");
+
+    let responses = responses.lock().unwrap();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].status, 404);
+    assert!(responses[0].body.is_none());
+}
+
+#[test]
+fn test_hooks_called_for_every_attempt() {
+    use jobsuche::{RequestInfo, ResponseInfo};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new();
+
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .with_body("slow down")
+        .expect(1)
+        .create();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(1)
+        .create();
+    let _logo = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/abc123")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body([0x89, b'P', b'N', b'G'])
+        .create();
+
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let request_sink = events.clone();
+    let response_sink = events.clone();
+    let config = ClientConfig {
+        on_request: Some(Arc::new(move |info: &RequestInfo| {
+            request_sink.lock().unwrap().push(format!(
+                "request {} #{}",
+                info.endpoint.as_str(),
+                info.attempt
+            ));
+        })),
+        on_response: Some(Arc::new(move |info: &ResponseInfo| {
+            response_sink.lock().unwrap().push(format!(
+                "response {} #{} {} {:?}",
+                info.endpoint.as_str(),
+                info.attempt,
+                info.status.as_u16(),
+                info.bytes
+            ));
+        })),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    client.job_details("10001-1001601666-S").unwrap();
+    assert_eq!(client.employer_logo("abc123").unwrap().len(), 4);
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "request jobdetails #1",
+            "response jobdetails #1 429 Some(9)",
+            "request jobdetails #2",
+            "response jobdetails #2 200 Some(67)",
+            "request logo #1",
+            "response logo #1 200 Some(4)",
+        ]
+    );
+}
+
+#[test]
+fn test_panicking_hook_does_not_fail_request() {
+    use jobsuche::{RequestInfo, ResponseInfo};
+    use std::sync::Arc;
+
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        on_request: Some(Arc::new(|_: &RequestInfo| panic!("broken request hook"))),
+        on_response: Some(Arc::new(|_: &ResponseInfo| panic!("broken response hook"))),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    for _ in 0..2 {
+        let job = client.job_details("10001-1001601666-S").unwrap();
+        assert_eq!(job.refnr.as_deref(), Some("10001-1001601666-S"));
+    }
+}
+
+#[test]
+fn test_request_id_sent_and_attached_to_fault() {
+    use jobsuche::RequestInfo;
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header(
+            "x-request-id",
+            mockito::Matcher::Regex(r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-".to_string()),
+        )
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["unprocessable"]}"#)
+        .create();
+
+    let sent_id: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    let sink = sent_id.clone();
+    let config = ClientConfig {
+        retry_enabled: false,
+        on_request: Some(Arc::new(move |info: &RequestInfo| {
+            *sink.lock().unwrap() = info
+                .headers
+                .iter()
+                .find(|(name, _)| name == "x-request-id")
+                .map(|(_, value)| value.clone());
+        })),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.job_details("10001-TEST-S").unwrap_err() {
+        jobsuche::Error::Fault { request_id, .. } => {
+            let sent = sent_id.lock().unwrap().clone();
+            assert!(sent.is_some());
+            assert_eq!(request_id, sent);
+        }
+        other => panic!("Expected Fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_request_id_header_disabled() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .match_header("x-request-id", mockito::Matcher::Missing)
+        .with_status(422)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["unprocessable"]}"#)
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        request_id_header: None,
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    match client.job_details("10001-TEST-S").unwrap_err() {
+        jobsuche::Error::Fault { request_id, .. } => assert_eq!(request_id, None),
+        other => panic!("Expected Fault, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_client_with_tls_config() {
+    let config = ClientConfig {
+        root_certificates_pem: vec![include_bytes!("fixtures/test-ca.pem").to_vec()],
+        min_tls_version: Some(reqwest::tls::Version::TLS_1_2),
+        danger_accept_invalid_certs: true,
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(jobsuche::DEFAULT_HOST, Credentials::default(), config)
+        .expect("client with custom TLS config should build");
+    assert_eq!(client.config().root_certificates_pem.len(), 1);
+    assert!(client.config().danger_accept_invalid_certs);
+}
+
+#[test]
+fn test_client_rejects_invalid_root_certificate() {
+    let config = ClientConfig {
+        root_certificates_pem: vec![b"definitely not a certificate".to_vec()],
+        ..Default::default()
+    };
+
+    let result = Jobsuche::with_config(jobsuche::DEFAULT_HOST, Credentials::default(), config);
+    assert!(matches!(result, Err(jobsuche::Error::ConfigError { .. })));
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_blocking_client_inside_tokio_runtime() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .create_async()
+        .await;
+
+    // Used to panic inside reqwest ("Cannot drop a runtime in a context where blocking is not allowed")
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let result = client
+        .search()
+        .list(SearchOptions::builder().was("Runtime").build())
+        .unwrap();
+
+    assert_eq!(result.stellenangebote.len(), 0);
+}
+
+#[test]
+fn test_retry_deadline_stops_retrying() {
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(2)
+        .create();
+
+    // Backoff sleeps 1s, then 2s: the second sleep would exceed the 1.5s budget
+    let config = ClientConfig {
+        max_retries: 5,
+        retry_deadline: Some(Duration::from_millis(1500)),
+        ..Default::default()
+    };
+
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let start = std::time::Instant::now();
+    let result = client.job_details("10001-DEADLINE-S");
+
+    assert!(start.elapsed() < Duration::from_millis(1500));
+    match result.unwrap_err() {
+        jobsuche::Error::RetriesExhausted {
+            attempts, source, ..
+        } => {
+            assert_eq!(attempts, 2);
+            assert!(matches!(
+                *source,
+                jobsuche::Error::Fault {
+                    code: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    ..
+                }
+            ));
+        }
+        other => panic!("expected RetriesExhausted, got {:?}", other),
+    }
+    m.assert();
+}
+
+#[test]
+fn test_read_timeout_error() {
+    let mut server = Server::new();
+
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(2));
+            w.write_all(br#"{"referenznummer": "10001-SLOW-S"}"#)
+        })
+        .create();
+
+    let config = ClientConfig {
+        timeout: Duration::from_millis(300),
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let result = client.job_details("10001-SLOW-S");
+    assert!(matches!(
+        result,
+        Err(jobsuche::Error::Timeout {
+            phase: jobsuche::TimeoutPhase::Read,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_read_timeout_is_retried() {
+    let mut server = Server::new();
+
+    // mockito only counts a chunked response once its body is written, which never happens
+    // before the client gives up, so the attempts are checked on the error instead
+    let _m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(1));
+            w.write_all(br#"{"referenznummer": "10001-SLOW-S"}"#)
+        })
+        .create();
+
+    let config = ClientConfig {
+        timeout: Duration::from_millis(200),
+        max_retries: 1,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-SLOW-S").unwrap_err();
+    assert!(matches!(
+        error,
+        jobsuche::Error::RetriesExhausted { attempts: 2, .. }
+    ));
+    assert!(error.root().is_retryable());
+    assert!(matches!(error.root(), jobsuche::Error::Timeout { .. }));
+}
+
+#[test]
+fn test_retries_exhausted_reports_attempts() {
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        max_retries: 1,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-DOWN-S").unwrap_err();
+    assert!(!error.is_retryable());
+    assert_eq!(
+        error.status_code(),
+        Some(jobsuche::StatusCode::SERVICE_UNAVAILABLE)
+    );
+    assert!(error
+        .to_string()
+        .starts_with("Giving up after 2 attempts in "));
+    match error {
+        jobsuche::Error::RetriesExhausted {
+            attempts, elapsed, ..
+        } => {
+            assert_eq!(attempts, 2);
+            // One backoff sleep of at least a second
+            assert!(elapsed >= Duration::from_secs(1), "{:?}", elapsed);
+        }
+        other => panic!("expected RetriesExhausted, got {:?}", other),
+    }
+    m.assert();
+}
+
+#[test]
+fn test_failure_without_retry_is_not_wrapped() {
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        max_retries: 0,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let error = client.job_details("10001-DOWN-S").unwrap_err();
+    assert!(matches!(error, jobsuche::Error::Fault { .. }));
+    assert!(error.is_retryable());
+    m.assert();
+}
+
+#[test]
+fn test_connection_refused_error() {
+    // Bind and drop a listener so nothing is listening on the port
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(url, Credentials::default(), config).unwrap();
+
+    let result = client.job_details("10001-REFUSED-S");
+    assert!(matches!(result, Err(jobsuche::Error::Connect(_))));
+    assert_eq!(result.unwrap_err().kind(), jobsuche::ErrorKind::Transient);
+}
+
+fn oauth_credentials(server: &mockito::ServerGuard) -> Credentials {
+    Credentials::OAuth {
+        client_id: "my-client".to_string(),
+        client_secret: "my-secret".to_string(),
+        token_url: format!("{}/oauth/token", server.url()),
+    }
+}
+
+#[test]
+fn test_oauth_bearer_token_cached() {
+    let mut server = Server::new();
+
+    let token = server
+        .mock("POST", "/oauth/token")
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+            mockito::Matcher::UrlEncoded("client_id".into(), "my-client".into()),
+            mockito::Matcher::UrlEncoded("client_secret".into(), "my-secret".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "token-1", "token_type": "Bearer", "expires_in": 3600}"#)
+        .expect(1)
+        .create();
+
+    let api = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .match_header("authorization", "Bearer token-1")
+        .match_header("x-api-key", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(2)
+        .create();
+
+    let client = Jobsuche::new(server.url(), oauth_credentials(&server)).unwrap();
+    for _ in 0..2 {
+        client
+            .search()
+            .list(SearchOptions::builder().was("Rust").build())
+            .unwrap();
+    }
+
+    token.assert();
+    api.assert();
+}
+
+#[test]
+fn test_oauth_token_refreshed_after_expiry() {
+    let mut server = Server::new();
+
+    // An expiry inside the refresh margin forces a new token for every request
+    let first_token = server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "token-1", "expires_in": 1}"#)
+        .expect(1)
+        .create();
+    let second_token = server
+        .mock("POST", "/oauth/token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"access_token": "token-2", "expires_in": 3600}"#)
+        .expect(1)
+        .create();
+
+    let first_call = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .match_header("authorization", "Bearer token-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create();
+    let second_call = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .match_header("authorization", "Bearer token-2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create();
+
+    let client = Jobsuche::new(server.url(), oauth_credentials(&server)).unwrap();
+    for _ in 0..2 {
+        client
+            .search()
+            .list(SearchOptions::builder().was("Rust").build())
+            .unwrap();
+    }
+
+    first_token.assert();
+    second_token.assert();
+    first_call.assert();
+    second_call.assert();
+}
+
+#[test]
+fn test_oauth_token_failure_is_unauthorized() {
+    let mut server = Server::new();
+
+    let _token = server
+        .mock("POST", "/oauth/token")
+        .with_status(401)
+        .with_body(r#"{"error": "invalid_client"}"#)
+        .create();
+    let api = server.mock("GET", mockito::Matcher::Any).expect(0).create();
+
+    let client = Jobsuche::new(server.url(), oauth_credentials(&server)).unwrap();
+    let result = client.job_details("10001-OAUTH-S");
+
+    match result {
+        Err(jobsuche::Error::Unauthorized {
+            context: Some(context),
+            ..
+        }) => {
+            assert!(context.contains("/oauth/token"));
+            assert!(context.contains("401"));
+        }
+        other => panic!("expected Unauthorized with context, got {:?}", other),
+    }
+    api.assert();
+}
+
+#[test]
+fn test_job_details_rejects_encoded_refnr() {
+    let mut server = Server::new();
+    let m = server.mock("GET", mockito::Matcher::Any).expect(0).create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let result = client.job_details("MTAwMDEtMTAwMTYwMTY2Ni1T");
+
+    assert!(matches!(result, Err(jobsuche::Error::InvalidRefnr { .. })));
+    m.assert();
+}
+
+#[test]
+fn test_job_details_validation_can_be_disabled() {
+    let mut server = Server::new();
+    let m = server
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"referenznummer": "FUTURE/FORMAT"}"#)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        validate_refnr: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let job = client.job_details("FUTURE/FORMAT").unwrap();
+
+    assert_eq!(job.refnr.as_deref(), Some("FUTURE/FORMAT"));
+    m.assert();
+}
+
+#[test]
+fn test_employer_logo_hash_path_encoding() {
+    let mut server = Server::new();
+
+    // mockito compares against the raw request path, so this also rules out double-encoding
+    let m = server
+        .mock(
+            "GET",
+            "/ed/v1/arbeitgeberlogo/VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=",
+        )
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(b"\x89PNG\r\n\x1a\n")
+        .create();
+    let escaped = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/a%2Fb%20c+d=")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(b"\x89PNG\r\n\x1a\n")
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    client
+        .employer_logo("VK2qoXBe0s-UAdH_qxLDRrZrY5iY8a1PJt3MjJCXsdo=")
+        .unwrap();
+    client.employer_logo("a/b c+d=").unwrap();
+
+    m.assert();
+    escaped.assert();
+}
+
+#[test]
+fn test_with_host_switches_server() {
+    let mut blue = Server::new();
+    let mut green = Server::new();
+
+    let blue_mock = blue
+        .mock("GET", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create();
+    let green_mock = green
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .match_header("x-api-key", "custom-key")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        max_retries: 7,
+        ..Default::default()
+    };
+    let credentials = Credentials::ApiKey("custom-key".to_string());
+    let client = Jobsuche::with_config(blue.url(), credentials, config).unwrap();
+    let switched = client.with_host(green.url()).unwrap();
+
+    assert_eq!(switched.config().max_retries, 7);
+    switched
+        .search()
+        .list(SearchOptions::builder().was("Rust").build())
+        .unwrap();
+    client
+        .search()
+        .list(SearchOptions::builder().was("Rust").build())
+        .unwrap();
+
+    blue_mock.assert();
+    green_mock.assert();
+}
+
+#[test]
+fn test_job_details_encoded_refnr_error_names_decoded_form() {
+    let mut server = Server::new();
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"referenznummer": "10001-1001601666-S"}"#)
+        .expect(1)
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+
+    // Decoded form: encoded exactly once
+    let job = client.job_details("10001-1001601666-S").unwrap();
+    assert_eq!(job.refnr.as_deref(), Some("10001-1001601666-S"));
+
+    // Encoded form: rejected with guidance
+    match client.job_details("MTAwMDEtMTAwMTYwMTY2Ni1T") {
+        Err(jobsuche::Error::InvalidRefnr { reason }) => {
+            assert!(reason.contains("10001-1001601666-S"), "{}", reason)
+        }
+        other => panic!("expected InvalidRefnr, got {:?}", other),
+    }
+
+    // Garbage: rejected without a decoded suggestion
+    match client.job_details("%%%") {
+        Err(jobsuche::Error::InvalidRefnr { reason }) => {
+            assert!(!reason.contains("already base64-encoded"), "{}", reason)
+        }
+        other => panic!("expected InvalidRefnr, got {:?}", other),
+    }
+
+    m.assert();
+}
+
+#[test]
+fn test_unknown_fields_policy() {
+    let mut server = Server::new();
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"referenznummer": "10001-1001601666-S", "tarifvertrag": "TVoeD"}"#)
+        .expect(2)
+        .create();
+
+    // Ignored by default
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let job = client.job_details("10001-1001601666-S").unwrap();
+    assert_eq!(job.refnr.as_deref(), Some("10001-1001601666-S"));
+
+    let config = ClientConfig {
+        unknown_fields: jobsuche::UnknownFieldPolicy::Deny,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    match client.job_details("10001-1001601666-S") {
+        Err(jobsuche::Error::UnknownFields { fields }) => assert_eq!(fields, ["tarifvertrag"]),
+        other => panic!("expected UnknownFields, got {:?}", other),
+    }
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_job_details_cache_shared_across_clones() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", Some("Rust Developer")))
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let first = client.job_details("10001-1001601666-S").unwrap();
+    // Answered from the cache, also for clones
+    let second = client.job_details("10001-1001601666-S").unwrap();
+    let third = client.clone().job_details("10001-1001601666-S").unwrap();
+    assert_eq!(first, second);
+    assert_eq!(first, third);
+
+    // Bypassing the cache hits the API once more
+    let fresh = client.job_details_fresh("10001-1001601666-S").unwrap();
+    assert_eq!(first, fresh);
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_job_details_cache_expires() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    client.job_details("10001-1001601666-S").unwrap();
+    client.job_details("10001-1001601666-S").unwrap();
+    std::thread::sleep(Duration::from_millis(100));
+    client.job_details("10001-1001601666-S").unwrap();
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_job_details_errors_not_cached() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    assert!(client.job_details("10001-1001601666-S").is_err());
+    assert!(client.job_details("10001-1001601666-S").is_err());
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_search_cache_keyed_by_options() {
+    use jobsuche::{Befristung, CacheConfig};
+
+    let mut server = Server::new();
+
+    let body =
+        r#"{"stellenangebote": [{"refnr": "10001-A-S", "arbeitsort": {}}], "maxErgebnisse": 1}"#;
+    let berlin = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*wo=Berlin".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(2)
+        .create();
+    let hamburg = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?.*wo=Hamburg".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let first = client
+        .search()
+        .list(
+            SearchOptions::builder()
+                .was("Rust")
+                .wo("Berlin")
+                .arbeitszeit(vec![Arbeitszeit::Vollzeit, Arbeitszeit::Teilzeit])
+                .build(),
+        )
+        .unwrap();
+
+    // Same options, built in a different order: cache hit, also for clones
+    let same = SearchOptions::builder()
+        .arbeitszeit(vec![Arbeitszeit::Teilzeit, Arbeitszeit::Vollzeit])
+        .wo("Berlin")
+        .was("Rust")
+        .build();
+    assert_eq!(client.search().list(same.clone()).unwrap(), first);
+    assert_eq!(client.clone().search().list(same.clone()).unwrap(), first);
+
+    // Any differing parameter is a miss
+    client
+        .search()
+        .list(
+            same.as_builder()
+                .befristung(vec![Befristung::Unbefristet])
+                .build(),
+        )
+        .unwrap();
+    client
+        .search()
+        .list(same.as_builder().wo("Hamburg").build())
+        .unwrap();
+
+    berlin.assert();
+    hamburg.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_search_cache_ttl_and_bypass() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(3)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            search_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let options = SearchOptions::builder().was("Rust").build();
+
+    client.search().list(options.clone()).unwrap();
+    client.search().list(options.clone()).unwrap();
+    client.search().list_fresh(options.clone()).unwrap();
+    std::thread::sleep(Duration::from_millis(100));
+    client.search().list(options).unwrap();
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_search_cache_pages_opt_out() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [{"refnr": "10001-A-S", "arbeitsort": {}}], "maxErgebnisse": 1}"#)
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            cache_pages: false,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let options = SearchOptions::builder().was("Rust").size(10).build();
+
+    // Pages fetched by the iterator are neither read from nor written to the cache
+    assert_eq!(client.search().iter(options.clone()).unwrap().len(), 1);
+    assert_eq!(client.search().iter(options).unwrap().len(), 1);
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_file_cache_survives_client_restart() {
+    use jobsuche::FileCache;
+    use std::sync::Arc;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", Some("Rust Developer")))
+        .expect(1)
+        .create();
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = |dir: &std::path::Path| {
+        // Setting only a backend enables caching with the default settings
+        let config = ClientConfig {
+            cache_backend: Some(Arc::new(FileCache::new(dir))),
+            ..Default::default()
+        };
+        Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap()
+    };
+
+    let first = client(dir.path())
+        .job_details("10001-1001601666-S")
+        .unwrap();
+    // A new client on the same directory, as in the next run of a CLI
+    let second = client(dir.path())
+        .job_details("10001-1001601666-S")
+        .unwrap();
+    assert_eq!(first, second);
+
+    m.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_cache_invalidation() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(3)
+        .create();
+    let searches = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/pc/v4/jobs\?".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0}"#)
+        .expect(3)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig::default()),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let options = SearchOptions::builder().was("Rust").build();
+    assert!(client.cache().is_enabled());
+
+    // Request 1 of each
+    client.job_details("10001-1001601666-S").unwrap();
+    client.search().list(options.clone()).unwrap();
+
+    // Invalidation affects only the named entry: request 2 of each
+    client.cache().invalidate_details("10001-1001601666-S");
+    client.job_details("10001-1001601666-S").unwrap();
+    client.search().list(options.clone()).unwrap();
+    client.cache().invalidate_search(&options);
+    client.search().list(options.clone()).unwrap();
+    client.job_details("10001-1001601666-S").unwrap();
+
+    // Clearing empties both namespaces: request 3 of each
+    client.cache().clear();
+    client.job_details("10001-1001601666-S").unwrap();
+    client.search().list(options).unwrap();
+
+    details.assert();
+    searches.assert();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_cache_handle_without_cache() {
+    let client = Jobsuche::new("https://example.org", Credentials::default()).unwrap();
+
+    assert!(!client.cache().is_enabled());
+    // No-ops
+    client.cache().invalidate_details("10001-1001601666-S");
+    client.cache().clear();
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_cache_stats_and_lru_eviction() {
+    use jobsuche::{CacheConfig, CacheStats};
+
+    let mut server = Server::new();
+
+    // B is fetched again after its eviction
+    let mocks: Vec<_> = [("10001-A-S", 1), ("10001-B-S", 2), ("10001-C-S", 1)]
+        .iter()
+        .map(|(refnr, hits)| {
+            let encoded = jobsuche::encode_refnr(refnr);
+            server
+                .mock("GET", format!("/pc/v4/jobdetails/{}", encoded).as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(details_body(refnr, None))
+                .expect(*hits)
+                .create()
+        })
+        .collect();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            max_entries: 2,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    assert_eq!(client.cache().stats(), CacheStats::default());
+
+    client.job_details("10001-A-S").unwrap();
+    client.job_details("10001-B-S").unwrap();
+    // Reading A makes B the least recently used
+    client.job_details("10001-A-S").unwrap();
+    // Beyond max_entries: B is evicted
+    client.job_details("10001-C-S").unwrap();
+
+    let stats = client.clone().cache().stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 3);
+    assert_eq!(stats.insertions, 3);
+    assert_eq!(stats.evictions, 1);
+    assert_eq!(stats.entries, 2);
+    assert!(stats.approximate_bytes > 0);
+    assert_eq!(stats.hit_rate(), Some(0.25));
+
+    // A is still cached, B is fetched again
+    client.job_details("10001-A-S").unwrap();
+    client.job_details("10001-B-S").unwrap();
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_job_details_cache_revalidates_with_etag() {
+    use jobsuche::CacheConfig;
+    use mockito::Matcher;
+
+    let mut server = Server::new();
+    let path = "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T";
+
+    // Mocks that reached their expected hits give way to the next matching one
+    let initial = server
+        .mock("GET", path)
+        .match_header("if-none-match", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(details_body("10001-1001601666-S", Some("Rust Developer")))
+        .expect(1)
+        .create();
+    let unchanged = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(304)
+        .expect(1)
+        .create();
+    let changed = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v1\"")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v2\"")
+        .with_body(details_body(
+            "10001-1001601666-S",
+            Some("Senior Rust Developer"),
+        ))
+        .expect(1)
+        .create();
+    let unchanged_again = server
+        .mock("GET", path)
+        .match_header("if-none-match", "\"v2\"")
+        .with_status(304)
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let titel = |client: &Jobsuche| client.job_details("10001-1001601666-S").unwrap().titel;
+
+    // 200 with an ETag, then served from the cache
+    assert_eq!(titel(&client).as_deref(), Some("Rust Developer"));
+    assert_eq!(titel(&client).as_deref(), Some("Rust Developer"));
+    initial.assert();
+
+    // Expired: If-None-Match gets a 304, which restarts the TTL
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(titel(&client).as_deref(), Some("Rust Developer"));
+    assert_eq!(titel(&client).as_deref(), Some("Rust Developer"));
+    unchanged.assert();
+    let stats = client.cache().stats();
+    assert_eq!(stats.revalidations, 1);
+    assert_eq!(stats.hits, 2);
+    assert_eq!(stats.entries, 1);
+
+    // Expired again: a 200 replaces the entry and its ETag
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(titel(&client).as_deref(), Some("Senior Rust Developer"));
+    assert_eq!(titel(&client).as_deref(), Some("Senior Rust Developer"));
+    changed.assert();
+
+    // job_details_fresh revalidates even within the TTL
+    let details = client.job_details_fresh("10001-1001601666-S").unwrap();
+    assert_eq!(details.titel.as_deref(), Some("Senior Rust Developer"));
+    unchanged_again.assert();
+
+    let stats = client.cache().stats();
+    assert_eq!(stats.revalidations, 2);
+    assert_eq!(stats.insertions, 2);
+    assert_eq!(stats.entries, 1);
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_job_details_not_found_cached() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .expect(3)
+        .create();
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            cache_not_found: true,
+            negative_ttl: Duration::from_millis(50),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    // One request for repeated lookups within the TTL
+    for _ in 0..3 {
+        let err = client.job_details("10001-1001601666-S").unwrap_err();
+        assert!(err.is_not_found());
+        assert!(err.to_string().contains("10001-1001601666-S"), "{}", err);
+    }
+    let stats = client.cache().stats();
+    assert_eq!(stats.negative_hits, 2);
+    assert_eq!(stats.hits, 0);
+
+    // job_details_fresh asks the API anyway
+    assert!(client
+        .job_details_fresh("10001-1001601666-S")
+        .unwrap_err()
+        .is_not_found());
+
+    // Expired: the API is asked again
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(client
+        .job_details("10001-1001601666-S")
+        .unwrap_err()
+        .is_not_found());
+
+    m.assert();
+    assert_eq!(client.cache().stats().negative_hits, 2);
+}
+
+/// Mocks for a cache warmup: A and B exist (B with an ETag), C is gone and D is rejected
+#[cfg(feature = "cache")]
+fn warmup_mocks(server: &mut mockito::ServerGuard) -> Vec<mockito::Mock> {
+    let details = |refnr: &str| details_body(refnr, None);
+    let path = |refnr: &str| format!("/pc/v4/jobdetails/{}", jobsuche::encode_refnr(refnr));
+    vec![
+        server
+            .mock("GET", path("10001-A-S").as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(details("10001-A-S"))
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", path("10001-B-S").as_str())
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("etag", "\"b1\"")
+            .with_body(details("10001-B-S"))
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", path("10001-B-S").as_str())
+            .match_header("if-none-match", "\"b1\"")
+            .with_status(304)
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", path("10001-C-S").as_str())
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+            .expect(1)
+            .create(),
+        server
+            .mock("GET", path("10001-D-S").as_str())
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"errors": [], "errorMessages": ["bad request"]}"#)
+            .expect(2)
+            .create(),
+    ]
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_warm_details_cache() {
+    use jobsuche::CacheConfig;
+
+    let mut server = Server::new();
+    let mocks = warmup_mocks(&mut server);
+
+    let config = ClientConfig {
+        cache: Some(CacheConfig {
+            details_ttl: Duration::from_millis(500),
+            cache_not_found: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let refnrs = ["10001-A-S", "10001-B-S", "10001-C-S", "10001-D-S"];
+
+    // B is stale when the warmup starts, A is fresh
+    client.job_details("10001-B-S").unwrap();
+    std::thread::sleep(Duration::from_millis(600));
+    client.job_details("10001-A-S").unwrap();
+
+    let report = client.warm_details_cache(&refnrs).unwrap();
+    assert_eq!(report.fetched, 1);
+    assert_eq!(report.skipped, 1);
+    assert_eq!(report.not_found, 1);
+    assert_eq!(report.failed, 1);
+
+    // B was revalidated and the 404 of C is remembered; only D is requested again
+    let report = client.warm_details_cache(&refnrs).unwrap();
+    assert_eq!(report.fetched, 0);
+    assert_eq!(report.skipped, 3);
+    assert_eq!(report.not_found, 0);
+    assert_eq!(report.failed, 1);
+
+    for mock in mocks {
+        mock.assert();
+    }
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_warm_details_cache_requires_cache() {
+    let client = Jobsuche::new("http://localhost:1", Credentials::default()).unwrap();
+    let err = client.warm_details_cache(&["10001-A-S"]).unwrap_err();
+    assert!(matches!(err, jobsuche::Error::ConfigError { .. }));
+}
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_file_cache_ignores_old_schema_versions() {
+    use jobsuche::{Cache, CachedEntry, FileCache};
+    use std::sync::Arc;
+
+    let mut server = Server::new();
+
+    let m = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", Some("Rust Developer")))
+        .expect(1)
+        .create();
+
+    let dir = tempfile::tempdir().unwrap();
+    let backend = Arc::new(FileCache::new(dir.path()));
+    let config = ClientConfig {
+        cache_backend: Some(backend.clone()),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    // An entry left behind by a release with an older layout
+    let old_key = format!(
+        "v0 {} jobdetails 10001-1001601666-S",
+        client.core().host.as_str()
+    );
+    backend.put(
+        &old_key,
+        CachedEntry::new(
+            serde_json::from_str(&details_body("10001-1001601666-S", Some("Alt"))).unwrap(),
+        ),
+        Duration::from_secs(60),
+    );
+
+    // Not read back: fetched and stored under the current version
+    let details = client.job_details("10001-1001601666-S").unwrap();
+    assert_eq!(details.titel.as_deref(), Some("Rust Developer"));
+    let details = client.job_details("10001-1001601666-S").unwrap();
+    assert_eq!(details.titel.as_deref(), Some("Rust Developer"));
+    assert_eq!(client.cache().stats().entries, 2);
+
+    assert_eq!(client.cache().purge_old_versions(), 1);
+    assert!(backend.get(&old_key).is_none());
+    assert_eq!(client.cache().stats().entries, 1);
+    m.assert();
+}
+
+/// Metrics recorded by a `DebuggingRecorder`
+#[cfg(feature = "metrics")]
+type MetricEntries = [(
+    metrics_util::CompositeKey,
+    Option<metrics::Unit>,
+    Option<metrics::SharedString>,
+    metrics_util::debugging::DebugValue,
+)];
+
+/// Value of the counter `name` with exactly `labels`, or 0 if it was never incremented
+#[cfg(feature = "metrics")]
+fn metric_counter(entries: &MetricEntries, name: &str, labels: &[(&str, &str)]) -> u64 {
+    use metrics_util::debugging::DebugValue;
+
+    entries
+        .iter()
+        .find_map(|(key, _, _, value)| {
+            let key = key.key();
+            let matches = key.name() == name
+                && key.labels().count() == labels.len()
+                && labels.iter().all(|(label, expected)| {
+                    key.labels()
+                        .any(|l| l.key() == *label && l.value() == *expected)
+                });
+            match value {
+                DebugValue::Counter(count) if matches => Some(*count),
+                _ => None,
+            }
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_metrics_recorded() {
+    use jobsuche::metrics::{
+        ERRORS_TOTAL, REQUESTS_TOTAL, REQUEST_DURATION_SECONDS, RETRIES_TOTAL, SUCCESSES_TOTAL,
+    };
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let mut server = Server::new();
+
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .create();
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create();
+    let _unavailable = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["maintenance"]}"#)
+        .expect(2)
+        .create();
+
+    let config = ClientConfig {
+        max_retries: 1,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        client.job_details("10001-1001601666-S").unwrap();
+        client.job_details("10001-1001601667-S").unwrap_err();
+        client
+            .search()
+            .list(SearchOptions::builder().was("Rust").build())
+            .unwrap_err();
+    });
+    let entries = snapshotter.snapshot().into_vec();
+
+    let details = [("endpoint", "jobdetails")];
+    let jobs = [("endpoint", "jobs")];
+    assert_eq!(metric_counter(&entries, REQUESTS_TOTAL, &details), 2);
+    assert_eq!(metric_counter(&entries, SUCCESSES_TOTAL, &details), 1);
+    assert_eq!(
+        metric_counter(
+            &entries,
+            ERRORS_TOTAL,
+            &[("endpoint", "jobdetails"), ("class", "4xx")]
+        ),
+        1
+    );
+    assert_eq!(metric_counter(&entries, REQUESTS_TOTAL, &jobs), 2);
+    assert_eq!(
+        metric_counter(
+            &entries,
+            ERRORS_TOTAL,
+            &[("endpoint", "jobs"), ("class", "5xx")]
+        ),
+        2
+    );
+    assert_eq!(metric_counter(&entries, RETRIES_TOTAL, &jobs), 1);
+    assert_eq!(metric_counter(&entries, RETRIES_TOTAL, &details), 0);
+
+    // One latency sample per attempt; no label contains a refnr
+    let samples: usize = entries
+        .iter()
+        .filter(|(key, ..)| key.key().name() == REQUEST_DURATION_SECONDS)
+        .map(|(_, _, _, value)| match value {
+            DebugValue::Histogram(samples) => samples.len(),
+            _ => 0,
+        })
+        .sum();
+    assert_eq!(samples, 4);
+    assert!(entries
+        .iter()
+        .all(|(key, ..)| key.key().labels().all(|l| !l.value().contains("10001"))));
+}
+
+#[cfg(feature = "prometheus")]
+#[test]
+fn test_prometheus_exposition() {
+    let mut server = Server::new();
+
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .create();
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create();
+
+    let handle = jobsuche::metrics::prometheus_handle().unwrap();
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    client.job_details("10001-1001601666-S").unwrap();
+    client.job_details("10001-1001601667-S").unwrap_err();
+
+    // The recorder is global, so other tests may add to the values
+    let scrape = jobsuche::metrics::prometheus_handle().unwrap().render();
+    for family in [
+        "# TYPE jobsuche_requests_total counter",
+        "# TYPE jobsuche_requests_success_total counter",
+        "# TYPE jobsuche_requests_errors_total counter",
+        "# TYPE jobsuche_request_duration_seconds histogram",
+        "# HELP jobsuche_requests_total",
+    ] {
+        assert!(
+            scrape.contains(family),
+            "missing {:?} in\n{}",
+            family,
+            scrape
+        );
+    }
+    assert!(scrape.contains(r#"jobsuche_requests_errors_total{endpoint="jobdetails",class="4xx"}"#));
+    assert!(scrape.contains(r#"jobsuche_request_duration_seconds_bucket{endpoint="jobdetails","#));
+    assert!(!scrape.contains("10001"));
+    assert!(handle.render().contains("jobsuche_requests_total"));
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_client_metrics_snapshot() {
+    let mut server = Server::new();
+
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .create();
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create();
+    let _limited = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(429)
+        .with_header("Retry-After", "60")
+        .create();
+
+    let config = ClientConfig {
+        retry_enabled: false,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let snapshot = client.metrics();
+    assert_eq!(snapshot.requests, 0);
+    assert_eq!(snapshot.latency.max, Duration::ZERO);
+
+    client.job_details("10001-1001601666-S").unwrap();
+    // Clones share the counters
+    client
+        .clone()
+        .job_details("10001-1001601667-S")
+        .unwrap_err();
+    client.search().list(SearchOptions::default()).unwrap_err();
+
+    let snapshot = client.metrics();
+    assert_eq!(snapshot.requests, 3);
+    assert_eq!(snapshot.successes, 1);
+    assert_eq!(snapshot.client_errors, 2);
+    assert_eq!(snapshot.rate_limited, 1);
+    assert_eq!(snapshot.server_errors, 0);
+    assert_eq!(snapshot.transport_errors, 0);
+    assert_eq!(snapshot.retries, 0);
+
+    let latency = snapshot.latency;
+    assert_eq!(latency.count, 3);
+    assert!(latency.min > Duration::ZERO);
+    assert!(latency.min <= latency.p50 && latency.p50 <= latency.p95);
+    assert!(latency.p95 <= latency.max);
+    assert!(latency.sum >= latency.max);
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_metrics_per_endpoint_series() {
+    use jobsuche::hooks::Endpoint;
+    use jobsuche::metrics::{StatusClass, REQUEST_DURATION_SECONDS};
+    use metrics_util::debugging::DebuggingRecorder;
+
+    let mut server = Server::new();
+
+    let _jobs = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0, "page": 1, "size": 10}"#)
+        .create();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .create();
+    let _gone = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ny1T")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"errors": [], "errorMessages": ["not found"]}"#)
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, || {
+        client
+            .search()
+            .list(SearchOptions::builder().was("Rust").build())
+            .unwrap();
+        client.job_details("10001-1001601666-S").unwrap();
+        client.job_details("10001-1001601667-S").unwrap_err();
+    });
+
+    // Facade: one duration series per endpoint and class, without refnrs or URLs
+    let mut series: Vec<Vec<(String, String)>> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .filter(|(key, ..)| key.key().name() == REQUEST_DURATION_SECONDS)
+        .map(|(key, ..)| {
+            key.key()
+                .labels()
+                .map(|l| (l.key().to_string(), l.value().to_string()))
+                .collect()
+        })
+        .collect();
+    series.sort();
+    let labels = |endpoint: &str, class: &str| {
+        vec![
+            ("endpoint".to_string(), endpoint.to_string()),
+            ("class".to_string(), class.to_string()),
+        ]
+    };
+    assert_eq!(
+        series,
+        vec![
+            labels("jobdetails", "2xx"),
+            labels("jobdetails", "4xx"),
+            labels("jobs", "2xx"),
+        ]
+    );
+
+    // Snapshot: the same breakdown, plus the slowest attempts
+    let snapshot = client.metrics();
+    let keys: Vec<_> = snapshot
+        .by_endpoint
+        .iter()
+        .map(|series| (series.endpoint, series.class, series.latency.count))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (Endpoint::Jobs, StatusClass::Success, 1),
+            (Endpoint::JobDetails, StatusClass::Success, 1),
+            (Endpoint::JobDetails, StatusClass::ClientError, 1),
+        ]
+    );
+    let slowest = snapshot.slowest_requests(3);
+    assert_eq!(slowest.len(), 3);
+    assert!(slowest.windows(2).all(|w| w[0].duration >= w[1].duration));
+    assert_eq!(
+        snapshot.slowest_requests(1)[0].duration,
+        snapshot.latency.max
+    );
+}
+
+/// Writer collecting formatted tracing output for assertions
+#[derive(Clone, Default)]
+struct CapturedLogs(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl CapturedLogs {
+    /// Run `f` with a subscriber at `level` that prints spans when they close
+    fn capture(level: tracing::Level, f: impl FnOnce()) -> String {
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        let logs = CapturedLogs::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        let output = logs.0.lock().unwrap();
+        String::from_utf8_lossy(&output).into_owned()
+    }
+}
+
+#[test]
+fn test_request_spans_for_retried_request() {
+    let mut server = Server::new();
+
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "0")
+        .expect(2)
+        .create();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(2)
+        .create();
+
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let logs = CapturedLogs::capture(tracing::Level::INFO, || {
+        client.job_details("10001-1001601666-S").unwrap();
+    });
+
+    // One request span with a child span per attempt: 429, 429, 200
+    let closed: Vec<&str> = logs.lines().filter(|l| l.contains("close")).collect();
+    assert_eq!(closed.len(), 4, "{}", logs);
+    let attempt = |n: u32| {
+        closed
+            .iter()
+            .find(|l| l.contains(&format!("jobsuche.attempt{{attempt={}", n)))
+            .unwrap_or_else(|| panic!("no span of attempt {} in\n{}", n, logs))
+    };
+    assert!(attempt(1).contains("status=429"));
+    assert!(attempt(1).contains("retry_after_ms=0"));
+    assert!(attempt(1).contains("rate_limited"));
+    assert!(attempt(1).contains("duration_ms="));
+    assert!(attempt(2).contains("status=429"));
+    assert!(attempt(3).contains("status=200"));
+    assert!(!attempt(3).contains("error="));
+    assert!(attempt(3).contains("jobsuche.request{"));
+    let request = closed
+        .iter()
+        .find(|l| l.contains("jobsuche.request{") && !l.contains("jobsuche.attempt"))
+        .unwrap();
+    assert!(request.contains("jobdetails"));
+    assert!(request.contains("method=GET"));
+    assert_eq!(request.matches("attempts=").count(), 1, "{}", request);
+    assert!(request.contains("attempts=3"));
+
+    // The refnr only appears at debug verbosity
+    assert!(!logs.contains("10001-1001601666-S"));
+    let logs = CapturedLogs::capture(tracing::Level::DEBUG, || {
+        client.job_details("10001-1001601666-S").unwrap();
+    });
+    assert!(logs.contains("jobsuche.job_details{refnr="));
+    assert!(logs.contains("10001-1001601666-S"));
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_rate_limit_telemetry() {
+    use jobsuche::metrics::{RATE_LIMITED_TOTAL, RETRY_WAIT_SECONDS};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let mut server = Server::new();
+
+    let _limited = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(429)
+        .with_header("Retry-After", "1")
+        .expect(1)
+        .create();
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(details_body("10001-1001601666-S", None))
+        .expect(1)
+        .create();
+
+    let config = ClientConfig {
+        retry_wait_warning: Some(Duration::from_millis(500)),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let logs = CapturedLogs::capture(tracing::Level::WARN, || {
+        metrics::with_local_recorder(&recorder, || {
+            client.job_details("10001-1001601666-S").unwrap();
+        });
+    });
+    assert!(
+        logs.contains("/pc/v4/jobdetails/{refnr} spent 1s waiting for retries"),
+        "{}",
+        logs
+    );
+    assert!(logs.contains("1 Retry-After waits, 0 backoff sleeps"));
+
+    let snapshot = client.metrics();
+    assert_eq!(snapshot.rate_limited, 1);
+    assert_eq!(snapshot.retries, 1);
+    assert_eq!(snapshot.retry_after_wait, Duration::from_secs(1));
+    assert_eq!(snapshot.backoff_sleeps, 0);
+    assert_eq!(snapshot.backoff_wait, Duration::ZERO);
+    assert_eq!(snapshot.longest_wait, Duration::from_secs(1));
+
+    let entries = snapshotter.snapshot().into_vec();
+    assert_eq!(
+        metric_counter(&entries, RATE_LIMITED_TOTAL, &[("endpoint", "jobdetails")]),
+        1
+    );
+    let waits: Vec<f64> = entries
+        .iter()
+        .filter(|(key, ..)| {
+            key.key().name() == RETRY_WAIT_SECONDS
+                && key
+                    .key()
+                    .labels()
+                    .any(|l| l.key() == "reason" && l.value() == "retry_after")
+        })
+        .flat_map(|(_, _, _, value)| match value {
+            DebugValue::Histogram(samples) => samples.iter().map(|s| s.into_inner()).collect(),
+            _ => Vec::new(),
+        })
+        .collect();
+    assert_eq!(waits, [1.0]);
+}
+
+#[test]
+fn test_slow_request_warning() {
+    let mut server = Server::new();
+
+    let delayed = |body: Vec<u8>| {
+        move |_: &mockito::Request| {
+            std::thread::sleep(Duration::from_millis(300));
+            body.clone()
+        }
+    };
+    let _details = server
+        .mock("GET", "/pc/v4/jobdetails/MTAwMDEtMTAwMTYwMTY2Ni1T")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(delayed(
+            details_body("10001-1001601666-S", None).into_bytes(),
+        ))
+        .create();
+    let _logo = server
+        .mock("GET", "/ed/v1/arbeitgeberlogo/abc123")
+        .with_status(404)
+        .with_body_from_request(delayed(Vec::new()))
+        .create();
+    let _jobs = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"stellenangebote": [], "maxErgebnisse": 0, "page": 1, "size": 10}"#)
+        .create();
+
+    let config = ClientConfig {
+        slow_request_threshold: Some(Duration::from_millis(200)),
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+
+    let logs = CapturedLogs::capture(tracing::Level::WARN, || {
+        client.job_details("10001-1001601666-S").unwrap();
+        client.employer_logo("abc123").unwrap_err();
+        client.search().list(SearchOptions::default()).unwrap();
+    });
+
+    let slow: Vec<&str> = logs
+        .lines()
+        .filter(|l| l.contains("Slow request"))
+        .collect();
+    assert_eq!(slow.len(), 2, "{}", logs);
+    assert!(slow[0].contains("Slow request to /pc/v4/jobdetails/{refnr}"));
+    assert!(slow[0].contains("until status 200 (threshold 200ms)"));
+    assert!(slow[1].contains("Slow request to /ed/v1/arbeitgeberlogo/{hash_id}"));
+    assert!(slow[1].contains("until status 404"));
+    assert!(!logs.contains("10001-1001601666-S"));
+
+    #[cfg(feature = "metrics")]
+    {
+        let snapshot = client.metrics();
+        assert_eq!(snapshot.requests, 3);
+        assert_eq!(snapshot.slow_requests, 2);
+    }
+}
+
+/// Mock a search page listing the given reference numbers
+fn mock_listing_page(server: &mut mockito::ServerGuard, refnrs: &[&str]) -> mockito::Mock {
+    let listings: Vec<String> = refnrs
+        .iter()
+        .map(|refnr| format!(r#"{{"refnr": "{}", "arbeitsort": {{}}}}"#, refnr))
+        .collect();
+    server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!(
+            r#"{{"stellenangebote": [{}], "maxErgebnisse": {}}}"#,
+            listings.join(","),
+            refnrs.len()
+        ))
+}
+
+#[test]
+fn test_job_watcher_delivers_only_new_listings() {
+    use jobsuche::JobWatcher;
+    use std::sync::mpsc::RecvTimeoutError;
+
+    let mut server = Server::new();
+    let first = mock_listing_page(&mut server, &["10001-A-S", "10001-B-S"])
+        .expect(1)
+        .create();
+    let failure = server
+        .mock("GET", mockito::Matcher::Regex(r"^/pc/v4/jobs".to_string()))
+        .with_status(503)
+        .expect(1)
+        .create();
+    let _second = mock_listing_page(&mut server, &["10001-B-S", "10001-C-S"]).create();
+
+    let config = ClientConfig {
+        max_retries: 0,
+        ..Default::default()
+    };
+    let client = Jobsuche::with_config(server.url(), Credentials::default(), config).unwrap();
+    let (watcher, jobs) = JobWatcher::channel(
+        &client,
+        SearchOptions::builder().was("Rust").build(),
+        Duration::from_millis(50),
+    )
+    .unwrap();
+
+    let delivered: Vec<String> = (0..3)
+        .map(|_| {
+            let job = jobs.recv_timeout(Duration::from_secs(5)).unwrap();
+            job.refnr.to_string()
+        })
+        .collect();
+    assert_eq!(delivered, ["10001-A-S", "10001-B-S", "10001-C-S"]);
+
+    // Later polls see the same listings again
+    assert_eq!(
+        jobs.recv_timeout(Duration::from_millis(200)),
+        Err(RecvTimeoutError::Timeout)
+    );
+    assert!(!watcher.is_finished());
+
+    watcher.stop();
+    assert_eq!(
+        jobs.recv_timeout(Duration::from_millis(200)),
+        Err(RecvTimeoutError::Disconnected)
+    );
+    first.assert();
+    failure.assert();
+}
+
+#[test]
+fn test_job_watcher_ends_when_receiver_dropped() {
+    use jobsuche::JobWatcher;
+    use std::sync::{Arc, Mutex};
+
+    let mut server = Server::new();
+    let _page = mock_listing_page(&mut server, &["10001-A-S"]).create();
+    let client = Jobsuche::new(server.url(), Credentials::default()).unwrap();
+    let options = SearchOptions::default();
+
+    let (watcher, jobs) =
+        JobWatcher::channel(&client, options.clone(), Duration::from_millis(20)).unwrap();
+    drop(jobs);
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    while !watcher.is_finished() && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(watcher.is_finished());
+
+    // A callback watcher runs until dropped
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    let watcher = JobWatcher::spawn(&client, options, Duration::from_secs(60), move |job| {
+        sink.lock().unwrap().push(job.refnr.to_string())
+    })
+    .unwrap();
+    while seen.lock().unwrap().is_empty() && std::time::Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    drop(watcher);
+    assert_eq!(*seen.lock().unwrap(), ["10001-A-S"]);
+
+    assert!(matches!(
+        JobWatcher::spawn(&client, SearchOptions::default(), Duration::ZERO, |_| {}),
+        Err(jobsuche::Error::ConfigError { .. })
+    ));
+}
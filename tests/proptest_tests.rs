@@ -13,14 +13,14 @@ fn arb_work_location() -> impl Strategy<Value = WorkLocation> {
         proptest::option::of("[a-zA-Z ]{0,20}"),
         proptest::option::of("[a-zA-Z ]{0,20}"),
     )
-        .prop_map(|(plz, ort, strasse, region, land)| WorkLocation {
-            plz,
-            ort,
-            strasse,
-            region,
-            land,
-            koordinaten: None,
-            entfernung: None,
+        .prop_map(|(plz, ort, strasse, region, land)| {
+            let mut location = WorkLocation::default();
+            location.plz = plz;
+            location.ort = ort;
+            location.strasse = strasse;
+            location.region = region;
+            location.land = land;
+            location
         })
 }
 
@@ -33,21 +33,13 @@ fn arb_job_listing() -> impl Strategy<Value = JobListing> {
         arb_work_location(),
         proptest::option::of("[a-zA-Z ]{3,40}"), // titel
     )
-        .prop_map(
-            |(refnr, beruf, arbeitgeber, arbeitsort, titel)| JobListing {
-                hash_id: None,
-                refnr,
-                beruf,
-                titel,
-                arbeitgeber,
-                aktuelle_veroeffentlichungsdatum: None,
-                eintrittsdatum: None,
-                arbeitsort,
-                modifikations_timestamp: None,
-                externe_url: None,
-                kundennummer_hash: None,
-            },
-        )
+        .prop_map(|(refnr, beruf, arbeitgeber, arbeitsort, titel)| {
+            let mut listing = JobListing::new(refnr, arbeitsort);
+            listing.beruf = beruf;
+            listing.titel = titel;
+            listing.arbeitgeber = arbeitgeber;
+            listing
+        })
 }
 
 /// Strategy to generate an arbitrary JobSearchResponse.
@@ -58,15 +50,13 @@ fn arb_job_search_response() -> impl Strategy<Value = JobSearchResponse> {
         proptest::option::of(0u64..100),
         proptest::option::of(1u64..100),
     )
-        .prop_map(
-            |(stellenangebote, max_ergebnisse, page, size)| JobSearchResponse {
-                stellenangebote,
-                max_ergebnisse,
-                page,
-                size,
-                facetten: None,
-            },
-        )
+        .prop_map(|(stellenangebote, max_ergebnisse, page, size)| {
+            let mut response = JobSearchResponse::new(stellenangebote);
+            response.max_ergebnisse = max_ergebnisse;
+            response.page = page;
+            response.size = size;
+            response
+        })
 }
 
 proptest! {
@@ -135,9 +125,9 @@ proptest! {
         );
     }
 
-    /// For any non-empty ASCII string, `decode_refnr(encode_refnr(s))` returns the original.
+    /// For any refnr-shaped string, `decode_refnr(encode_refnr(s))` returns the original.
     #[test]
-    fn base64_encode_decode_roundtrip(s in "[[:ascii:]]{1,100}") {
+    fn base64_encode_decode_roundtrip(s in "[0-9]{1,10}(-[a-zA-Z0-9]{1,20}){1,3}") {
         let encoded = encode_refnr(&s);
         let decoded = decode_refnr(&encoded).unwrap();
         prop_assert_eq!(&decoded, &s);